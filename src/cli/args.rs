@@ -1,4 +1,17 @@
 // CLI argument parsing
 //
 // Command-line argument parsing is done in main.rs using clap derive macros.
-// This module is reserved for future argument processing logic.
+// This module holds argument processing that works on the resulting clap `Command`.
+
+use clap::Command;
+use clap_complete::{Shell, generate};
+use std::io::Write;
+
+/// 生成 shell 补全脚本 / Write a completion script for `shell` to `out`
+///
+/// Flags and subcommands are read from `cmd`, so new options are completed
+/// without changes here.
+pub fn write_completions(shell: Shell, cmd: &mut Command, out: &mut dyn Write) {
+    let name = cmd.get_name().to_string();
+    generate(shell, cmd, name, out);
+}
@@ -9,12 +9,13 @@ pub mod args;
 pub mod prompts;
 
 use std::env;
+use std::io::{self, IsTerminal};
 
 /// Detect if we should run in non-interactive mode
 ///
 /// This function checks:
 /// - CI environment variable (set by most CI/CD systems)
-/// - TTY availability (terminal presence)
+/// - TTY availability (whether stdin is a terminal)
 /// - Explicit non_interactive flag from command line
 ///
 /// # Returns
@@ -42,8 +43,7 @@ pub fn is_non_interactive(explicit_flag: bool) -> bool {
         return true;
     }
 
-    // Check if we're in a terminal (TTY)
-    // This is a basic check - for production use, consider using atty crate
+    // inquire reads answers from stdin, so prompting needs a terminal there
     if !is_tty() {
         return true;
     }
@@ -51,36 +51,11 @@ pub fn is_non_interactive(explicit_flag: bool) -> bool {
     false
 }
 
-/// Basic TTY detection
+/// TTY detection
 ///
-/// Returns true if stdout appears to be a terminal
-#[allow(clippy::needless_return)] // Explicit return needed for clarity with cfg attributes
+/// Returns true if stdin is a terminal
 fn is_tty() -> bool {
-    // Try to check if we're in a terminal
-    // This is a simplified check - for robust detection, use atty or is-terminal crate
-    #[cfg(unix)]
-    {
-        use std::fs;
-        // Check if stdout is a TTY by checking /dev/stdout
-        if fs::metadata("/dev/stdout").is_ok() {
-            // Check file type (TTY devices have specific permissions)
-            // This is a heuristic - not 100% reliable
-            return true;
-        }
-    }
-
-    #[cfg(windows)]
-    {
-        // On Windows, assume we might be in a terminal unless in CI
-        // For production, use windows-sys or winapi to check properly
-        return true;
-    }
-
-    // Default to assuming TTY if we can't determine
-    #[cfg(not(windows))]
-    {
-        true
-    }
+    io::stdin().is_terminal()
 }
 
 #[cfg(test)]
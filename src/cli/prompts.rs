@@ -2,9 +2,16 @@
 //
 // This module contains interactive prompt logic using inquire.
 
-use crate::config::{DatabaseOption, FeatureSet, Preset, ProjectConfig, ProjectMode};
-use crate::utils::validator::validate_project_name;
-use inquire::{Confirm, Select, Text};
+use crate::config::{
+    AuthConfig, CiProvider, DatabaseOption, Edition, FeatureSet, JsonBackend, Locale,
+    LoggingConfig, Preset, ProjectConfig, ProjectMode, ServerConfig, WorkspaceLayout,
+    user_config_path,
+};
+use crate::template::resolver::{
+    resolve_template_dir, template_dir_from_env, template_dir_from_user_config,
+};
+use crate::utils::validator::{suggest_valid_name, validate_host, validate_project_name};
+use inquire::{Confirm, CustomType, MultiSelect, Select, Text};
 
 /// CLI overrides for non-interactive mode
 #[derive(Debug, Default)]
@@ -12,35 +19,86 @@ pub struct CliOverrides {
     pub database: Option<DatabaseOption>,
     pub auth: Option<bool>,
     pub biz_error: Option<bool>,
+    pub openapi: Option<bool>,
+    pub sse: Option<bool>,
+    pub sessions: Option<bool>,
+    pub multipart: Option<bool>,
+    pub cache: Option<bool>,
+    pub cors: Option<bool>,
+    pub compression: Option<bool>,
+    pub metrics: Option<bool>,
+    pub jwt_algorithm: Option<String>,
+    pub jwt_expiration: Option<u64>,
     pub log_level: Option<String>,
+    pub log_format: Option<String>,
     pub author: Option<String>,
     pub mode: Option<ProjectMode>,
+    pub workspace_layout: Option<WorkspaceLayout>,
     pub preset: Option<Preset>,
     pub ci: Option<bool>,
+    pub ci_provider: Option<CiProvider>,
+    pub cargo_features: Option<bool>,
+    pub seed_rng: Option<u64>,
+    pub testcontainers: Option<bool>,
+    pub api_prefix: Option<String>,
+    pub api_routes: Option<Vec<String>>,
+    pub api_versions: Option<Vec<String>>,
+    pub sql_lint: Option<bool>,
+    pub normalize_path: Option<bool>,
+    pub startup_banner: Option<bool>,
+    pub json_backend: Option<JsonBackend>,
+    pub edition: Option<Edition>,
+    pub template_dir: Option<std::path::PathBuf>,
+    pub locale: Option<Locale>,
+    pub comment_locale: Option<Locale>,
+    pub entities: Option<Vec<String>>,
+    pub bare: Option<bool>,
+    pub no_update: Option<bool>,
+    pub with_env: Option<bool>,
+    pub db_url_parts: Option<bool>,
+    pub no_git: Option<bool>,
+    pub procfile: Option<bool>,
+    pub codeowners: Option<bool>,
+    pub repository: Option<String>,
+    pub license: Option<String>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
 }
 
 /// Prompt for project name
 ///
-/// Returns None if in non-interactive mode and no name provided
+/// Returns None if in non-interactive mode and no name provided. An invalid
+/// answer is re-prompted with [`suggest_valid_name`] as the default.
 pub fn prompt_project_name(interactive: bool, default_name: Option<String>) -> Option<String> {
     if !interactive {
         return default_name;
     }
 
-    let name = Text::new("Project name?")
+    let mut name = Text::new("Project name?")
         .with_placeholder("my-axum-app")
         .with_help_message("Use kebab-case (e.g., my-app, my-awesome-project)")
         .prompt()
         .ok()?;
 
+    // Re-prompt with a sanitized suggestion until the name is valid
+    while let Err(e) = validate_project_name(&name) {
+        eprintln!("\n{}\n", e);
+        let suggestion = suggest_valid_name(&name);
+        name = Text::new(&format!("Did you mean '{}'?", suggestion))
+            .with_default(&suggestion)
+            .with_help_message("Press Enter to accept, or type another name")
+            .prompt()
+            .ok()?;
+    }
+
     Some(name)
 }
 
 /// Prompt for author name
 ///
 /// Returns None if not provided (will be detected from git)
-pub fn prompt_author_name(interactive: bool) -> Option<String> {
-    if !interactive {
+pub fn prompt_author_name(interactive: bool, assume_yes: bool) -> Option<String> {
+    if !interactive || assume_yes {
         return None;
     }
 
@@ -52,20 +110,64 @@ pub fn prompt_author_name(interactive: bool) -> Option<String> {
 }
 
 /// Prompt for project description
-pub fn prompt_description(interactive: bool) -> Option<String> {
-    if !interactive {
-        return Some("An Axum web application".to_string());
+pub fn prompt_description(interactive: bool, assume_yes: bool) -> Option<String> {
+    let default = "An Axum web application";
+    if !interactive || assume_yes {
+        return Some(default.to_string());
     }
 
     Text::new("Project description (optional)?")
-        .with_default("An Axum web application")
+        .with_default(default)
+        .prompt()
+        .ok()
+}
+
+/// Prompt for the server bind host
+pub fn prompt_host(interactive: bool, assume_yes: bool) -> String {
+    let default = ServerConfig::default().host;
+    if !interactive || assume_yes {
+        return default;
+    }
+
+    loop {
+        let Some(host) = Text::new("Server host?")
+            .with_default(&default)
+            .with_help_message("Use 0.0.0.0 to accept connections from other machines")
+            .prompt()
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+        else {
+            return default;
+        };
+
+        // Re-prompt until the host is an IP address or hostname
+        match validate_host(&host) {
+            Ok(()) => return host,
+            Err(e) => eprintln!("\n{}\n", e),
+        }
+    }
+}
+
+/// Prompt for the server port
+pub fn prompt_port(interactive: bool, assume_yes: bool) -> u16 {
+    let default = ServerConfig::default().port;
+    if !interactive || assume_yes {
+        return default;
+    }
+
+    CustomType::<u16>::new("Server port?")
+        .with_default(default)
+        .with_error_message("Please enter a port between 1 and 65535")
         .prompt()
         .ok()
+        .filter(|port| *port != 0)
+        .unwrap_or(default)
 }
 
 /// Prompt for database selection
-pub fn prompt_database(interactive: bool) -> DatabaseOption {
-    if !interactive {
+pub fn prompt_database(interactive: bool, assume_yes: bool) -> DatabaseOption {
+    if !interactive || assume_yes {
         return DatabaseOption::None;
     }
 
@@ -89,35 +191,105 @@ pub fn prompt_database(interactive: bool) -> DatabaseOption {
     }
 }
 
-/// Prompt for authentication
-pub fn prompt_authentication(interactive: bool) -> bool {
-    if !interactive {
-        return false;
+/// Optional features offered by [`prompt_features_multiselect`], in display order
+///
+/// Each label starts with the feature's flag name followed by ` - `.
+pub const FEATURE_OPTIONS: &[&str] = &[
+    "auth - JWT authentication with login/register endpoints",
+    "biz-error - Business error codes (biz-error crate)",
+    "openapi - OpenAPI documentation with Swagger UI",
+    "sse - Server-sent events endpoint",
+    "sessions - Cookie-backed server-side sessions",
+    "multipart - Multipart file upload endpoint",
+    "cache - Redis cache connection",
+    "cors - CORS layer allowing CORS_ALLOWED_ORIGINS",
+    "compression - Gzip/Brotli response compression",
+    "metrics - Prometheus request metrics at /metrics",
+];
+
+/// Map the labels picked from [`FEATURE_OPTIONS`] to a `FeatureSet`
+///
+/// Only the optional boolean features are set; `database` and `logging` keep
+/// their defaults.
+///
+/// # Examples
+/// ```
+/// use axum_app_create::cli::prompts::{FEATURE_OPTIONS, features_from_labels};
+///
+/// let features = features_from_labels(&[FEATURE_OPTIONS[0]]);
+/// assert!(features.authentication);
+/// assert!(!features.cors);
+/// ```
+pub fn features_from_labels(selected: &[&str]) -> FeatureSet {
+    let mut features = FeatureSet::default();
+    for label in selected {
+        match label.split(" - ").next() {
+            Some("auth") => features.authentication = true,
+            Some("biz-error") => features.biz_error = true,
+            Some("openapi") => features.openapi = true,
+            Some("sse") => features.sse = true,
+            Some("sessions") => features.sessions = true,
+            Some("multipart") => features.multipart = true,
+            Some("cache") => features.cache = true,
+            Some("cors") => features.cors = true,
+            Some("compression") => features.compression = true,
+            Some("metrics") => features.metrics = true,
+            _ => {}
+        }
     }
+    features
+}
 
-    Confirm::new("Enable JWT authentication?")
-        .with_default(false)
-        .with_help_message("Include login/register endpoints and JWT middleware")
-        .prompt()
-        .unwrap_or(false)
+/// Labels of [`FEATURE_OPTIONS`] whose feature is not already set by a CLI flag
+///
+/// A flag always wins, so offering its feature in the multi-select would let
+/// the user tick a box that is then silently ignored.
+pub fn unset_feature_options(overrides: &CliOverrides) -> Vec<&'static str> {
+    FEATURE_OPTIONS
+        .iter()
+        .copied()
+        .filter(|label| {
+            let overridden = match label.split(" - ").next() {
+                Some("auth") => overrides.auth,
+                Some("biz-error") => overrides.biz_error,
+                Some("openapi") => overrides.openapi,
+                Some("sse") => overrides.sse,
+                Some("sessions") => overrides.sessions,
+                Some("multipart") => overrides.multipart,
+                Some("cache") => overrides.cache,
+                Some("cors") => overrides.cors,
+                Some("compression") => overrides.compression,
+                Some("metrics") => overrides.metrics,
+                _ => None,
+            };
+            overridden.is_none()
+        })
+        .collect()
 }
 
-/// Prompt for biz-error support
-pub fn prompt_biz_error(interactive: bool) -> bool {
-    if !interactive {
-        return false;
+/// Prompt for the optional features in `options` at once with a multi-select
+///
+/// Returns no features in non-interactive mode, with `assume_yes` (nothing is
+/// preselected), when `options` is empty or if the prompt is cancelled.
+pub fn prompt_features_multiselect(
+    interactive: bool,
+    assume_yes: bool,
+    options: Vec<&str>,
+) -> FeatureSet {
+    if !interactive || assume_yes || options.is_empty() {
+        return FeatureSet::default();
     }
 
-    Confirm::new("Enable biz-error integration?")
-        .with_default(false)
-        .with_help_message("Standardized error responses with i18n support")
+    let selected = MultiSelect::new("Select optional features:", options)
+        .with_help_message("Space to toggle, Enter to confirm")
         .prompt()
-        .unwrap_or(false)
+        .unwrap_or_default();
+    features_from_labels(&selected)
 }
 
 /// Prompt for log level selection
-pub fn prompt_log_level(interactive: bool) -> String {
-    if !interactive {
+pub fn prompt_log_level(interactive: bool, assume_yes: bool) -> String {
+    if !interactive || assume_yes {
         return "info".to_string();
     }
 
@@ -130,6 +302,7 @@ pub fn prompt_log_level(interactive: bool) -> String {
     ];
 
     let ans = Select::new("Select default log level:", options)
+        .with_starting_cursor(2)
         .prompt()
         .unwrap_or("info - General informational messages (default)");
 
@@ -138,23 +311,37 @@ pub fn prompt_log_level(interactive: bool) -> String {
 }
 
 /// Prompt for project mode selection
-pub fn prompt_project_mode(interactive: bool) -> ProjectMode {
-    if !interactive {
+pub fn prompt_project_mode(interactive: bool, assume_yes: bool, locale: Locale) -> ProjectMode {
+    if !interactive || assume_yes {
         return ProjectMode::Single;
     }
 
     let options = vec![
-        "Single package - 单包模式（推荐新手）/ Single-package mode (recommended for beginners)",
-        "Workspace - 工作区模式（多 crate 分层架构）/ Multi-crate workspace (Clean Architecture)",
+        format!(
+            "Single package - {}",
+            locale.text(
+                "Single-package mode (recommended for beginners)",
+                "单包模式（推荐新手）"
+            )
+        ),
+        format!(
+            "Workspace - {}",
+            locale.text(
+                "Multi-crate workspace (Clean Architecture)",
+                "工作区模式（多 crate 分层架构）"
+            )
+        ),
     ];
 
-    let default_option = options[0];
-
-    let ans = Select::new("选择项目模式 / Select project mode:", options)
-        .prompt()
-        .unwrap_or(default_option);
+    let ans = Select::new(
+        &locale.text("Select project mode:", "选择项目模式"),
+        options,
+    )
+    .raw_prompt()
+    .map(|ans| ans.index)
+    .unwrap_or(0);
 
-    if ans.starts_with("Workspace") {
+    if ans == 1 {
         ProjectMode::Workspace
     } else {
         ProjectMode::Single
@@ -162,46 +349,71 @@ pub fn prompt_project_mode(interactive: bool) -> ProjectMode {
 }
 
 /// Prompt for preset selection
-/// Returns None if user chooses "Custom"
-pub fn prompt_preset(interactive: bool) -> Option<Preset> {
+/// Returns None if user chooses "Custom"; `assume_yes` takes the preselected Minimal
+pub fn prompt_preset(interactive: bool, assume_yes: bool, locale: Locale) -> Option<Preset> {
     if !interactive {
         return None;
     }
+    if assume_yes {
+        return Some(Preset::Minimal);
+    }
 
     let options = vec![
-        "Minimal - 最小配置 / No optional features",
-        "API - API 开发 / PostgreSQL + Auth + Biz-error",
-        "Fullstack - 全栈开发 / Both DBs + Auth + Biz-error",
-        "Custom - 自定义 / Choose features individually",
+        format!(
+            "Minimal - {}",
+            locale.text("No optional features", "最小配置")
+        ),
+        format!(
+            "API - {}",
+            locale.text("PostgreSQL + Auth + Biz-error", "API 开发")
+        ),
+        format!(
+            "Fullstack - {}",
+            locale.text("Both DBs + Auth + Biz-error", "全栈开发")
+        ),
+        format!(
+            "Custom - {}",
+            locale.text("Choose features individually", "自定义")
+        ),
     ];
 
-    let default_option = options[3];
-
-    let ans = Select::new("选择配置预设 / Select configuration preset:", options)
-        .prompt()
-        .unwrap_or(default_option);
+    let ans = Select::new(
+        &locale.text("Select configuration preset:", "选择配置预设"),
+        options,
+    )
+    .raw_prompt()
+    .map(|ans| ans.index)
+    .unwrap_or(3);
 
     match ans {
-        s if s.starts_with("Minimal") => Some(Preset::Minimal),
-        s if s.starts_with("API") => Some(Preset::Api),
-        s if s.starts_with("Fullstack") => Some(Preset::Fullstack),
+        0 => Some(Preset::Minimal),
+        1 => Some(Preset::Api),
+        2 => Some(Preset::Fullstack),
         _ => None, // Custom
     }
 }
 
 /// Prompt for CI/CD workflow generation
-pub fn prompt_ci(interactive: bool) -> bool {
+pub fn prompt_ci(interactive: bool, assume_yes: bool, locale: Locale) -> bool {
+    let default = true;
     if !interactive {
         return false;
     }
+    if assume_yes {
+        return default;
+    }
 
-    Confirm::new("生成 GitHub Actions CI 工作流？/ Generate GitHub Actions CI workflow?")
-        .with_default(true)
-        .with_help_message(
-            "包含 check/test/fmt/clippy 四个 job / Includes check, test, fmt, clippy jobs",
-        )
-        .prompt()
-        .unwrap_or(false)
+    Confirm::new(&locale.text(
+        "Generate GitHub Actions CI workflow?",
+        "生成 GitHub Actions CI 工作流？",
+    ))
+    .with_default(default)
+    .with_help_message(&locale.text(
+        "Includes check, test, fmt, clippy jobs",
+        "包含 check/test/fmt/clippy 四个 job",
+    ))
+    .prompt()
+    .unwrap_or(false)
 }
 
 /// Resolve features from preset + CLI overrides
@@ -211,6 +423,7 @@ pub fn resolve_features(
     preset: Option<Preset>,
     overrides: &CliOverrides,
     interactive: bool,
+    assume_yes: bool,
 ) -> (FeatureSet, String) {
     let base = match preset {
         Some(p) => p.to_feature_set(),
@@ -221,28 +434,32 @@ pub fn resolve_features(
         if preset.is_some() {
             base.database
         } else {
-            prompt_database(interactive)
+            prompt_database(interactive, assume_yes)
         }
     });
-    let authentication = overrides.auth.unwrap_or_else(|| {
-        if preset.is_some() {
-            base.authentication
-        } else {
-            prompt_authentication(interactive)
+    // A preset supplies the optional features; a custom setup picks the ones
+    // not set by a flag in one multi-select (skipped if every feature is set)
+    let picked = match preset {
+        Some(_) => base,
+        None => {
+            prompt_features_multiselect(interactive, assume_yes, unset_feature_options(overrides))
         }
-    });
-    let biz_error = overrides.biz_error.unwrap_or_else(|| {
-        if preset.is_some() {
-            base.biz_error
-        } else {
-            prompt_biz_error(interactive)
-        }
-    });
+    };
+    let authentication = overrides.auth.unwrap_or(picked.authentication);
+    let biz_error = overrides.biz_error.unwrap_or(picked.biz_error);
+    let openapi = overrides.openapi.unwrap_or(picked.openapi);
+    let sse = overrides.sse.unwrap_or(picked.sse);
+    let sessions = overrides.sessions.unwrap_or(picked.sessions);
+    let multipart = overrides.multipart.unwrap_or(picked.multipart);
+    let cache = overrides.cache.unwrap_or(picked.cache);
+    let cors = overrides.cors.unwrap_or(picked.cors);
+    let compression = overrides.compression.unwrap_or(picked.compression);
+    let metrics = overrides.metrics.unwrap_or(picked.metrics);
     let log_level = overrides.log_level.clone().unwrap_or_else(|| {
         if preset.is_some() {
             "info".to_string()
         } else {
-            prompt_log_level(interactive)
+            prompt_log_level(interactive, assume_yes)
         }
     });
 
@@ -251,6 +468,14 @@ pub fn resolve_features(
         authentication,
         logging: true,
         biz_error,
+        openapi,
+        sse,
+        sessions,
+        multipart,
+        cache,
+        cors,
+        compression,
+        metrics,
     };
 
     (features, log_level)
@@ -262,10 +487,12 @@ pub fn resolve_features(
 /// CLI overrides take precedence over interactive prompts.
 pub fn prompt_project_config(
     interactive: bool,
+    assume_yes: bool,
     default_name: Option<String>,
     overrides: Option<CliOverrides>,
 ) -> Result<ProjectConfig, String> {
     let overrides = overrides.unwrap_or_default();
+    let locale = overrides.locale.unwrap_or_default();
 
     // Get project name
     let project_name = if let Some(name) = default_name {
@@ -282,48 +509,268 @@ pub fn prompt_project_config(
     let author_name = if overrides.author.is_some() {
         overrides.author.clone()
     } else {
-        prompt_author_name(interactive)
+        prompt_author_name(interactive, assume_yes)
     };
 
-    let description = prompt_description(interactive);
+    let description = prompt_description(interactive, assume_yes);
 
     // Get project mode (CLI override > prompt > default)
     let mode = overrides
         .mode
-        .unwrap_or_else(|| prompt_project_mode(interactive));
+        .unwrap_or_else(|| prompt_project_mode(interactive, assume_yes, locale));
 
     // Get preset (CLI override > prompt > None)
     let preset = if overrides.preset.is_some() {
         overrides.preset
     } else {
-        prompt_preset(interactive)
+        prompt_preset(interactive, assume_yes, locale)
     };
 
     // Resolve features from preset + overrides
-    let (features, log_level) = resolve_features(preset, &overrides, interactive);
+    let (features, log_level) = resolve_features(preset, &overrides, interactive, assume_yes);
 
     // Get CI option (CLI override > prompt > default)
-    let ci = overrides.ci.unwrap_or_else(|| prompt_ci(interactive));
+    // (choosing a provider implies CI)
+    let ci = overrides
+        .ci
+        .or(overrides.ci_provider.map(|_| true))
+        .unwrap_or_else(|| prompt_ci(interactive, assume_yes, locale));
+
+    // Cargo features, testcontainers tests, SQL lint and path normalization
+    // are opt-in via CLI flag only
+    let cargo_features = overrides.cargo_features.unwrap_or(false);
+    let testcontainers = overrides.testcontainers.unwrap_or(false);
+    let sql_lint = overrides.sql_lint.unwrap_or(false);
+    let normalize_path = overrides.normalize_path.unwrap_or(false);
+    let startup_banner = overrides.startup_banner.unwrap_or(false);
+
+    // Get bind address (CLI override > prompt > default)
+    let server = ServerConfig {
+        host: overrides
+            .host
+            .clone()
+            .unwrap_or_else(|| prompt_host(interactive, assume_yes)),
+        port: overrides
+            .port
+            .unwrap_or_else(|| prompt_port(interactive, assume_yes)),
+    };
 
     // Build logging config with selected log level
-    let logging = Some(crate::config::LoggingConfig {
-        default_level: log_level,
-        ..Default::default()
-    });
+    let logging = Some(logging_config(None, Some(log_level), &overrides));
+
+    // JWT settings only apply when authentication is enabled
+    let authentication = auth_config(features.authentication, None, &overrides);
 
     Ok(ProjectConfig {
         project_name,
         features,
+        authentication,
         author_name,
         description,
         logging,
         mode,
+        workspace_layout: overrides.workspace_layout.unwrap_or_default(),
         preset,
         ci,
+        ci_provider: overrides.ci_provider.unwrap_or_default(),
+        cargo_features,
+        seed_rng: overrides.seed_rng,
+        testcontainers,
+        api_prefix: overrides
+            .api_prefix
+            .clone()
+            .unwrap_or_else(|| "/api".to_string()),
+        api_routes: overrides.api_routes.clone().unwrap_or_default(),
+        api_versions: overrides.api_versions.clone().unwrap_or_default(),
+        sql_lint,
+        normalize_path,
+        startup_banner,
+        json_backend: overrides.json_backend.unwrap_or_default(),
+        edition: overrides.edition.unwrap_or_default(),
+        template_dir: resolve_template_dir(
+            overrides.template_dir.clone(),
+            template_dir_from_env(),
+            template_dir_from_user_config(user_config_path().as_deref()),
+        ),
+        locale,
+        comment_locale: overrides.comment_locale.unwrap_or(locale),
+        entities: overrides.entities.clone().unwrap_or_default(),
+        bare: overrides.bare.unwrap_or(false),
+        no_update: overrides.no_update.unwrap_or(false),
+        with_env: overrides.with_env.unwrap_or(false),
+        db_url_parts: overrides.db_url_parts.unwrap_or(false),
+        no_git: overrides.no_git.unwrap_or(false),
+        procfile: overrides.procfile.unwrap_or(false),
+        codeowners: overrides.codeowners.unwrap_or(false),
+        repository: overrides.repository.clone(),
+        license: overrides.license.clone(),
+        server,
         ..Default::default()
     })
 }
 
+/// Build the logging config from `base` (or the defaults), applying `--log-level`/`--log-format`
+fn logging_config(
+    base: Option<LoggingConfig>,
+    log_level: Option<String>,
+    overrides: &CliOverrides,
+) -> LoggingConfig {
+    let defaults = base.unwrap_or_default();
+    LoggingConfig {
+        default_level: log_level.unwrap_or(defaults.default_level.clone()),
+        format: overrides
+            .log_format
+            .clone()
+            .unwrap_or(defaults.format.clone()),
+        ..defaults
+    }
+}
+
+/// Build the JWT config when authentication is `enabled`, applying `--jwt-algorithm`/`--jwt-expiration`
+fn auth_config(
+    enabled: bool,
+    base: Option<AuthConfig>,
+    overrides: &CliOverrides,
+) -> Option<AuthConfig> {
+    enabled.then(|| {
+        let defaults = base.unwrap_or_default();
+        AuthConfig {
+            algorithm: overrides
+                .jwt_algorithm
+                .clone()
+                .unwrap_or(defaults.algorithm.clone()),
+            expiration_seconds: overrides
+                .jwt_expiration
+                .unwrap_or(defaults.expiration_seconds),
+            ..defaults
+        }
+    })
+}
+
+/// Apply CLI flags on top of a configuration read from a project manifest
+///
+/// Flags that were given replace the manifest's values (a `--preset` first
+/// replaces its features); the JWT and logging sub-configs are then derived
+/// from the resulting features the same way [`prompt_project_config`] does.
+pub fn apply_overrides(mut config: ProjectConfig, overrides: &CliOverrides) -> ProjectConfig {
+    if let Some(preset) = overrides.preset {
+        config.preset = Some(preset);
+        config.features = preset.to_feature_set();
+    }
+
+    let features = &mut config.features;
+    if let Some(database) = overrides.database {
+        features.database = database;
+    }
+    for (flag, feature) in [
+        (overrides.auth, &mut features.authentication),
+        (overrides.biz_error, &mut features.biz_error),
+        (overrides.openapi, &mut features.openapi),
+        (overrides.sse, &mut features.sse),
+        (overrides.sessions, &mut features.sessions),
+        (overrides.multipart, &mut features.multipart),
+        (overrides.cache, &mut features.cache),
+        (overrides.cors, &mut features.cors),
+        (overrides.compression, &mut features.compression),
+        (overrides.metrics, &mut features.metrics),
+    ] {
+        if let Some(enabled) = flag {
+            *feature = enabled;
+        }
+    }
+
+    for (flag, option) in [
+        (
+            overrides.ci.or(overrides.ci_provider.map(|_| true)),
+            &mut config.ci,
+        ),
+        (overrides.cargo_features, &mut config.cargo_features),
+        (overrides.testcontainers, &mut config.testcontainers),
+        (overrides.sql_lint, &mut config.sql_lint),
+        (overrides.normalize_path, &mut config.normalize_path),
+        (overrides.startup_banner, &mut config.startup_banner),
+        (overrides.bare, &mut config.bare),
+        (overrides.no_update, &mut config.no_update),
+        (overrides.with_env, &mut config.with_env),
+        (overrides.db_url_parts, &mut config.db_url_parts),
+        (overrides.no_git, &mut config.no_git),
+        (overrides.procfile, &mut config.procfile),
+        (overrides.codeowners, &mut config.codeowners),
+    ] {
+        if let Some(enabled) = flag {
+            *option = enabled;
+        }
+    }
+
+    if overrides.author.is_some() {
+        config.author_name = overrides.author.clone();
+    }
+    if let Some(mode) = overrides.mode {
+        config.mode = mode;
+    }
+    if let Some(layout) = overrides.workspace_layout {
+        config.workspace_layout = layout;
+    }
+    if let Some(provider) = overrides.ci_provider {
+        config.ci_provider = provider;
+    }
+    if overrides.seed_rng.is_some() {
+        config.seed_rng = overrides.seed_rng;
+    }
+    if let Some(prefix) = &overrides.api_prefix {
+        config.api_prefix = prefix.clone();
+    }
+    if let Some(routes) = &overrides.api_routes {
+        config.api_routes = routes.clone();
+    }
+    if let Some(versions) = &overrides.api_versions {
+        config.api_versions = versions.clone();
+    }
+    if let Some(entities) = &overrides.entities {
+        config.entities = entities.clone();
+    }
+    if let Some(backend) = overrides.json_backend {
+        config.json_backend = backend;
+    }
+    if let Some(edition) = overrides.edition {
+        config.edition = edition;
+    }
+    if overrides.template_dir.is_some() {
+        config.template_dir = overrides.template_dir.clone();
+    }
+    if let Some(locale) = overrides.locale {
+        config.locale = locale;
+        config.comment_locale = locale;
+    }
+    if let Some(comment_locale) = overrides.comment_locale {
+        config.comment_locale = comment_locale;
+    }
+    if overrides.repository.is_some() {
+        config.repository = overrides.repository.clone();
+    }
+    if overrides.license.is_some() {
+        config.license = overrides.license.clone();
+    }
+    if let Some(host) = &overrides.host {
+        config.server.host = host.clone();
+    }
+    if let Some(port) = overrides.port {
+        config.server.port = port;
+    }
+
+    config.logging = Some(logging_config(
+        config.logging.take(),
+        overrides.log_level.clone(),
+        overrides,
+    ));
+    config.authentication = auth_config(
+        config.features.authentication,
+        config.authentication.take(),
+        overrides,
+    );
+    config
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -366,7 +813,7 @@ mod tests {
                 ..Default::default()
             };
 
-            let (features, _) = resolve_features(Some(preset), &overrides, false);
+            let (features, _) = resolve_features(Some(preset), &overrides, false, false);
             let preset_features = preset.to_feature_set();
 
             // Overridden fields should match the override value
@@ -390,6 +837,116 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_features_from_labels() {
+        assert_eq!(features_from_labels(&[]), FeatureSet::default());
+
+        let all = features_from_labels(FEATURE_OPTIONS);
+        assert!(all.authentication);
+        assert!(all.biz_error);
+        assert!(all.openapi);
+        assert!(all.sse);
+        assert!(all.sessions);
+        assert!(all.multipart);
+        assert!(all.cache);
+        assert!(all.cors);
+        assert!(all.compression);
+        assert!(all.metrics);
+        assert_eq!(all.database, DatabaseOption::None);
+        assert!(!all.logging);
+
+        let picked = features_from_labels(&[FEATURE_OPTIONS[2], FEATURE_OPTIONS[7]]);
+        assert_eq!(
+            picked,
+            FeatureSet {
+                openapi: true,
+                cors: true,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_apply_overrides_on_manifest() {
+        let manifest = ProjectConfig {
+            project_name: "manifest-app".to_string(),
+            features: FeatureSet {
+                authentication: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // No flags: the manifest is kept and auth gets its default JWT config
+        let config = apply_overrides(manifest.clone(), &CliOverrides::default());
+        assert!(config.features.authentication);
+        assert_eq!(config.authentication, Some(AuthConfig::default()));
+        assert_eq!(config.server, ServerConfig::default());
+
+        let overrides = CliOverrides {
+            database: Some(DatabaseOption::SQLite),
+            cors: Some(true),
+            jwt_algorithm: Some("HS512".to_string()),
+            log_level: Some("debug".to_string()),
+            port: Some(3000),
+            ..Default::default()
+        };
+        let config = apply_overrides(manifest.clone(), &overrides);
+        assert_eq!(config.features.database, DatabaseOption::SQLite);
+        assert!(config.features.cors);
+        assert_eq!(config.authentication.unwrap().algorithm, "HS512");
+        assert_eq!(config.logging.unwrap().default_level, "debug");
+        assert_eq!(config.server.port, 3000);
+
+        // Turning auth off drops the JWT config
+        let overrides = CliOverrides {
+            auth: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(apply_overrides(manifest, &overrides).authentication, None);
+    }
+
+    #[test]
+    fn test_unset_feature_options_skips_flags() {
+        assert_eq!(
+            unset_feature_options(&CliOverrides::default()),
+            FEATURE_OPTIONS.to_vec()
+        );
+
+        let overrides = CliOverrides {
+            auth: Some(true),
+            cors: Some(false),
+            ..Default::default()
+        };
+        let options = unset_feature_options(&overrides);
+        assert_eq!(options.len(), FEATURE_OPTIONS.len() - 2);
+        assert!(!options.iter().any(|label| label.starts_with("auth - ")));
+        assert!(!options.iter().any(|label| label.starts_with("cors - ")));
+    }
+
+    #[test]
+    fn test_assume_yes_takes_prompt_defaults() {
+        let locale = Locale::default();
+
+        // Interactive, but every prompt answers with its default instead of blocking
+        assert_eq!(prompt_database(true, true), DatabaseOption::None);
+        assert_eq!(
+            prompt_features_multiselect(true, true, FEATURE_OPTIONS.to_vec()),
+            FeatureSet::default()
+        );
+        assert_eq!(prompt_log_level(true, true), "info");
+        assert_eq!(prompt_project_mode(true, true, locale), ProjectMode::Single);
+        assert_eq!(prompt_preset(true, true, locale), Some(Preset::Minimal));
+        assert!(prompt_ci(true, true, locale));
+        assert_eq!(prompt_author_name(true, true), None);
+        assert_eq!(prompt_port(true, true), ServerConfig::default().port);
+
+        let config = prompt_project_config(true, true, Some("yes-app".to_string()), None).unwrap();
+        assert_eq!(config.preset, Some(Preset::Minimal));
+        assert!(config.ci);
+        assert_eq!(config.server, ServerConfig::default());
+    }
+
     #[test]
     fn test_prompt_database() {
         // Test that prompt_database returns a valid option
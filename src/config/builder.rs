@@ -0,0 +1,167 @@
+// Project configuration builder
+//
+// This module provides a chainable builder for embedding the generator as a
+// library without spelling out every `ProjectConfig` field.
+
+use super::{
+    AuthConfig, BizErrorConfig, DatabaseConfig, DatabaseOption, Preset, ProjectConfig, ProjectMode,
+};
+use crate::error::{CliError, Result};
+use crate::utils::validator::validate_project_name;
+
+/// 项目配置构建器 / Chainable builder for [`ProjectConfig`]
+///
+/// Enabling a feature also fills in its sub-configuration, so the result
+/// matches what the CLI would produce for the same choices.
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::config::{DatabaseOption, ProjectConfigBuilder, ProjectMode};
+///
+/// let config = ProjectConfigBuilder::new()
+///     .name("my-app")
+///     .mode(ProjectMode::Workspace)
+///     .with_database(DatabaseOption::PostgreSQL)
+///     .with_auth()
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(config.project_name, "my-app");
+/// assert!(config.features.authentication);
+/// assert!(config.database.is_some());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ProjectConfigBuilder {
+    config: ProjectConfig,
+}
+
+impl ProjectConfigBuilder {
+    /// Start from the default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the project name (validated by [`build`](Self::build))
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.config.project_name = name.into();
+        self
+    }
+
+    /// Set the project mode (single package or workspace)
+    pub fn mode(mut self, mode: ProjectMode) -> Self {
+        self.config.mode = mode;
+        self
+    }
+
+    /// Enable database support with a default `DatabaseConfig` for `option`
+    ///
+    /// `DatabaseOption::None` disables the database again.
+    pub fn with_database(mut self, option: DatabaseOption) -> Self {
+        self.config.features.database = option;
+        self.config.database = (option != DatabaseOption::None).then(|| DatabaseConfig {
+            option,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Enable JWT authentication with a default `AuthConfig`
+    pub fn with_auth(mut self) -> Self {
+        self.config.features.authentication = true;
+        self.config.authentication = Some(AuthConfig::default());
+        self
+    }
+
+    /// Apply a preset's feature set and the sub-configs it needs
+    pub fn preset(mut self, preset: Preset) -> Self {
+        let features = preset.to_feature_set();
+        self.config.preset = Some(preset);
+
+        self = self.with_database(features.database);
+        self.config.authentication = features.authentication.then(AuthConfig::default);
+        self.config.biz_error = features.biz_error.then(BizErrorConfig::default);
+        self.config.features = features;
+        self
+    }
+
+    /// Validate the project name and return the configuration
+    pub fn build(self) -> Result<ProjectConfig> {
+        validate_project_name(&self.config.project_name)
+            .map_err(|e| CliError::InvalidName(self.config.project_name.clone(), e))?;
+        Ok(self.config)
+    }
+}
+
+impl ProjectConfig {
+    /// 创建构建器 / Start building a configuration, see [`ProjectConfigBuilder`]
+    pub fn builder() -> ProjectConfigBuilder {
+        ProjectConfigBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::FeatureSet;
+
+    #[test]
+    fn test_builder_matches_manual_config() {
+        let built = ProjectConfig::builder()
+            .name("auth-test-app")
+            .mode(ProjectMode::Workspace)
+            .with_database(DatabaseOption::PostgreSQL)
+            .with_auth()
+            .build()
+            .unwrap();
+
+        let manual = ProjectConfig {
+            project_name: "auth-test-app".to_string(),
+            mode: ProjectMode::Workspace,
+            features: FeatureSet {
+                database: DatabaseOption::PostgreSQL,
+                authentication: true,
+                ..Default::default()
+            },
+            database: Some(DatabaseConfig {
+                option: DatabaseOption::PostgreSQL,
+                ..Default::default()
+            }),
+            authentication: Some(AuthConfig::default()),
+            ..Default::default()
+        };
+
+        assert_eq!(built, manual);
+    }
+
+    #[test]
+    fn test_builder_preset() {
+        let built = ProjectConfig::builder()
+            .name("api-app")
+            .preset(Preset::Api)
+            .build()
+            .unwrap();
+
+        assert_eq!(built.preset, Some(Preset::Api));
+        assert_eq!(built.features, Preset::Api.to_feature_set());
+        assert_eq!(
+            built.database.map(|db| db.option),
+            Some(DatabaseOption::PostgreSQL)
+        );
+        assert!(built.authentication.is_some());
+        assert!(built.biz_error.is_some());
+
+        let minimal = ProjectConfig::builder()
+            .name("minimal-app")
+            .preset(Preset::Minimal)
+            .build()
+            .unwrap();
+        assert!(minimal.database.is_none());
+        assert!(minimal.authentication.is_none());
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_name() {
+        let result = ProjectConfig::builder().name("123invalid").build();
+        assert!(matches!(result, Err(CliError::InvalidName(..))));
+    }
+}
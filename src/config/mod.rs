@@ -2,7 +2,14 @@
 //
 // This module contains project configuration structures and validation.
 
+use crate::error::{CliError, Result};
+use crate::utils::validator::validate_project_name;
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+mod builder;
+
+pub use builder::ProjectConfigBuilder;
 
 /// Database option selection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -33,6 +40,17 @@ impl DatabaseOption {
     pub fn supports_sqlite(&self) -> bool {
         matches!(self, Self::SQLite | Self::Both)
     }
+
+    /// SQL 方言（用于 sqlfluff）/ SQL dialect name used by sqlfluff
+    ///
+    /// `Both` lints as PostgreSQL, the production database.
+    pub fn sql_dialect(&self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::PostgreSQL | Self::Both => Some("postgres"),
+            Self::SQLite => Some("sqlite"),
+        }
+    }
 }
 
 impl std::fmt::Display for DatabaseOption {
@@ -65,6 +83,169 @@ impl std::fmt::Display for ProjectMode {
     }
 }
 
+/// 工作区布局 / Crates generated in workspace mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceLayout {
+    /// 分层 / `api`, `domain`, `infrastructure` and `common`
+    #[default]
+    Layered,
+    /// 精简 / A `core` library and a thin `api` binary (`--mode bin-only-workspace`)
+    Simple,
+}
+
+impl std::fmt::Display for WorkspaceLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Layered => write!(f, "layered"),
+            Self::Simple => write!(f, "simple"),
+        }
+    }
+}
+
+/// 输出语言 / Output language for CLI messages and generated docs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Locale {
+    /// 仅英文 / English only
+    En,
+    /// 仅中文 / Chinese only
+    Zh,
+    /// 中英双语 / Chinese and English
+    #[default]
+    Both,
+}
+
+impl Locale {
+    /// 按语言选择文本 / Pick the text for this locale
+    ///
+    /// `Both` keeps the existing "中文 / English" format.
+    pub fn text(&self, en: &str, zh: &str) -> String {
+        match self {
+            Self::En => en.to_string(),
+            Self::Zh => zh.to_string(),
+            Self::Both => format!("{} / {}", zh, en),
+        }
+    }
+
+    /// Returns true if English output is included
+    pub fn includes_en(&self) -> bool {
+        matches!(self, Self::En | Self::Both)
+    }
+
+    /// Returns true if Chinese output is included
+    pub fn includes_zh(&self) -> bool {
+        matches!(self, Self::Zh | Self::Both)
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::En => write!(f, "en"),
+            Self::Zh => write!(f, "zh"),
+            Self::Both => write!(f, "both"),
+        }
+    }
+}
+
+/// JSON 序列化后端 / JSON serialization backend for responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum JsonBackend {
+    /// serde_json (portable default)
+    #[default]
+    Serde,
+    /// sonic-rs (SIMD-accelerated, fastest with `-C target-cpu=native`)
+    Simd,
+}
+
+impl std::fmt::Display for JsonBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Serde => write!(f, "serde"),
+            Self::Simd => write!(f, "simd"),
+        }
+    }
+}
+
+/// 覆盖策略 / How generation treats a target directory that already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverwritePolicy {
+    /// 删除后重新生成 / Delete the directory and regenerate (same as `--force`)
+    Replace,
+    /// 合并 / Write missing files and keep every existing one
+    Merge,
+    /// 跳过 / Leave the directory untouched and generate nothing
+    Skip,
+}
+
+impl std::fmt::Display for OverwritePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Replace => write!(f, "replace"),
+            Self::Merge => write!(f, "merge"),
+            Self::Skip => write!(f, "skip"),
+        }
+    }
+}
+
+/// CI 平台 / CI service the generated pipeline targets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CiProvider {
+    /// GitHub Actions (`.github/workflows/ci.yml`)
+    #[default]
+    GitHub,
+    /// GitLab CI (`.gitlab-ci.yml`)
+    GitLab,
+}
+
+impl std::fmt::Display for CiProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GitHub => write!(f, "github"),
+            Self::GitLab => write!(f, "gitlab"),
+        }
+    }
+}
+
+/// 输出详细程度 / How much progress output generation prints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Verbosity {
+    /// 安静 / Only the final project path
+    Quiet,
+    /// 默认 / Progress lines and the success banner
+    #[default]
+    Normal,
+    /// 详细 / Also unified diffs of the files an update changes
+    Verbose,
+}
+
+/// Rust 版本 / Rust edition of the generated project
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
+pub enum Edition {
+    #[serde(rename = "2015")]
+    E2015,
+    #[serde(rename = "2018")]
+    E2018,
+    #[serde(rename = "2021")]
+    E2021,
+    #[serde(rename = "2024")]
+    #[default]
+    E2024,
+}
+
+impl std::fmt::Display for Edition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::E2015 => write!(f, "2015"),
+            Self::E2018 => write!(f, "2018"),
+            Self::E2021 => write!(f, "2021"),
+            Self::E2024 => write!(f, "2024"),
+        }
+    }
+}
+
 /// 配置预设 / Configuration preset
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Preset {
@@ -77,6 +258,18 @@ pub enum Preset {
 }
 
 impl Preset {
+    /// 所有预设 / Every preset, in the order they are offered
+    pub const ALL: [Preset; 3] = [Self::Minimal, Self::Api, Self::Fullstack];
+
+    /// 一行描述 / One-line human summary of what the preset enables
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Minimal => "Bare server with logging, no database or auth",
+            Self::Api => "REST API with PostgreSQL, JWT auth and business error codes",
+            Self::Fullstack => "PostgreSQL and SQLite with JWT auth and business error codes",
+        }
+    }
+
     /// 将预设转换为功能集 / Convert preset to FeatureSet
     pub fn to_feature_set(&self) -> FeatureSet {
         match self {
@@ -85,18 +278,42 @@ impl Preset {
                 authentication: false,
                 logging: true,
                 biz_error: false,
+                openapi: false,
+                sse: false,
+                sessions: false,
+                multipart: false,
+                cache: false,
+                cors: false,
+                compression: false,
+                metrics: false,
             },
             Self::Api => FeatureSet {
                 database: DatabaseOption::PostgreSQL,
                 authentication: true,
                 logging: true,
                 biz_error: true,
+                openapi: false,
+                sse: false,
+                sessions: false,
+                multipart: false,
+                cache: false,
+                cors: false,
+                compression: false,
+                metrics: false,
             },
             Self::Fullstack => FeatureSet {
                 database: DatabaseOption::Both,
                 authentication: true,
                 logging: true,
                 biz_error: true,
+                openapi: false,
+                sse: false,
+                sessions: false,
+                multipart: false,
+                cache: false,
+                cors: false,
+                compression: false,
+                metrics: false,
             },
         }
     }
@@ -123,10 +340,34 @@ pub struct FeatureSet {
     pub logging: bool,
     /// Business error handling integration
     pub biz_error: bool,
+    /// OpenAPI documentation with Swagger UI (utoipa)
+    #[serde(default)]
+    pub openapi: bool,
+    /// Server-sent events endpoint
+    #[serde(default)]
+    pub sse: bool,
+    /// Cookie-backed server-side sessions (tower-sessions)
+    #[serde(default)]
+    pub sessions: bool,
+    /// Multipart file upload endpoint
+    #[serde(default)]
+    pub multipart: bool,
+    /// Redis cache connection
+    #[serde(default)]
+    pub cache: bool,
+    /// CORS layer configured from CORS_ALLOWED_ORIGINS
+    #[serde(default)]
+    pub cors: bool,
+    /// Gzip/Brotli response compression layer
+    #[serde(default)]
+    pub compression: bool,
+    /// Prometheus request metrics served at /metrics
+    #[serde(default)]
+    pub metrics: bool,
 }
 
 /// Database configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DatabaseConfig {
     /// Database type selected
     pub option: DatabaseOption,
@@ -156,7 +397,7 @@ impl Default for DatabaseConfig {
 }
 
 /// Authentication configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AuthConfig {
     /// JWT secret (for .env.example only, not real secret)
     pub example_secret: String,
@@ -183,7 +424,7 @@ impl Default for AuthConfig {
 }
 
 /// Logging configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoggingConfig {
     /// Default log level
     pub default_level: String,
@@ -210,7 +451,7 @@ impl Default for LoggingConfig {
 }
 
 /// Business error configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BizErrorConfig {
     /// Include example error codes
     pub include_examples: bool,
@@ -233,8 +474,47 @@ impl Default for BizErrorConfig {
     }
 }
 
+/// 服务器配置 / Default bind address of the generated server
+///
+/// Rendered as the `HOST`/`PORT` defaults; both can still be changed through
+/// the environment at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerConfig {
+    /// Bind host (`HOST`)
+    pub host: String,
+    /// Bind port (`PORT`)
+    pub port: u16,
+}
+
+impl ServerConfig {
+    /// Base URL for reaching the server locally (`0.0.0.0` is reached via `127.0.0.1`)
+    pub fn local_url(&self) -> String {
+        let host = match self.host.as_str() {
+            "0.0.0.0" | "::" | "[::]" => "127.0.0.1",
+            host => host,
+        };
+        format!("http://{}:{}", host, self.port)
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 8080,
+        }
+    }
+}
+
+/// 可挂载在 API 前缀下的功能路由 / Feature routes that can be nested under the API prefix
+pub const API_ROUTE_FEATURES: &[&str] = &["auth"];
+
 /// Project configuration for generation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Missing fields deserialize to their defaults, so metadata written by older
+/// versions stays readable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ProjectConfig {
     /// Project name (validated)
     pub project_name: String,
@@ -254,10 +534,81 @@ pub struct ProjectConfig {
     pub biz_error: Option<BizErrorConfig>,
     /// 项目模式 / Project mode (single or workspace)
     pub mode: ProjectMode,
+    /// 工作区布局 / Crates generated in workspace mode
+    pub workspace_layout: WorkspaceLayout,
     /// 使用的预设 / Preset used (if any)
     pub preset: Option<Preset>,
     /// 是否生成 CI/CD 配置 / Whether to generate CI/CD config
     pub ci: bool,
+    /// CI 平台 / CI service the pipeline is generated for (when `ci` is set)
+    pub ci_provider: CiProvider,
+    /// 是否将可选功能暴露为 Cargo features / Expose optional features as Cargo features
+    pub cargo_features: bool,
+    /// 随机数种子 / Seed for generated secrets (None = secure random seed)
+    pub seed_rng: Option<u64>,
+    /// 是否生成 testcontainers 数据库测试 / Generate a testcontainers DB integration test
+    pub testcontainers: bool,
+    /// API 路由前缀 / Prefix for routes nested under the API (e.g. "/api")
+    pub api_prefix: String,
+    /// 挂载在 API 前缀下的功能路由 / Feature routes nested under `api_prefix` (e.g. ["auth"])
+    pub api_routes: Vec<String>,
+    /// API 版本 / API versions nested under `api_prefix`, one module each (e.g. ["v1"])
+    pub api_versions: Vec<String>,
+    /// 是否生成 SQL lint 配置 / Generate a `.sqlfluff` config for migrations
+    pub sql_lint: bool,
+    /// 是否规范化尾部斜杠 / Strip trailing slashes from request paths
+    pub normalize_path: bool,
+    /// 是否打印启动横幅 / Print a startup banner with name, version and port
+    pub startup_banner: bool,
+    /// JSON 序列化后端 / JSON backend used to serialize responses
+    pub json_backend: JsonBackend,
+    /// Rust 版本 / Edition written to the generated Cargo.toml
+    pub edition: Edition,
+    /// 自定义模板目录 / Directory of custom templates overriding the built-ins
+    pub template_dir: Option<PathBuf>,
+    /// 输出语言 / Language of CLI messages and generated docs
+    pub locale: Locale,
+    /// 注释语言 / Language of comments in generated files
+    pub comment_locale: Locale,
+    /// 实体列表 / Entities to generate migrations and models for (PascalCase, e.g. ["User", "Post"])
+    pub entities: Vec<String>,
+    /// 仅生成 crate / Omit repo-level files (README, .gitignore, CI, Docker) and git init
+    pub bare: bool,
+    /// 跳过依赖更新 / Skip `cargo update` after generation (offline or reproducible builds)
+    pub no_update: bool,
+    /// 是否生成 .env / Write a ready-to-run `.env` (with a generated JWT secret) next to `.env.example`
+    pub with_env: bool,
+    /// 由分量组装数据库连接串 / Build the PostgreSQL URL from DB_HOST/DB_PORT/... when DATABASE_URL is unset
+    pub db_url_parts: bool,
+    /// 跳过 git 初始化 / Don't initialize a git repository (e.g. inside an existing repo)
+    pub no_git: bool,
+    /// 覆盖策略 / What to do when the target directory exists (None = fail or prompt)
+    ///
+    /// A per-run choice, so it is not recorded in the generation metadata.
+    #[serde(skip)]
+    pub overwrite_policy: Option<OverwritePolicy>,
+    /// 输出详细程度 / Progress output printed during generation (per run, not recorded)
+    #[serde(skip)]
+    pub verbosity: Verbosity,
+    /// 生成后钩子 / Shell command run in the project directory after generation
+    ///
+    /// Only ever set from the command line: it is neither read from manifests
+    /// nor recorded in the generation metadata.
+    #[serde(skip)]
+    pub post_generate_hook: Option<String>,
+    /// 自动确认 / Take the default answer of every prompt; an existing directory is never overwritten (use `--force`)
+    #[serde(skip)]
+    pub assume_yes: bool,
+    /// 是否生成 Procfile / Generate a Procfile for Heroku/Railway-style platforms
+    pub procfile: bool,
+    /// 是否生成 CODEOWNERS / Generate .github/CODEOWNERS with ownership stubs
+    pub codeowners: bool,
+    /// 仓库地址 / Repository URL for Cargo.toml and the README, e.g. from `git remote get-url origin`
+    pub repository: Option<String>,
+    /// 许可证 / SPDX license identifier for Cargo.toml and the LICENSE file(s), e.g. "MIT"
+    pub license: Option<String>,
+    /// 服务器绑定地址 / Default host and port the server binds to
+    pub server: ServerConfig,
 }
 
 impl Default for ProjectConfig {
@@ -272,12 +623,93 @@ impl Default for ProjectConfig {
             logging: Some(LoggingConfig::default()),
             biz_error: None,
             mode: ProjectMode::Single,
+            workspace_layout: WorkspaceLayout::Layered,
             preset: None,
             ci: false,
+            ci_provider: CiProvider::GitHub,
+            cargo_features: false,
+            seed_rng: None,
+            testcontainers: false,
+            api_prefix: "/api".to_string(),
+            api_routes: Vec::new(),
+            api_versions: Vec::new(),
+            sql_lint: false,
+            normalize_path: false,
+            startup_banner: false,
+            json_backend: JsonBackend::Serde,
+            edition: Edition::E2024,
+            template_dir: None,
+            locale: Locale::Both,
+            comment_locale: Locale::Both,
+            entities: Vec::new(),
+            bare: false,
+            no_update: false,
+            with_env: false,
+            db_url_parts: false,
+            no_git: false,
+            overwrite_policy: None,
+            verbosity: Verbosity::Normal,
+            post_generate_hook: None,
+            assume_yes: false,
+            procfile: false,
+            codeowners: false,
+            repository: None,
+            license: None,
+            server: ServerConfig::default(),
+        }
+    }
+}
+
+impl ProjectConfig {
+    /// 从项目清单读取配置 / Load a whole project description from a TOML manifest (e.g. `axum-app.toml`)
+    ///
+    /// Fields use the same names as the generation metadata and fall back to
+    /// their defaults when missing. A `preset` without a `[features]` table
+    /// selects the preset's feature set.
+    ///
+    /// # Errors
+    /// * `CliError::Config` if the file can't be read or isn't a valid manifest
+    /// * `CliError::InvalidName` if `project_name` is not a valid crate name
+    pub fn from_toml_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            CliError::Config(format!(
+                "❌ 无法读取项目清单 / Cannot read project manifest '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let manifest: toml::Table = toml::from_str(&content).map_err(|e| malformed(path, e))?;
+        let has_features = manifest.contains_key("features");
+
+        let mut config: Self = manifest.try_into().map_err(|e| malformed(path, e))?;
+        if let (Some(preset), false) = (config.preset, has_features) {
+            config.features = preset.to_feature_set();
         }
+
+        validate_project_name(&config.project_name)
+            .map_err(|e| CliError::InvalidName(config.project_name.clone(), e))?;
+        Ok(config)
     }
 }
 
+/// 用户配置文件名 / File name of the optional user config in the home directory
+pub const USER_CONFIG_FILE: &str = ".axum-app-create.toml";
+
+/// 用户配置路径 / Path of the user config (`~/.axum-app-create.toml`), if a home directory is set
+pub fn user_config_path() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(USER_CONFIG_FILE))
+}
+
+fn malformed(path: &Path, e: toml::de::Error) -> CliError {
+    CliError::Config(format!(
+        "❌ 项目清单格式错误 / Malformed project manifest '{}': {}",
+        path.display(),
+        e
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -304,6 +736,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_database_option_sql_dialect() {
+        assert_eq!(DatabaseOption::None.sql_dialect(), None);
+        assert_eq!(DatabaseOption::PostgreSQL.sql_dialect(), Some("postgres"));
+        assert_eq!(DatabaseOption::SQLite.sql_dialect(), Some("sqlite"));
+        assert_eq!(DatabaseOption::Both.sql_dialect(), Some("postgres"));
+    }
+
+    #[test]
+    fn test_locale_text() {
+        assert_eq!(Locale::En.text("Fix", "修复建议"), "Fix");
+        assert_eq!(Locale::Zh.text("Fix", "修复建议"), "修复建议");
+        assert_eq!(Locale::Both.text("Fix", "修复建议"), "修复建议 / Fix");
+        assert_eq!(Locale::default(), Locale::Both);
+    }
+
     #[test]
     fn test_project_mode_default_is_single() {
         assert_eq!(ProjectMode::default(), ProjectMode::Single);
@@ -329,6 +777,14 @@ mod tests {
         assert!(!fs.authentication);
         assert!(fs.logging);
         assert!(!fs.biz_error);
+        assert!(!fs.openapi);
+        assert!(!fs.sse);
+        assert!(!fs.sessions);
+        assert!(!fs.multipart);
+        assert!(!fs.cache);
+        assert!(!fs.cors);
+        assert!(!fs.compression);
+        assert!(!fs.metrics);
     }
 
     #[test]
@@ -349,11 +805,114 @@ mod tests {
         assert!(fs.biz_error);
     }
 
+    #[test]
+    fn test_preset_description() {
+        let description = Preset::Api.description();
+        assert!(description.contains("PostgreSQL"));
+        assert!(description.contains("auth"));
+    }
+
     #[test]
     fn test_project_config_default() {
         let config = ProjectConfig::default();
         assert_eq!(config.mode, ProjectMode::Single);
+        assert_eq!(config.workspace_layout, WorkspaceLayout::Layered);
         assert!(config.preset.is_none());
         assert!(!config.ci);
+        assert!(!config.cargo_features);
+        assert!(!config.testcontainers);
+        assert_eq!(config.api_prefix, "/api");
+        assert!(config.api_routes.is_empty());
+        assert!(config.api_versions.is_empty());
+        assert!(!config.sql_lint);
+        assert!(!config.normalize_path);
+        assert!(!config.startup_banner);
+        assert!(!config.bare);
+        assert!(!config.no_update);
+        assert!(!config.with_env);
+        assert!(!config.db_url_parts);
+        assert!(!config.no_git);
+        assert!(config.overwrite_policy.is_none());
+        assert_eq!(config.verbosity, Verbosity::Normal);
+        assert!(config.post_generate_hook.is_none());
+        assert!(!config.assume_yes);
+        assert_eq!(config.ci_provider, CiProvider::GitHub);
+        assert!(!config.procfile);
+        assert!(!config.codeowners);
+        assert!(config.repository.is_none());
+        assert_eq!(config.json_backend, JsonBackend::Serde);
+        assert_eq!(config.edition, Edition::E2024);
+        assert!(config.entities.is_empty());
+        assert_eq!(config.server.host, "127.0.0.1");
+        assert_eq!(config.server.port, 8080);
+    }
+
+    #[test]
+    fn test_from_toml_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("axum-app.toml");
+        std::fs::write(
+            &path,
+            r#"
+project_name = "manifest-app"
+mode = "Workspace"
+ci = true
+
+[features]
+database = "PostgreSQL"
+authentication = true
+logging = true
+biz_error = false
+
+[server]
+host = "0.0.0.0"
+port = 3000
+"#,
+        )
+        .unwrap();
+
+        let config = ProjectConfig::from_toml_file(&path).unwrap();
+        assert_eq!(config.project_name, "manifest-app");
+        assert_eq!(config.mode, ProjectMode::Workspace);
+        assert!(config.ci);
+        assert_eq!(config.features.database, DatabaseOption::PostgreSQL);
+        assert!(config.features.authentication);
+        assert_eq!(config.server.port, 3000);
+        // Unset fields keep their defaults
+        assert_eq!(config.api_prefix, "/api");
+    }
+
+    #[test]
+    fn test_from_toml_file_preset_features() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("axum-app.toml");
+        std::fs::write(&path, "project_name = \"preset-app\"\npreset = \"Api\"\n").unwrap();
+
+        let config = ProjectConfig::from_toml_file(&path).unwrap();
+        assert_eq!(config.features, Preset::Api.to_feature_set());
+    }
+
+    #[test]
+    fn test_from_toml_file_invalid_project_name() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("axum-app.toml");
+        std::fs::write(&path, "project_name = \"123-app\"\n").unwrap();
+
+        let result = ProjectConfig::from_toml_file(&path);
+        assert!(matches!(result, Err(CliError::InvalidName(name, _)) if name == "123-app"));
+
+        std::fs::write(&path, "project_name = [\"not\", \"a\", \"string\"]\n").unwrap();
+        let result = ProjectConfig::from_toml_file(&path);
+        assert!(matches!(result, Err(CliError::Config(_))));
+    }
+
+    #[test]
+    fn test_server_local_url() {
+        assert_eq!(ServerConfig::default().local_url(), "http://127.0.0.1:8080");
+        let public = ServerConfig {
+            host: "0.0.0.0".to_string(),
+            port: 3000,
+        };
+        assert_eq!(public.local_url(), "http://127.0.0.1:3000");
     }
 }
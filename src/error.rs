@@ -28,6 +28,9 @@ pub enum CliError {
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    #[error("Configuration error: {0}")]
+    Config(String),
+
     #[error("Prompt error: {0}")]
     PromptError(#[from] inquire::InquireError),
 }
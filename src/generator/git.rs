@@ -1,6 +1,6 @@
 // Git initialization
 //
-// This module handles git repository initialization.
+// This module handles git repository initialization and remote detection.
 
 use crate::error::{CliError, Result};
 use git2::{Repository, Signature};
@@ -10,7 +10,7 @@ use std::path::Path;
 ///
 /// This function:
 /// - Runs `git init` to create the repository
-/// - Creates a .gitignore file with standard Rust patterns
+/// - Creates a .gitignore file with standard Rust patterns, unless one exists
 /// - Stages all files
 /// - Creates an initial commit
 ///
@@ -32,10 +32,11 @@ pub fn init_git_repo(project_dir: &Path) -> Result<()> {
         }
     };
 
-    // Create .gitignore
+    // Create .gitignore (keep the one rendered from templates)
     let gitignore_path = project_dir.join(".gitignore");
-    let gitignore_content = get_gitignore_content();
-    std::fs::write(&gitignore_path, gitignore_content)?;
+    if !gitignore_path.exists() {
+        std::fs::write(&gitignore_path, get_gitignore_content())?;
+    }
 
     // Add all files to index
     let mut index = match repo.index() {
@@ -100,6 +101,42 @@ pub fn init_git_repo(project_dir: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Detect the `origin` remote of the git repository containing `dir`
+///
+/// `dir` may not exist yet (the directory about to be scaffolded into), so the
+/// lookup starts from its nearest existing ancestor.
+///
+/// SSH remotes (`git@host:owner/repo.git`) are turned into HTTPS URLs so they
+/// can be used as the Cargo `repository` field.
+///
+/// # Returns
+/// * `Some(url)` if `dir` is inside a repository with an `origin` remote
+/// * `None` otherwise
+pub fn detect_git_remote(dir: &Path) -> Option<String> {
+    let start = dir
+        .ancestors()
+        .find(|ancestor| !ancestor.as_os_str().is_empty() && ancestor.exists())
+        .unwrap_or(Path::new("."));
+    let repo = Repository::discover(start).ok()?;
+    let remote = repo.find_remote("origin").ok()?;
+    let url = remote.url()?.trim();
+    if url.is_empty() {
+        return None;
+    }
+    Some(remote_to_url(url))
+}
+
+/// Convert an scp-style SSH remote to an HTTPS URL, leaving other remotes as-is
+fn remote_to_url(remote: &str) -> String {
+    match remote
+        .strip_prefix("git@")
+        .and_then(|rest| rest.split_once(':'))
+    {
+        Some((host, path)) => format!("https://{}/{}", host, path.trim_end_matches(".git")),
+        None => remote.to_string(),
+    }
+}
+
 /// Get the .gitignore content for Rust projects
 fn get_gitignore_content() -> &'static str {
     r###"# Rust
@@ -126,6 +163,9 @@ Thumbs.db
 # Logs
 *.log
 
+# axum-app-create generation metadata
+.axum-app-create.json
+
 # Database
 *.db
 *.sqlite
@@ -163,6 +203,36 @@ mod tests {
         assert!(project_dir.join(".git").exists());
     }
 
+    #[test]
+    fn test_detect_git_remote() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(detect_git_remote(temp_dir.path()), None);
+
+        let repo = Repository::init(temp_dir.path()).unwrap();
+        assert_eq!(detect_git_remote(temp_dir.path()), None);
+
+        repo.remote("origin", "git@github.com:acme/shop.git")
+            .unwrap();
+        let nested = temp_dir.path().join("new-app");
+        std::fs::create_dir(&nested).unwrap();
+        assert_eq!(
+            detect_git_remote(&nested).as_deref(),
+            Some("https://github.com/acme/shop")
+        );
+    }
+
+    #[test]
+    fn test_remote_to_url() {
+        assert_eq!(
+            remote_to_url("git@gitlab.com:group/app.git"),
+            "https://gitlab.com/group/app"
+        );
+        assert_eq!(
+            remote_to_url("https://github.com/acme/shop.git"),
+            "https://github.com/acme/shop.git"
+        );
+    }
+
     #[test]
     fn test_gitignore_content() {
         let content = get_gitignore_content();
@@ -170,5 +240,6 @@ mod tests {
         assert!(content.contains(".env"));
         assert!(content.contains("Cargo.lock"));
         assert!(content.contains(".DS_Store"));
+        assert!(content.contains(".axum-app-create.json"));
     }
 }
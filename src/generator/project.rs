@@ -3,191 +3,658 @@
 // This module handles the main project generation logic.
 
 use crate::config::ProjectConfig;
-use crate::config::ProjectMode;
+use crate::config::{CiProvider, OverwritePolicy, ProjectMode};
 use crate::error::{CliError, Result};
-use crate::template::context::TemplateContext;
+use crate::generator::reporter::Reporter;
+use crate::template::context::{TemplateContext, workspace_crates};
 use crate::template::engine::TemplateEngine;
-use crate::template::templates::{
-    get_ci_templates, get_single_mode_templates, get_workspace_mode_templates,
+use crate::template::resolver::{ResolvedTemplate, TemplateResolver, validate_output_path};
+use crate::template::templates::{get_api_version_templates, get_entity_templates};
+use crate::updater::checksum::ChecksumCalculator;
+use crate::updater::metadata::MetadataManager;
+use crate::utils::validator::{
+    validate_edition, validate_host, validate_jwt_algorithm, validate_license,
+    validate_testcontainers, validate_workspace_packages,
 };
-use std::path::Path;
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Repository-level files omitted by `--bare` (paths ending in `/` are directories)
+///
+/// `.env` is included because it holds generated secrets and is normally kept
+/// out of version control by the omitted `.gitignore`.
+const REPO_LEVEL_FILES: &[&str] = &[
+    "README.md",
+    "SECURITY.md",
+    ".gitignore",
+    ".editorconfig",
+    ".github/",
+    ".gitlab-ci.yml",
+    "Dockerfile",
+    "docker-entrypoint.sh",
+    ".dockerignore",
+    "docker-compose.yml",
+    "Procfile",
+    ".sqlfluff",
+    ".env",
+    "LICENSE",
+    "LICENSE-MIT",
+    "LICENSE-APACHE",
+];
+
+/// 生成报告 / Report of the files produced by a generation run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenerationReport {
+    /// 已创建（或 dry-run 时将创建）的文件 / Files created (or that would be, in dry-run mode)
+    pub files_created: Vec<String>,
+    /// 渲染为空而跳过的模板 / Templates skipped because they rendered to empty content
+    pub skipped_empty: Vec<String>,
+    /// 因已存在而保留的文件 / Existing files kept by the `merge` or `skip` overwrite policy
+    pub skipped_existing: Vec<String>,
+}
 
 /// Generate a new project with the given configuration
 ///
 /// This function orchestrates the entire project generation process:
 /// - Creates project directory
 /// - Renders and writes all template files
-/// - Initializes git repository
+/// - Initializes git repository (skipped for `--bare` and `--no-git`)
 ///
 /// # Arguments
 /// * `project_dir` - Path where the project should be created
 /// * `config` - Project configuration
 /// * `interactive` - Whether to prompt for user input on conflicts
-/// * `force` - Force overwrite if directory exists
+/// * `force` - Force overwrite if directory exists (overrides `config.overwrite_policy`)
+/// * `dry_run` - Render every template and report the files without writing
+///   anything, deleting the existing directory or running git/cargo
 ///
 /// # Returns
-/// * `Ok(())` if generation succeeded
+/// * `Ok(GenerationReport)` if generation succeeded
 /// * `Err(CliError)` if generation failed
 pub fn generate_project(
     project_dir: &Path,
     config: &ProjectConfig,
     interactive: bool,
     force: bool,
-) -> Result<()> {
+    dry_run: bool,
+) -> Result<GenerationReport> {
+    validate_edition(config.edition, &config.features).map_err(CliError::ValidationError)?;
+    validate_host(&config.server.host).map_err(CliError::ValidationError)?;
+    if let Some(auth) = &config.authentication {
+        validate_jwt_algorithm(&auth.algorithm).map_err(CliError::ValidationError)?;
+    }
+    if let Some(license) = &config.license {
+        validate_license(license).map_err(CliError::ValidationError)?;
+    }
+    if config.testcontainers {
+        validate_testcontainers(config.mode, config.features.database)
+            .map_err(CliError::ValidationError)?;
+    }
+    if let Some(crates) = TemplateContext::from_config(config).workspace_crates {
+        let names: Vec<&str> = crates.iter().map(|c| c.package_name.as_str()).collect();
+        validate_workspace_packages(&names).map_err(CliError::ValidationError)?;
+    }
+
+    // Resolve templates first so a bad --template-dir fails before touching disk
+    let resolver = TemplateResolver::new(config.template_dir.clone());
+    let templates = resolver.resolve(
+        config.mode,
+        config.workspace_layout,
+        config.ci.then_some(config.ci_provider),
+        config.license.as_deref(),
+    )?;
+    let engine = TemplateEngine::with_partials(&resolver.partials()?)?;
+
+    let locale = config.locale;
+    let reporter = Reporter::new(config.verbosity);
+
+    // A path the user already had (even if --force replaces it) is never cleaned up
+    let existed = project_dir.exists();
+    let policy = if force {
+        Some(OverwritePolicy::Replace)
+    } else {
+        config.overwrite_policy
+    };
+    let force = policy == Some(OverwritePolicy::Replace);
+    let merge = existed && policy == Some(OverwritePolicy::Merge);
+
     // Validate project directory doesn't exist
-    if project_dir.exists() {
-        // --force flag: delete and recreate
-        if force {
-            println!(
-                "🗑️  --force: 正在删除现有目录 / Deleting existing directory: '{}'",
+    if existed {
+        // --overwrite-policy skip: render only to report what was left alone
+        if policy == Some(OverwritePolicy::Skip) {
+            reporter.progress(format!(
+                "⏭️  --overwrite-policy skip: {}: '{}'",
+                locale.text(
+                    "Directory already exists, nothing generated",
+                    "目录已存在，未生成任何文件"
+                ),
                 project_dir.display()
-            );
+            ));
+            let (files, skipped_empty) = render_templates(config, templates, &engine)?;
+            return Ok(GenerationReport {
+                files_created: Vec::new(),
+                skipped_empty,
+                skipped_existing: files.into_iter().map(|(path, _)| path).collect(),
+            });
+        } else if merge {
+            reporter.progress(format!(
+                "🔀 --overwrite-policy merge: {}: '{}'",
+                locale.text("Keeping existing files in", "保留现有文件"),
+                project_dir.display()
+            ));
+        // --force flag (or --overwrite-policy replace): delete and recreate
+        } else if force && dry_run {
+            reporter.progress(format!(
+                "🗑️  --force: {}: '{}'",
+                locale.text("Would delete existing directory", "将删除现有目录"),
+                project_dir.display()
+            ));
+        } else if force {
+            reporter.progress(format!(
+                "🗑️  --force: {}: '{}'",
+                locale.text("Deleting existing directory", "正在删除现有目录"),
+                project_dir.display()
+            ));
             std::fs::remove_dir_all(project_dir)?;
-        } else if !interactive {
-            // In non-interactive mode without --force, fail immediately
+        } else if !interactive || dry_run || config.assume_yes {
+            // In non-interactive or dry-run mode without --force, fail immediately;
+            // --yes never picks the destructive Overwrite answer on its own
             return Err(CliError::Generation(format!(
-                "❌ 目录已存在 / Directory already exists: '{}'\n\n\
-                 💡 修复建议 / Fix:\n\
-                 - 删除现有目录 / Remove existing directory: rm -rf {}\n\
-                 - 使用不同的名称 / Use a different name\n\
-                 - 使用 --force 标志强制覆盖 / Use --force flag to overwrite\n\
-                 - 查看帮助 / View help: axum-app-create --help",
+                "❌ {}: '{}'\n\n\
+                 💡 {}:\n\
+                 - {}: rm -rf {}\n\
+                 - {}\n\
+                 - {}\n\
+                 - {}\n\
+                 - {}: axum-app-create --help",
+                locale.text("Directory already exists", "目录已存在"),
                 project_dir.display(),
-                project_dir.display()
+                locale.text("Fix", "修复建议"),
+                locale.text("Remove existing directory", "删除现有目录"),
+                project_dir.display(),
+                locale.text("Use a different name", "使用不同的名称"),
+                locale.text("Use --force flag to overwrite", "使用 --force 标志强制覆盖"),
+                locale.text(
+                    "Use --overwrite-policy merge to keep existing files",
+                    "使用 --overwrite-policy merge 保留现有文件"
+                ),
+                locale.text("View help", "查看帮助"),
             )));
         } else {
             // In interactive mode, prompt for action
             println!(
-                "\n⚠️  警告 / Warning: 目录已存在 / Directory already exists: '{}'",
+                "\n⚠️  {}: {}: '{}'",
+                locale.text("Warning", "警告"),
+                locale.text("Directory already exists", "目录已存在"),
+                project_dir.display()
+            );
+            println!(
+                "📁 {}: {}",
+                locale.text("Location", "位置"),
                 project_dir.display()
             );
-            println!("📁 位置 / Location: {}", project_dir.display());
             println!();
 
             // Use inquire for user choice
             let options = vec![
-                "覆盖 / Overwrite - Delete existing directory and regenerate",
-                "取消 / Cancel - Abort project generation",
-                "重命名 / Rename - Keep existing directory, use different name",
+                format!(
+                    "{} - Delete existing directory and regenerate",
+                    locale.text("Overwrite", "覆盖")
+                ),
+                format!(
+                    "{} - Abort project generation",
+                    locale.text("Cancel", "取消")
+                ),
+                format!(
+                    "{} - Keep existing directory, use different name",
+                    locale.text("Rename", "重命名")
+                ),
             ];
 
-            let ans = inquire::Select::new("请选择操作 / Choose an action:", options).prompt()?;
+            let ans =
+                inquire::Select::new(&locale.text("Choose an action:", "请选择操作"), options)
+                    .raw_prompt()?
+                    .index;
 
             match ans {
-                "覆盖 / Overwrite - Delete existing directory and regenerate" => {
-                    println!("🗑️  正在删除现有目录 / Deleting existing directory...");
+                0 => {
+                    println!(
+                        "🗑️  {}...",
+                        locale.text("Deleting existing directory", "正在删除现有目录")
+                    );
                     std::fs::remove_dir_all(project_dir)?;
-                    println!("✓ 已删除 / Deleted");
+                    println!("✓ {}", locale.text("Deleted", "已删除"));
                 }
-                "取消 / Cancel - Abort project generation" => {
-                    println!("❌ 已取消 / Aborted");
+                1 => {
+                    println!("❌ {}", locale.text("Aborted", "已取消"));
                     return Err(CliError::Generation(
-                        "项目生成已取消 / Project generation cancelled by user".to_string(),
+                        locale.text("Project generation cancelled by user", "项目生成已取消"),
                     ));
                 }
-                "重命名 / Rename - Keep existing directory, use different name" => {
+                _ => {
                     println!(
-                        "❌ 请使用不同的项目名称重新运行 / Please run again with a different project name"
+                        "❌ {}",
+                        locale.text(
+                            "Please run again with a different project name",
+                            "请使用不同的项目名称重新运行"
+                        )
                     );
-                    return Err(CliError::Generation(
-                        "请使用不同的项目名称 / Please use a different project name".to_string(),
-                    ));
-                }
-                _ => {
-                    return Err(CliError::Generation(
-                        "无效选择 / Invalid choice".to_string(),
-                    ));
+                    return Err(CliError::Generation(locale.text(
+                        "Please use a different project name",
+                        "请使用不同的项目名称",
+                    )));
                 }
             }
         }
     }
 
-    println!(
-        "\n🚀 正在创建项目 / Creating project: {}",
+    reporter.progress(format!(
+        "\n🚀 {}: {}",
+        locale.text("Creating project", "正在创建项目"),
         config.project_name
-    );
-    println!("📁 位置 / Location: {}", project_dir.display());
+    ));
+    reporter.progress(format!(
+        "📁 {}: {}",
+        locale.text("Location", "位置"),
+        project_dir.display()
+    ));
+
+    let executables: HashSet<String> = templates
+        .values()
+        .filter(|template| template.executable)
+        .map(|template| template.path.clone())
+        .collect();
+    let (mut files, skipped_empty) = render_templates(config, templates, &engine)?;
+
+    // Under `merge`, files already on disk are reported and left alone
+    let mut skipped_existing = Vec::new();
+    if merge {
+        files.retain(|(path, _)| {
+            let exists = project_dir.join(path).exists();
+            if exists {
+                skipped_existing.push(path.clone());
+            }
+            !exists
+        });
+    }
+    let report = GenerationReport {
+        files_created: files.iter().map(|(path, _)| path.clone()).collect(),
+        skipped_empty,
+        skipped_existing,
+    };
+
+    if dry_run {
+        reporter.progress(format!(
+            "\n📝 {}:",
+            locale.text(
+                "Files that would be created (dry run)",
+                "将创建的文件（预演）"
+            )
+        ));
+        for (path, content) in &files {
+            reporter.progress(format!("  • {} ({} bytes)", path, content.len()));
+        }
+        return Ok(report);
+    }
 
     // Create project directory
     if let Err(e) = std::fs::create_dir_all(project_dir) {
-        return handle_permission_error(e, project_dir);
+        handle_permission_error(e, project_dir)?;
     }
+    let mut cleanup = CleanupGuard {
+        dir: project_dir,
+        armed: !existed,
+    };
 
-    // Create template context
-    let ctx = TemplateContext::from_config(config);
+    // Write files in parallel; `files` is sorted, so the report order stays stable
+    reporter.progress(format!(
+        "\n📝 {}:",
+        locale.text("Generating files", "正在生成文件")
+    ));
+
+    files
+        .par_iter()
+        .try_for_each(|(path, content)| -> Result<()> {
+            write_file(project_dir, path, content)?;
+            if executables.contains(path) {
+                set_executable(&project_dir.join(path))?;
+            }
+            Ok(())
+        })?;
+    for (path, _) in &files {
+        reporter.progress(format!("  ✓ {} {}", locale.text("Created", "已创建"), path));
+    }
+    for path in &report.skipped_existing {
+        reporter.progress(format!(
+            "  ⏭ {} {} ({})",
+            locale.text("Skipped", "已跳过"),
+            path,
+            locale.text("already exists", "已存在")
+        ));
+    }
+
+    // Initialize git repository (a bare crate lives in its parent's repository)
+    let has_repo = merge && project_dir.join(".git").exists();
+    if !config.bare && !config.no_git && !has_repo {
+        reporter.progress(format!(
+            "\n🔧 {}...",
+            locale.text("Initializing git repository", "正在初始化 git 仓库")
+        ));
+        super::git::init_git_repo(project_dir)?;
+    }
 
-    // Create template engine
-    let engine = TemplateEngine::new();
+    // Record configuration, checksums and snapshots so files can be regenerated later
+    // (`files_created` is sorted, so checksums are read in a stable order)
+    let checksums = ChecksumCalculator::calculate_all(project_dir, &report.files_created)?;
+    let snapshots = files.iter().cloned().collect();
+    MetadataManager::create(project_dir, config, checksums, snapshots)?;
+
+    // Update dependencies to latest compatible versions (unless --no-update)
+    if !config.no_update {
+        reporter.progress(format!(
+            "📦 {}...",
+            locale.text(
+                "Updating dependencies to latest compatible versions",
+                "正在将依赖更新到最新兼容版本"
+            )
+        ));
+        let update_output = std::process::Command::new("cargo")
+            .arg("update")
+            .current_dir(project_dir)
+            .output();
+        match update_output {
+            Ok(output) if output.status.success() => {
+                reporter.progress(format!(
+                    "  ✓ {}",
+                    locale.text("Dependencies updated", "依赖已更新")
+                ));
+            }
+            _ => {
+                reporter.progress(format!(
+                    "  ⚠ {}",
+                    locale.text(
+                        "Could not update dependencies, run `cargo update` manually",
+                        "无法更新依赖，请手动运行 `cargo update`"
+                    )
+                ));
+            }
+        }
+    }
 
-    // Select templates based on project mode
-    let mut templates = match config.mode {
-        ProjectMode::Single => get_single_mode_templates(),
-        ProjectMode::Workspace => get_workspace_mode_templates(),
-    };
+    // Verify workspace Cargo.toml files (Requirement 5.5)
+    if config.mode == ProjectMode::Workspace {
+        let crates = TemplateContext::from_config(config)
+            .workspace_crates
+            .unwrap_or_default();
+        let required_files = std::iter::once("Cargo.toml".to_string()).chain(
+            crates
+                .iter()
+                .map(|krate| format!("{}/Cargo.toml", krate.name)),
+        );
+        for file in required_files {
+            if !project_dir.join(&file).exists() {
+                return Err(CliError::Generation(format!(
+                    "❌ {}: {}: {}",
+                    locale.text("Workspace verification failed", "工作区验证失败"),
+                    locale.text("Missing file", "缺少文件"),
+                    file
+                )));
+            }
+        }
+        reporter.progress(format!(
+            "  ✓ {}",
+            locale.text("Workspace structure verified", "工作区结构已验证")
+        ));
+    }
+
+    // The project is complete: a failing hook reports an error but keeps it
+    cleanup.armed = false;
+    if let Some(hook) = &config.post_generate_hook {
+        reporter.progress(format!(
+            "\n🪝 {}: {}",
+            locale.text("Running post-generation hook", "正在运行生成后钩子"),
+            hook
+        ));
+        run_post_hook(project_dir, hook)?;
+    }
 
-    // Append CI templates if enabled
-    if config.ci {
-        templates.extend(get_ci_templates());
+    Ok(report)
+}
+
+/// Run the post-generation `hook` through the shell in `project_dir`
+///
+/// The hook's output is streamed to the terminal.
+///
+/// # Errors
+/// * `CliError::Generation` if the hook cannot be started or exits non-zero
+fn run_post_hook(project_dir: &Path, hook: &str) -> Result<()> {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let status = std::process::Command::new(shell)
+        .args([flag, hook])
+        .current_dir(project_dir)
+        .status();
+
+    match status {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(CliError::Generation(format!(
+            "❌ 生成后钩子失败 / Post-generation hook `{}` failed ({})\n\n\
+             💡 修复建议 / Fix: 项目已生成，修复后可在 {} 中手动运行该命令 / The project was generated; fix the command and run it manually in {}",
+            hook,
+            status,
+            project_dir.display(),
+            project_dir.display()
+        ))),
+        Err(e) => Err(CliError::Generation(format!(
+            "❌ 无法运行生成后钩子 / Could not run post-generation hook `{}`: {}",
+            hook, e
+        ))),
     }
+}
 
-    // Render and write each template
-    println!("\n📝 Generating files:");
+/// 由目录推导项目名 / Derive the default project name from the target directory
+///
+/// Uses the last path component, resolving `.` and `..` against the current
+/// directory first, so `services/billing` gives `billing` and `.` gives the
+/// name of the current directory.
+///
+/// # Examples
+/// ```
+/// use axum_app_create::generator::project::project_name_from_dir;
+/// use std::path::Path;
+///
+/// assert_eq!(project_name_from_dir(Path::new("services/billing")).as_deref(), Some("billing"));
+/// ```
+pub fn project_name_from_dir(dir: &Path) -> Option<String> {
+    let name = match dir.file_name() {
+        Some(name) => name.to_os_string(),
+        None => dir.canonicalize().ok()?.file_name()?.to_os_string(),
+    };
+    name.into_string().ok()
+}
 
-    for (name, template_file) in templates {
-        // Render template
-        let rendered = engine.render_template(name, template_file.content, &ctx)?;
+/// 失败清理 / Removes a partially generated project directory when dropped while armed
+///
+/// Armed only when this run created the directory, and disarmed once
+/// generation succeeds, so any early `Err` return leaves nothing behind.
+struct CleanupGuard<'a> {
+    dir: &'a Path,
+    armed: bool,
+}
 
-        // Skip files that render to empty content (conditional templates)
-        if rendered.trim().is_empty() {
-            continue;
+impl Drop for CleanupGuard<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            let _ = std::fs::remove_dir_all(self.dir);
         }
+    }
+}
+
+/// A rendered file: `(output path, content)`
+pub type RenderedFile = (String, String);
 
-        // Write file
-        write_file(project_dir, template_file.path, &rendered)?;
+/// Render all project files for `config`
+///
+/// Returns `(output path, content)` pairs. Templates that render to empty
+/// content (conditional templates) are left out.
+pub fn render_project_files(config: &ProjectConfig) -> Result<Vec<RenderedFile>> {
+    let resolver = TemplateResolver::new(config.template_dir.clone());
+    let templates = resolver.resolve(
+        config.mode,
+        config.workspace_layout,
+        config.ci.then_some(config.ci_provider),
+        config.license.as_deref(),
+    )?;
+    let engine = TemplateEngine::with_partials(&resolver.partials()?)?;
+    Ok(render_templates(config, templates, &engine)?.0)
+}
 
-        println!("  ✓ Created {}", template_file.path);
+/// Render a single project file without writing anything
+///
+/// Templates are resolved from the built-ins layered with `template_dir` (which
+/// takes the place of `config.template_dir`), and only `file_key` is rendered.
+/// Per-entity files are matched against their rendered output paths.
+///
+/// # Returns
+/// * `Ok(Some(content))` with the rendered file
+/// * `Ok(None)` if no template produces `file_key`, or it renders to empty content
+/// * `Err(CliError)` if templates cannot be resolved or rendering fails
+pub fn render_file(
+    config: &ProjectConfig,
+    file_key: &str,
+    template_dir: Option<PathBuf>,
+) -> Result<Option<String>> {
+    if config.bare && is_repo_level(file_key) {
+        return Ok(None);
     }
 
-    // Initialize git repository
-    println!("\n🔧 Initializing git repository...");
-    super::git::init_git_repo(project_dir)?;
+    let resolver = TemplateResolver::new(template_dir);
+    let templates = resolver.resolve(
+        config.mode,
+        config.workspace_layout,
+        config.ci.then_some(config.ci_provider),
+        config.license.as_deref(),
+    )?;
+    let engine = TemplateEngine::with_partials(&resolver.partials()?)?;
+    let ctx = TemplateContext::from_config(config);
 
-    // Update dependencies to latest compatible versions
-    println!("📦 Updating dependencies to latest compatible versions...");
-    let update_output = std::process::Command::new("cargo")
-        .arg("update")
-        .current_dir(project_dir)
-        .output();
-    match update_output {
-        Ok(output) if output.status.success() => {
-            println!("  ✓ Dependencies updated");
+    let rendered = if let Some(template_file) = templates.get(file_key) {
+        Some(engine.render_template(file_key, &template_file.content, &ctx)?)
+    } else {
+        let entity_templates = get_entity_templates(config.mode, config.workspace_layout);
+        let mut found = None;
+        'entities: for entity in &ctx.entities {
+            let entity_ctx = ctx.with_entity(entity);
+            for (name, template_file) in &entity_templates {
+                if engine.render_template(name, template_file.path, &entity_ctx)? == file_key {
+                    found =
+                        Some(engine.render_template(name, template_file.content, &entity_ctx)?);
+                    break 'entities;
+                }
+            }
         }
-        _ => {
-            println!("  ⚠ Could not update dependencies, run `cargo update` manually");
+        if found.is_none() {
+            let version_templates = get_api_version_templates(config.mode);
+            'versions: for version in &ctx.api_versions {
+                let version_ctx = ctx.with_api_version(version);
+                for (name, template_file) in &version_templates {
+                    if engine.render_template(name, template_file.path, &version_ctx)? == file_key {
+                        found = Some(engine.render_template(
+                            name,
+                            template_file.content,
+                            &version_ctx,
+                        )?);
+                        break 'versions;
+                    }
+                }
+            }
+        }
+        found
+    };
+
+    Ok(rendered.filter(|content| !content.trim().is_empty()))
+}
+
+/// Whether `path` is a repository-level file omitted by `--bare`
+fn is_repo_level(path: &str) -> bool {
+    REPO_LEVEL_FILES.iter().any(|file| {
+        if file.ends_with('/') {
+            path.starts_with(file)
+        } else {
+            path == *file
+        }
+    })
+}
+
+/// Render resolved templates plus per-entity and per-version templates for `config`
+///
+/// Returns the rendered `(output path, content)` pairs sorted by path, and the
+/// paths of templates skipped because they rendered to empty content.
+fn render_templates(
+    config: &ProjectConfig,
+    templates: HashMap<String, ResolvedTemplate>,
+    engine: &TemplateEngine,
+) -> Result<(Vec<RenderedFile>, Vec<String>)> {
+    // Create template context
+    let ctx = TemplateContext::from_config(config);
+
+    // Sort by output path first so rendering, its errors and the reports
+    // don't depend on HashMap iteration order
+    let mut templates: Vec<(String, ResolvedTemplate)> = templates.into_iter().collect();
+    templates.sort_by(|(_, a), (_, b)| a.path.cmp(&b.path));
+
+    // Render in parallel (the collected order is kept); conditional templates that render empty are skipped
+    let rendered = templates
+        .into_par_iter()
+        .filter(|(_, template_file)| !(config.bare && is_repo_level(&template_file.path)))
+        .map(|(name, template_file)| {
+            let rendered = engine.render_template(&name, &template_file.content, &ctx)?;
+            Ok((template_file.path, rendered))
+        })
+        .collect::<Result<Vec<RenderedFile>>>()?;
+
+    let (empty, mut files): (Vec<_>, Vec<_>) = rendered
+        .into_iter()
+        .partition(|(_, content)| content.trim().is_empty());
+    let mut skipped_empty: Vec<String> = empty.into_iter().map(|(path, _)| path).collect();
+
+    // Render per-entity templates (migrations, models)
+    let mut entity_templates: Vec<_> = get_entity_templates(config.mode, config.workspace_layout)
+        .into_iter()
+        .collect();
+    entity_templates.sort_by_key(|(name, _)| *name);
+    for entity in &ctx.entities {
+        let entity_ctx = ctx.with_entity(entity);
+
+        for (name, template_file) in &entity_templates {
+            let path = engine.render_template(name, template_file.path, &entity_ctx)?;
+            let rendered = engine.render_template(name, template_file.content, &entity_ctx)?;
+            files.push((path, rendered));
         }
     }
 
-    // Verify workspace Cargo.toml files (Requirement 5.5)
-    if config.mode == ProjectMode::Workspace {
-        let required_files = [
-            "Cargo.toml",
-            "api/Cargo.toml",
-            "domain/Cargo.toml",
-            "infrastructure/Cargo.toml",
-            "common/Cargo.toml",
-        ];
-        for file in &required_files {
-            if !project_dir.join(file).exists() {
-                return Err(CliError::Generation(format!(
-                    "❌ 工作区验证失败 / Workspace verification failed: 缺少文件 / Missing file: {}",
-                    file
-                )));
-            }
+    // Render per-version API modules
+    let mut version_templates: Vec<_> =
+        get_api_version_templates(config.mode).into_iter().collect();
+    version_templates.sort_by_key(|(name, _)| *name);
+    for version in &ctx.api_versions {
+        let version_ctx = ctx.with_api_version(version);
+
+        for (name, template_file) in &version_templates {
+            let path = engine.render_template(name, template_file.path, &version_ctx)?;
+            let rendered = engine.render_template(name, template_file.content, &version_ctx)?;
+            files.push((path, rendered));
         }
-        println!("  ✓ Workspace structure verified");
     }
 
-    Ok(())
+    files.sort();
+    skipped_empty.sort();
+
+    Ok((files, skipped_empty))
 }
 
 /// Handle permission errors with helpful suggestions
@@ -224,6 +691,71 @@ fn handle_permission_error(error: std::io::Error, path: &Path) -> Result<()> {
     }
 }
 
+/// Maximum path length supported by the platform
+///
+/// Windows limits paths to `MAX_PATH` (260 characters including the trailing NUL).
+/// The limit is kept even when long-path support is enabled, since tools run
+/// on the generated project (git, older toolchains) may not support it.
+#[cfg(windows)]
+const MAX_PATH_LEN: usize = 259;
+
+/// Maximum path length supported by the platform (`PATH_MAX` on Unix)
+#[cfg(not(windows))]
+const MAX_PATH_LEN: usize = 4096;
+
+/// Check that a path fits within the given length limit
+///
+/// # Arguments
+/// * `path` - The path that is about to be written
+/// * `max_len` - Maximum allowed length of the absolute path
+///
+/// # Returns
+/// * `Ok(())` if the path is short enough
+/// * `Err(CliError)` with a suggestion to shorten the base path otherwise
+fn check_path_length(path: &Path, max_len: usize) -> Result<()> {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let len = absolute.as_os_str().len();
+
+    if len > max_len {
+        return Err(CliError::Generation(format!(
+            "❌ 文件路径过长 / File path too long ({} 个字符，最大 {} / {} chars, max {})\n\n\
+             📄 路径 / Path: {}\n\n\
+             💡 修复建议 / Fix:\n\
+             1. 使用更短的基础目录 / Use a shorter base directory (e.g. C:\\dev)\n\
+             2. 使用更短的项目名称 / Use a shorter project name\n\n\
+             📖 查看帮助 / View help: axum-app-create --help",
+            len,
+            max_len,
+            len,
+            max_len,
+            absolute.display()
+        )));
+    }
+
+    Ok(())
+}
+
+/// 校验写入位置 / Ensure `file_path` resolves to a descendant of `project_dir`
+///
+/// Canonicalizes the deepest part of `file_path` that already exists, so a
+/// symlinked directory or file pointing outside the project is caught too.
+fn ensure_inside_project(project_dir: &Path, file_path: &Path, key: &str) -> Result<()> {
+    let root = project_dir.canonicalize()?;
+    let existing = file_path
+        .ancestors()
+        .find(|path| path.symlink_metadata().is_ok())
+        .unwrap_or(project_dir);
+
+    match existing.canonicalize() {
+        Ok(resolved) if resolved.starts_with(&root) => Ok(()),
+        _ => Err(CliError::Template(format!(
+            "❌ 模板路径超出项目目录 / Template path escapes the project directory: '{}'\n\n\
+             💡 修复建议 / Fix: 检查项目内指向外部的符号链接 / Check for symlinks inside the project pointing outside it",
+            key
+        ))),
+    }
+}
+
 /// Write a file to the project directory
 ///
 /// # Arguments
@@ -233,10 +765,23 @@ fn handle_permission_error(error: std::io::Error, path: &Path) -> Result<()> {
 ///
 /// # Returns
 /// * `Ok(())` if write succeeded
-/// * `Err(CliError)` if write failed
+/// * `Err(CliError)` if `relative_path` escapes `project_dir` or the write failed
 pub fn write_file(project_dir: &Path, relative_path: &str, content: &str) -> Result<()> {
+    // Never write outside the project, whatever a template key says
+    validate_output_path(relative_path)?;
+
     let file_path = project_dir.join(relative_path);
 
+    // Fail early with guidance instead of a raw OS error on over-long paths
+    check_path_length(&file_path, MAX_PATH_LEN)?;
+
+    // Symlinks already on disk (e.g. when merging into an existing directory)
+    // can still redirect a relative path, so check where it really lands
+    if let Err(e) = std::fs::create_dir_all(project_dir) {
+        return handle_permission_error(e, project_dir);
+    }
+    ensure_inside_project(project_dir, &file_path, relative_path)?;
+
     // Create parent directories if needed
     if let Some(parent) = file_path.parent()
         && let Err(e) = std::fs::create_dir_all(parent)
@@ -252,6 +797,22 @@ pub fn write_file(project_dir: &Path, relative_path: &str, content: &str) -> Res
     Ok(())
 }
 
+/// Mark a generated file as executable (0o755); a no-op on non-Unix platforms
+#[cfg(unix)]
+fn set_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    if let Err(e) = std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755)) {
+        return handle_permission_error(e, path);
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
 /// Ensure a directory exists in the project
 ///
 /// # Arguments
@@ -331,63 +892,107 @@ Happy hacking! 🦀
 }
 
 /// Get success message for workspace mode project generation
+///
+/// Labels follow `config.locale`.
 pub fn get_success_message_with_config(project_dir: &Path, config: &ProjectConfig) -> String {
     use chrono::Utc;
 
+    let locale = config.locale;
     let generation_time = Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
     let project_name = &config.project_name;
+    // Pad "Label:" so the values line up (exactly as before for English)
+    let label = |en: &str, zh: &str| format!("{:<13} ", format!("{}:", locale.text(en, zh)));
 
     let mode_info = match config.mode {
-        ProjectMode::Workspace => "\n📦 Mode:         Workspace (multi-crate)\n\
-             📁 Crates:       api, domain, infrastructure, common"
-            .to_string(),
-        ProjectMode::Single => "\n📦 Mode:         Single package".to_string(),
+        ProjectMode::Workspace => {
+            let crates: Vec<String> = workspace_crates(project_name, config.workspace_layout)
+                .into_iter()
+                .map(|c| c.name)
+                .collect();
+            format!(
+                "\n📦 {}{}\n📁 {}{}",
+                label("Mode", "模式"),
+                locale.text("Workspace (multi-crate)", "工作区（多 crate）"),
+                label("Crates", "crate 列表"),
+                crates.join(", ")
+            )
+        }
+        ProjectMode::Single => format!(
+            "\n📦 {}{}",
+            label("Mode", "模式"),
+            locale.text("Single package", "单包")
+        ),
     };
 
-    let ci_info = if config.ci {
-        "\n🔄 CI/CD:        GitHub Actions workflow generated (.github/workflows/ci.yml)"
-    } else {
-        ""
+    let ci_info = match config.ci_provider {
+        _ if !config.ci || config.bare => String::new(),
+        CiProvider::GitHub => format!(
+            "\n🔄 {}{}",
+            label("CI/CD", "CI/CD"),
+            locale.text(
+                "GitHub Actions workflow generated (.github/workflows/ci.yml)",
+                "已生成 GitHub Actions 工作流（.github/workflows/ci.yml）"
+            )
+        ),
+        CiProvider::GitLab => format!(
+            "\n🔄 {}{}",
+            label("CI/CD", "CI/CD"),
+            locale.text(
+                "GitLab CI pipeline generated (.gitlab-ci.yml)",
+                "已生成 GitLab CI 流水线（.gitlab-ci.yml）"
+            )
+        ),
     };
 
     format!(
         r#"
 ✨ ══════════════════════════════════════════════════════ ✓
 ✨                                                        ✨
-✨  Project '{}' created successfully!                      ✨
+✨  {}                      ✨
 ✨                                                        ✨
 ✨ ══════════════════════════════════════════════════════ ✓
 
-📂 Location:     {}
-🕐 Generated:    {}{}{}
+📂 {}{}
+🕐 {}{}{}{}
 
 ═════════════════════════════════════════════════════════
 
-🚀 Quick Start:
+🚀 {}:
 
   $ cd {}
   $ cargo run
 
 ═════════════════════════════════════════════════════════
 
-🧪 Test your API:
+🧪 {}:
 
-  # Health check
-  $ curl http://127.0.0.1:8080/health
+  # {}
+  $ curl {}/health
 
-  # Expected response: {{"status":"ok"}}
+  # {}: {{"status":"ok"}}
 
 ═════════════════════════════════════════════════════════
 
-Happy hacking! 🦀
+{} 🦀
 
 "#,
-        project_name,
+        locale.text(
+            &format!("Project '{}' created successfully!", project_name),
+            &format!("项目 '{}' 创建成功！", project_name)
+        ),
+        label("Location", "位置"),
         project_dir.display(),
+        label("Generated", "生成时间"),
         generation_time,
         mode_info,
         ci_info,
-        project_name
+        locale.text("Quick Start", "快速开始"),
+        project_dir.display(),
+        locale.text("Test your API", "测试 API"),
+        locale.text("Health check", "健康检查"),
+        config.server.local_url(),
+        locale.text("Expected response", "预期响应"),
+        locale.text("Happy hacking!", "编码愉快！"),
     )
 }
 
@@ -407,6 +1012,160 @@ mod tests {
         assert!(temp_dir.path().join("test.txt").exists());
     }
 
+    #[test]
+    fn test_success_message_lists_workspace_crates() {
+        use crate::config::WorkspaceLayout;
+
+        let mut config = ProjectConfig {
+            project_name: "shop".to_string(),
+            mode: ProjectMode::Workspace,
+            locale: crate::config::Locale::En,
+            ..Default::default()
+        };
+        let message = get_success_message_with_config(Path::new("shop"), &config);
+        assert!(message.contains("Crates:       api, domain, infrastructure, common\n"));
+
+        config.workspace_layout = WorkspaceLayout::Simple;
+        let message = get_success_message_with_config(Path::new("shop"), &config);
+        assert!(message.contains("Crates:       api, core\n"));
+    }
+
+    #[test]
+    fn test_check_path_length_within_limit() {
+        let path = Path::new("project/src/main.rs");
+        assert!(check_path_length(path, MAX_PATH_LEN).is_ok());
+    }
+
+    #[test]
+    fn test_check_path_length_rejects_overlong_path() {
+        let path = PathBuf::from("deep").join("a".repeat(300)).join("main.rs");
+
+        let result = check_path_length(&path, 259);
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("File path too long"));
+        assert!(err.contains("Use a shorter base directory"));
+        assert!(!err.contains("LongPathsEnabled"));
+    }
+
+    #[test]
+    fn test_write_file_rejects_overlong_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let relative = format!("{}/main.rs", "a".repeat(MAX_PATH_LEN));
+
+        let result = write_file(temp_dir.path(), &relative, "fn main() {}");
+
+        assert!(matches!(result, Err(CliError::Generation(_))));
+    }
+
+    #[test]
+    fn test_write_file_rejects_path_traversal() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_dir = temp_dir.path().join("project");
+
+        let result = write_file(&project_dir, "../escape.txt", "owned");
+
+        assert!(matches!(result, Err(CliError::Template(msg)) if msg.contains("../escape.txt")));
+        assert!(!temp_dir.path().join("escape.txt").exists());
+
+        let result = write_file(&project_dir, "/tmp/evil.txt", "owned");
+        assert!(matches!(result, Err(CliError::Template(msg)) if msg.contains("/tmp/evil.txt")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_file_rejects_symlink_escape() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_dir = temp_dir.path().join("project");
+        let outside = temp_dir.path().join("outside");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, project_dir.join("src")).unwrap();
+
+        let result = write_file(&project_dir, "src/main.rs", "owned");
+
+        assert!(matches!(result, Err(CliError::Template(msg)) if msg.contains("src/main.rs")));
+        assert!(!outside.join("main.rs").exists());
+
+        // A symlink that stays inside the project is fine
+        std::fs::create_dir_all(project_dir.join("real")).unwrap();
+        std::os::unix::fs::symlink(project_dir.join("real"), project_dir.join("linked")).unwrap();
+        write_file(&project_dir, "linked/lib.rs", "").unwrap();
+        assert!(project_dir.join("real/lib.rs").exists());
+    }
+
+    #[test]
+    fn test_render_file_known_key() {
+        let config = ProjectConfig {
+            project_name: "preview-app".to_string(),
+            ..Default::default()
+        };
+
+        let cargo_toml = render_file(&config, "Cargo.toml", None).unwrap().unwrap();
+
+        assert!(cargo_toml.contains("name = \"preview-app\""));
+    }
+
+    #[test]
+    fn test_render_file_missing_or_empty_key() {
+        let config = ProjectConfig::default();
+
+        assert_eq!(render_file(&config, "src/missing.rs", None).unwrap(), None);
+        // Conditional template for a disabled feature
+        assert_eq!(
+            render_file(&config, "src/handlers/auth.rs", None).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_render_file_uses_template_dir_and_entities() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("NOTES.md.hbs"), "# {{project_name}}").unwrap();
+        let config = ProjectConfig {
+            project_name: "preview-app".to_string(),
+            entities: vec!["Post".to_string()],
+            features: crate::config::FeatureSet {
+                database: crate::config::DatabaseOption::PostgreSQL,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let notes = render_file(&config, "NOTES.md", Some(temp_dir.path().to_path_buf()));
+        assert_eq!(notes.unwrap().as_deref(), Some("# preview-app"));
+
+        let files = render_project_files(&config).unwrap();
+        let (path, content) = files
+            .iter()
+            .find(|(path, _)| path.starts_with("migrations/") && path.contains("post"))
+            .unwrap();
+        assert_eq!(
+            render_file(&config, path, None).unwrap().as_ref(),
+            Some(content)
+        );
+    }
+
+    #[test]
+    fn test_project_name_from_dir() {
+        assert_eq!(
+            project_name_from_dir(Path::new("services/billing")).as_deref(),
+            Some("billing")
+        );
+        assert_eq!(
+            project_name_from_dir(Path::new("my-app/")).as_deref(),
+            Some("my-app")
+        );
+
+        let temp_dir = TempDir::new().unwrap();
+        let nested = temp_dir.path().join("current-app");
+        std::fs::create_dir_all(&nested).unwrap();
+        assert_eq!(
+            project_name_from_dir(&nested.join(".")).as_deref(),
+            Some("current-app")
+        );
+    }
+
     #[test]
     fn test_ensure_dir() {
         let temp_dir = TempDir::new().unwrap();
@@ -421,10 +1180,12 @@ mod tests {
     fn test_generate_project_creates_all_files() {
         let temp_dir = TempDir::new().unwrap();
         let project_dir = temp_dir.path().join("my-test-app");
-        let mut config = ProjectConfig::default();
-        config.project_name = "my-test-app".to_string();
+        let config = ProjectConfig {
+            project_name: "my-test-app".to_string(),
+            ..Default::default()
+        };
 
-        let result = generate_project(&project_dir, &config, false, false);
+        let result = generate_project(&project_dir, &config, false, false, false);
 
         if let Err(e) = &result {
             eprintln!("Generation error: {:?}", e);
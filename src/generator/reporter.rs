@@ -0,0 +1,54 @@
+// Progress reporting
+//
+// This module decides which progress lines generation prints, so callers pass
+// every message through one `Reporter` instead of checking flags themselves.
+
+use crate::config::Verbosity;
+use std::fmt::Display;
+
+/// 进度输出 / Prints generation progress according to a [`Verbosity`]
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::config::Verbosity;
+/// use axum_app_create::generator::reporter::Reporter;
+///
+/// let reporter = Reporter::new(Verbosity::Quiet);
+/// assert!(reporter.is_quiet());
+/// reporter.progress("📝 Generating files:"); // prints nothing
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Reporter {
+    verbosity: Verbosity,
+}
+
+impl Reporter {
+    /// Create a reporter for `verbosity`
+    pub fn new(verbosity: Verbosity) -> Self {
+        Self { verbosity }
+    }
+
+    /// Whether progress output is suppressed
+    pub fn is_quiet(&self) -> bool {
+        self.verbosity == Verbosity::Quiet
+    }
+
+    /// Whether details such as diffs are printed
+    pub fn is_verbose(&self) -> bool {
+        self.verbosity == Verbosity::Verbose
+    }
+
+    /// Print a progress line unless quiet
+    pub fn progress(&self, message: impl Display) {
+        if !self.is_quiet() {
+            println!("{}", message);
+        }
+    }
+
+    /// Print a detail (e.g. a diff) only when verbose
+    pub fn detail(&self, message: impl Display) {
+        if self.is_verbose() {
+            println!("{}", message);
+        }
+    }
+}
@@ -2,23 +2,53 @@
 //
 // This tool generates new Axum projects with sensible defaults and optional features.
 
-use axum_app_create::cli::{is_non_interactive, prompts::prompt_project_config};
-use axum_app_create::config::{DatabaseOption, Preset, ProjectMode};
+use axum_app_create::cli::{
+    args::write_completions, is_non_interactive, prompts::apply_overrides,
+    prompts::prompt_project_config,
+};
+use axum_app_create::config::{
+    API_ROUTE_FEATURES, CiProvider, DatabaseOption, Edition, JsonBackend, Locale, OverwritePolicy,
+    Preset, ProjectConfig, ProjectMode, Verbosity, WorkspaceLayout,
+};
 use axum_app_create::error::CliError;
-use axum_app_create::generator::project::{generate_project, get_success_message_with_config};
+use axum_app_create::generator::git::detect_git_remote;
+use axum_app_create::generator::project::{
+    generate_project, get_success_message_with_config, project_name_from_dir,
+};
+use axum_app_create::generator::reporter::Reporter;
+use axum_app_create::template::changelog::changes_since;
+use axum_app_create::template::validate::validate_template_dir;
+use axum_app_create::updater::handler::new_handler;
+use axum_app_create::updater::metadata::MetadataManager;
+use axum_app_create::updater::regen::{RegenReport, regen_file, regen_manifests};
+use axum_app_create::updater::repair::repair_gitignore;
+use axum_app_create::utils::doctor::run_checks;
 use axum_app_create::utils::rust_toolchain::check_rust_toolchain;
-use clap::Parser;
-use std::path::PathBuf;
+use axum_app_create::utils::validator::{
+    validate_api_version, validate_entity_name, validate_host, validate_jwt_algorithm,
+    validate_license,
+};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use std::path::{Path, PathBuf};
 
 /// Simple CLI tool to scaffold Axum web applications
 #[derive(Parser, Debug)]
 #[command(name = "axum-app-create")]
 #[command(about = "Scaffold a new Axum web application", long_about = None)]
 #[command(version = "0.2.0")]
+#[command(args_conflicts_with_subcommands = true)]
 struct CliArgs {
-    /// Project name (positional argument or --project-name)
-    #[arg(value_name = "PROJECT_NAME")]
-    project_name: Option<String>,
+    #[command(subcommand)]
+    command: Option<Commands>,
+
+    /// Target directory (e.g. my-app, services/billing or .); its last component is the project name unless --name is given
+    #[arg(value_name = "PATH")]
+    path: Option<PathBuf>,
+
+    /// Cargo package name, when it should differ from the target directory's name
+    #[arg(long, value_name = "NAME")]
+    name: Option<String>,
 
     /// Author name for generated project
     #[arg(long)]
@@ -36,11 +66,47 @@ struct CliArgs {
     #[arg(long)]
     biz_error: bool,
 
+    /// Generate OpenAPI docs with Swagger UI (utoipa)
+    #[arg(long)]
+    openapi: bool,
+
+    /// Generate a server-sent events endpoint at /events
+    #[arg(long)]
+    sse: bool,
+
+    /// Generate cookie-backed server-side sessions (tower-sessions)
+    #[arg(long)]
+    sessions: bool,
+
+    /// Generate a multipart file upload endpoint at /upload
+    #[arg(long)]
+    multipart: bool,
+
+    /// Generate a Redis cache connection configured from REDIS_URL
+    #[arg(long)]
+    cache: bool,
+
+    /// Add a CORS layer allowing the origins in CORS_ALLOWED_ORIGINS
+    #[arg(long)]
+    cors: bool,
+
+    /// Compress responses with gzip or brotli (tower-http CompressionLayer)
+    #[arg(long)]
+    compression: bool,
+
+    /// Serve Prometheus request metrics at /metrics (axum-prometheus)
+    #[arg(long)]
+    metrics: bool,
+
     /// Default log level: trace, debug, info, warn, error
     #[arg(long, value_name = "LEVEL")]
     log_level: Option<String>,
 
-    /// Project mode: single (default) or workspace
+    /// Log output format of the generated project: compact (default), pretty, or json
+    #[arg(long, value_name = "FORMAT")]
+    log_format: Option<String>,
+
+    /// Project mode: single (default), workspace, or bin-only-workspace (a `core` library and a thin `api` binary)
     #[arg(long, value_name = "MODE")]
     mode: Option<String>,
 
@@ -48,38 +114,323 @@ struct CliArgs {
     #[arg(long, value_name = "PRESET")]
     preset: Option<String>,
 
-    /// Generate GitHub Actions CI/CD workflow
+    /// Generate a CI/CD pipeline (GitHub Actions unless --ci-provider says otherwise)
     #[arg(long)]
     ci: bool,
 
+    /// CI provider for the pipeline: github (default) or gitlab; implies --ci
+    #[arg(long, value_name = "PROVIDER")]
+    ci_provider: Option<String>,
+
+    /// Expose optional features (auth, postgres, sqlite) as Cargo features (single mode)
+    #[arg(long)]
+    cargo_features: bool,
+
+    /// Generate a PostgreSQL integration test using testcontainers (single mode, `--database postgresql`; rejected otherwise)
+    #[arg(long)]
+    testcontainers: bool,
+
+    /// Generate a .sqlfluff config for linting migrations (single mode, requires a database)
+    #[arg(long)]
+    sql_lint: bool,
+
+    /// Strip trailing slashes so `/health/` and `/health` both match
+    #[arg(long)]
+    normalize_path: bool,
+
+    /// Build the PostgreSQL URL from DB_HOST, DB_PORT, DB_USER, DB_PASSWORD and DB_NAME when DATABASE_URL is unset
+    #[arg(long)]
+    db_url_parts: bool,
+
+    /// JSON backend for responses: serde (default) or simd (sonic-rs)
+    #[arg(long, value_name = "BACKEND")]
+    json_backend: Option<String>,
+
+    /// Rust edition of the generated project: 2015, 2018, 2021, or 2024
+    #[arg(long, value_name = "EDITION")]
+    edition: Option<String>,
+
+    /// Generate a Procfile for Heroku/Railway-style platforms
+    #[arg(long)]
+    procfile: bool,
+
+    /// Generate .github/CODEOWNERS (one entry per crate in workspace mode)
+    #[arg(long)]
+    codeowners: bool,
+
+    /// Set the repository URL in Cargo.toml and the README from the target directory's git origin remote
+    #[arg(long)]
+    from_git_remote: bool,
+
+    /// Default host the server binds to (HOST)
+    #[arg(long, value_name = "HOST")]
+    host: Option<String>,
+
+    /// Default port the server binds to (PORT)
+    #[arg(long, value_name = "PORT", value_parser = clap::value_parser!(u16).range(1..))]
+    port: Option<u16>,
+
+    /// Print a startup banner with the service name, version and port
+    #[arg(long)]
+    startup_banner: bool,
+
+    /// Prefix for routes nested under the API
+    #[arg(long, value_name = "PREFIX")]
+    api_prefix: Option<String>,
+
+    /// Feature routes to nest under the API prefix (comma-separated): auth
+    #[arg(long, value_name = "ROUTES", value_delimiter = ',')]
+    api_routes: Option<Vec<String>>,
+
+    /// API versions to scaffold under the API prefix (comma-separated, e.g. v1,v2)
+    #[arg(long, value_name = "VERSIONS", value_delimiter = ',')]
+    api_versions: Option<Vec<String>>,
+
+    /// Entities to generate migrations and models for (comma-separated PascalCase, e.g. User,Post)
+    #[arg(long, value_name = "ENTITIES", value_delimiter = ',')]
+    entities: Option<Vec<String>>,
+
+    /// Directory of custom templates overriding the built-in ones (default: $AXUM_APP_CREATE_TEMPLATE_DIR, then template_dir in ~/.axum-app-create.toml)
+    #[arg(long, value_name = "DIR")]
+    template_dir: Option<PathBuf>,
+
+    /// Read the project configuration from a TOML manifest (e.g. axum-app.toml) instead of prompts; other flags override its values
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["path", "name"])]
+    from_file: Option<PathBuf>,
+
+    /// Seed the RNG used for generated secrets (reproducible output)
+    #[arg(long, value_name = "SEED")]
+    seed_rng: Option<u64>,
+
+    /// Language of CLI messages and generated docs: en, zh, or both
+    #[arg(long, value_name = "LOCALE", global = true)]
+    locale: Option<String>,
+
+    /// Language of comments in generated files: en, zh, or both (defaults to --locale)
+    #[arg(long, value_name = "LOCALE")]
+    comments: Option<String>,
+
+    /// Generate only the crate: no README, .gitignore, CI, Docker files or git repository
+    #[arg(long)]
+    bare: bool,
+
+    /// Don't initialize a git repository (e.g. when generating inside an existing repository)
+    #[arg(long)]
+    no_git: bool,
+
+    /// Skip `cargo update` after generation (offline use, reproducible builds)
+    #[arg(long)]
+    no_update: bool,
+
+    /// Also write a ready-to-run .env (local defaults, random JWT secret) next to .env.example
+    #[arg(long)]
+    with_env: bool,
+
     /// Force overwrite if target directory exists
     #[arg(long)]
     force: bool,
 
+    /// When the target directory exists: replace (same as --force), merge (write missing files, keep existing ones) or skip (generate nothing)
+    #[arg(long, value_name = "POLICY", conflicts_with = "force")]
+    overwrite_policy: Option<String>,
+
+    /// JWT signing algorithm of the generated auth config: HS256 (default), HS384 or HS512
+    #[arg(long, value_name = "ALG")]
+    jwt_algorithm: Option<String>,
+
+    /// Lifetime of issued JWTs in seconds (default: 86400)
+    #[arg(long, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..))]
+    jwt_expiration: Option<u64>,
+
+    /// SPDX license for Cargo.toml and the LICENSE file: MIT, Apache-2.0, "MIT OR Apache-2.0", BSD-3-Clause or ISC
+    #[arg(long, value_name = "SPDX")]
+    license: Option<String>,
+
+    /// Shell command to run in the project directory after generation (e.g. "cargo fmt"); generation fails if it exits non-zero
+    #[arg(long, value_name = "CMD")]
+    post_hook: Option<String>,
+
+    /// Render templates and list the files that would be created without writing anything
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Suppress progress output and the success banner, printing only the project path
+    #[arg(long)]
+    quiet: bool,
+
+    /// Print a unified diff of every file `regen` changes
+    #[arg(long, global = true)]
+    verbose: bool,
+
+    /// Take the default answer of every prompt without asking (never overwrites an existing directory; use --force)
+    #[arg(short = 'y', long, global = true)]
+    yes: bool,
+
     /// Non-interactive mode (fail if required values missing)
     #[arg(long)]
     non_interactive: bool,
 }
 
+/// Parse a `--locale` / `--comments` value, exiting on an invalid value
+fn parse_locale(value: &str) -> Locale {
+    match value {
+        "en" => Locale::En,
+        "zh" => Locale::Zh,
+        "both" => Locale::Both,
+        other => {
+            eprintln!(
+                "\n❌ 无效的语言 / Invalid locale: '{}'\n\
+                 💡 有效选项 / Valid options: en, zh, both",
+                other
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Subcommands operating on an already generated project
+#[derive(Subcommand, Debug)]
+enum Commands {
+    /// Regenerate a single file from templates using the project's metadata
+    Regen {
+        /// File to regenerate, relative to the project root (e.g. src/handlers/health.rs)
+        #[arg(
+            required_unless_present = "manifest_only",
+            conflicts_with = "manifest_only"
+        )]
+        path: Option<String>,
+
+        /// Regenerate only Cargo.toml (and workspace member manifests), leaving sources untouched
+        #[arg(long)]
+        manifest_only: bool,
+
+        /// Root of the generated project
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        project_dir: PathBuf,
+
+        /// Overwrite the file even if it was modified since generation
+        #[arg(long)]
+        force: bool,
+
+        /// Three-way merge local modifications with the regenerated content (Cargo.toml merges by key)
+        #[arg(long, conflicts_with = "force")]
+        merge: bool,
+
+        /// Copy the previous content of each overwritten file to <path>.bak first
+        #[arg(long)]
+        backup: bool,
+
+        /// Output format: text (default) or json (files_written, files_merged, files_conflicted)
+        #[arg(long, value_name = "FORMAT", default_value = "text", value_parser = ["text", "json"])]
+        format: String,
+    },
+
+    /// Repair generation metadata and re-sync .gitignore with the templates
+    Repair {
+        /// Root of the generated project
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        project_dir: PathBuf,
+
+        /// Only re-add missing .gitignore patterns; leave the metadata untouched
+        #[arg(long)]
+        update_gitignore_only: bool,
+    },
+
+    /// Scaffold a new handler into a generated project and mount its router
+    NewHandler {
+        /// Handler module name in snake_case (e.g. orders), also used as its route
+        name: String,
+
+        /// Root of the generated project
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        project_dir: PathBuf,
+    },
+
+    /// Check that every template in a custom template directory renders
+    Validate {
+        /// Custom template directory to check
+        #[arg(long, value_name = "DIR")]
+        template_dir: PathBuf,
+    },
+
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate completions for: bash, zsh, fish, powershell, or elvish
+        shell: Shell,
+    },
+
+    /// List the configuration presets and the features each one enables
+    Presets,
+
+    /// Check that rustc, cargo and git are available and the user config parses
+    Doctor,
+
+    /// List changes to the embedded templates since a CLI version
+    TemplateChanges {
+        /// Version that generated the project (e.g. 0.2.0)
+        #[arg(long, value_name = "VERSION")]
+        since: String,
+    },
+}
+
 /// Format error message with troubleshooting guidance
-fn format_error_message(error: &CliError) -> String {
+fn format_error_message(error: &CliError, locale: Locale) -> String {
     match error {
         CliError::Io(_) | CliError::Git(_) | CliError::Template(_) | CliError::Generation(_) => {
             format!(
                 "{}\n\n\
-                 🔍 故障排查 / Troubleshooting:\n\
-                 1. 检查文件系统权限 / Check file system permissions\n\
-                 2. 确保磁盘空间充足 / Ensure sufficient disk space\n\
-                 3. 查看日志获取更多信息 / Check logs for more details: RUST_LOG=debug\n\
-                 4. 查看帮助 / View help: axum-app-create --help\n\
-                 5. 提交bug报告 / Report bug: https://github.com/Yu-Xiao-Sheng/axum-app-create/issues",
-                error
+                 🔍 {}:\n\
+                 1. {}\n\
+                 2. {}\n\
+                 3. {}: RUST_LOG=debug\n\
+                 4. {}: axum-app-create --help\n\
+                 5. {}: https://github.com/Yu-Xiao-Sheng/axum-app-create/issues",
+                error,
+                locale.text("Troubleshooting", "故障排查"),
+                locale.text("Check file system permissions", "检查文件系统权限"),
+                locale.text("Ensure sufficient disk space", "确保磁盘空间充足"),
+                locale.text("Check logs for more details", "查看日志获取更多信息"),
+                locale.text("View help", "查看帮助"),
+                locale.text("Report bug", "提交bug报告"),
             )
         }
         _ => error.to_string(),
     }
 }
 
+/// Print each preset with its description and resolved feature set as a table
+fn print_presets() {
+    let yes_no = |enabled: bool| if enabled { "yes" } else { "-" };
+
+    println!(
+        "PRESET     DATABASE            AUTH  LOGGING BIZ-ERROR OPENAPI SSE  SESSIONS MULTIPART CACHE CORS COMPRESSION METRICS"
+    );
+    for preset in Preset::ALL {
+        let features = preset.to_feature_set();
+        println!(
+            "{:<10} {:<19} {:<5} {:<7} {:<9} {:<7} {:<4} {:<8} {:<9} {:<5} {:<4} {:<11} {}",
+            preset.to_string(),
+            features.database.to_string(),
+            yes_no(features.authentication),
+            yes_no(features.logging),
+            yes_no(features.biz_error),
+            yes_no(features.openapi),
+            yes_no(features.sse),
+            yes_no(features.sessions),
+            yes_no(features.multipart),
+            yes_no(features.cache),
+            yes_no(features.cors),
+            yes_no(features.compression),
+            yes_no(features.metrics)
+        );
+    }
+
+    println!();
+    for preset in Preset::ALL {
+        println!("{:<10} {}", preset.to_string(), preset.description());
+    }
+}
+
 fn main() -> anyhow::Result<()> {
     // Initialize logging
     tracing_subscriber::fmt()
@@ -91,7 +442,230 @@ fn main() -> anyhow::Result<()> {
 
     let args = CliArgs::parse();
 
-    println!("\n🦀 axum-app-create CLI Tool v0.2.0");
+    // Completion scripts go to stdout as-is, so print them before the banner
+    if let Some(Commands::Completions { shell }) = args.command {
+        write_completions(shell, &mut CliArgs::command(), &mut std::io::stdout());
+        return Ok(());
+    }
+
+    // Parse locale first so later messages (subcommands included) use it
+    let locale = args.locale.as_deref().map(parse_locale).unwrap_or_default();
+
+    // Likewise keep JSON reports parseable, and --quiet output to the project path
+    let json_output =
+        matches!(&args.command, Some(Commands::Regen { format, .. }) if format == "json");
+    if !json_output && !args.quiet {
+        println!("\n🦀 axum-app-create CLI Tool v0.2.0");
+    }
+
+    match args.command {
+        Some(Commands::Regen {
+            path,
+            manifest_only,
+            project_dir,
+            force,
+            merge,
+            backup,
+            format,
+        }) => {
+            // Read up front: the recorded generator version is compared with ours
+            if let Ok(metadata) = MetadataManager::read(&project_dir) {
+                if format != "json" {
+                    println!("\nℹ️  {}", metadata.version_summary(locale));
+                }
+                if metadata.generated_by_newer_tool() {
+                    eprintln!(
+                        "\n⚠️  {}\n💡 {}",
+                        locale.text(
+                            &format!(
+                                "This axum-app-create (v{}) is older than the one that generated the project (v{})",
+                                env!("CARGO_PKG_VERSION"),
+                                metadata.version
+                            ),
+                            &format!(
+                                "当前工具版本（v{}）比生成该项目的版本（v{}）旧",
+                                env!("CARGO_PKG_VERSION"),
+                                metadata.version
+                            )
+                        ),
+                        locale.text(
+                            "Consider upgrading before updating generated files",
+                            "建议升级后再更新"
+                        )
+                    );
+                }
+            }
+            let regenerated = match path {
+                Some(path) if !manifest_only => {
+                    regen_file(&project_dir, &path, force, merge, backup).map(|file| vec![file])
+                }
+                _ => regen_manifests(&project_dir, force, merge, backup),
+            };
+            let reporter = Reporter::new(if args.verbose {
+                Verbosity::Verbose
+            } else {
+                Verbosity::Normal
+            });
+            match regenerated.map(RegenReport::new) {
+                Ok(report) if format == "json" => println!("{:#}", report.to_json()),
+                Ok(report) => {
+                    for diff in &report.diffs {
+                        reporter.detail(format!("\n{}", diff.trim_end()));
+                    }
+                    println!("\n{}", report.summary(locale));
+                }
+                Err(e) => {
+                    eprintln!("\n{}", e);
+                    std::process::exit(1);
+                }
+            }
+            return Ok(());
+        }
+        Some(Commands::Repair {
+            project_dir,
+            update_gitignore_only,
+        }) => {
+            // Migrate first so the metadata can be read to render .gitignore
+            let migrated = if update_gitignore_only {
+                Ok(false)
+            } else {
+                MetadataManager::migrate(&project_dir)
+            };
+            match migrated.and_then(|migrated| Ok((migrated, repair_gitignore(&project_dir)?))) {
+                Ok((migrated, added)) => {
+                    if migrated {
+                        println!(
+                            "\n✓ {}",
+                            locale.text("Migrated generation metadata", "已迁移生成元数据")
+                        );
+                    }
+                    if added.is_empty() {
+                        println!(
+                            "\n✓ {}",
+                            locale.text(".gitignore is up to date", ".gitignore 已是最新")
+                        );
+                    } else {
+                        println!(
+                            "\n✓ {}:",
+                            locale.text("Added to .gitignore", "已添加到 .gitignore")
+                        );
+                        for pattern in added {
+                            println!("  + {}", pattern);
+                        }
+                    }
+                }
+                Err(e) => {
+                    eprintln!("\n{}", e);
+                    std::process::exit(1);
+                }
+            }
+            return Ok(());
+        }
+        Some(Commands::NewHandler { name, project_dir }) => {
+            match new_handler(&project_dir, &name) {
+                Ok(report) => {
+                    println!(
+                        "\n✓ {} {}",
+                        locale.text("Created", "已创建"),
+                        report.created
+                    );
+                    for file in report.updated {
+                        println!("  ~ {}", file);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("\n{}", e);
+                    std::process::exit(1);
+                }
+            }
+            return Ok(());
+        }
+        Some(Commands::Validate { template_dir }) => {
+            let checks = match validate_template_dir(&template_dir) {
+                Ok(checks) => checks,
+                Err(e) => {
+                    eprintln!("\n{}", e);
+                    std::process::exit(1);
+                }
+            };
+            let failed = checks.iter().filter(|check| check.error.is_some()).count();
+            println!();
+            for check in &checks {
+                match &check.error {
+                    None => println!("  ✓ {}", check.path),
+                    Some(error) => eprintln!("  ❌ {}\n\n{}\n", check.path, error),
+                }
+            }
+            if failed > 0 {
+                eprintln!(
+                    "\n❌ {}",
+                    locale.text(
+                        &format!("{} of {} templates failed", failed, checks.len()),
+                        &format!("{} 个模板无效（共 {} 个）", failed, checks.len())
+                    )
+                );
+                std::process::exit(1);
+            }
+            println!(
+                "\n✓ {}",
+                locale.text(
+                    &format!("All {} templates are valid", checks.len()),
+                    &format!("所有 {} 个模板有效", checks.len())
+                )
+            );
+            return Ok(());
+        }
+        Some(Commands::TemplateChanges { since }) => {
+            let changes = match changes_since(&since) {
+                Ok(changes) => changes,
+                Err(e) => {
+                    eprintln!("\n{}", e);
+                    std::process::exit(1);
+                }
+            };
+            if changes.is_empty() {
+                println!(
+                    "\n✓ {}",
+                    locale.text(
+                        &format!("No template changes since {}", since),
+                        &format!("自 {} 以来模板没有变更", since)
+                    )
+                );
+            }
+            let mut version = "";
+            for change in changes {
+                if change.version != version {
+                    version = change.version;
+                    println!("\n{}", version);
+                }
+                println!("  - {}", change.summary);
+            }
+            return Ok(());
+        }
+        Some(Commands::Presets) => {
+            print_presets();
+            return Ok(());
+        }
+        Some(Commands::Doctor) => {
+            let checks = run_checks();
+            println!();
+            for check in &checks {
+                println!("{}", check.line());
+            }
+            if checks.iter().any(|check| check.required && !check.passed) {
+                eprintln!(
+                    "\n❌ {}",
+                    locale.text(
+                        "Required tools are missing or too old",
+                        "缺少必需的工具或版本过低"
+                    )
+                );
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Commands::Completions { .. }) | None => {}
+    }
 
     // Check Rust toolchain
     if let Err(e) = check_rust_toolchain() {
@@ -99,6 +673,8 @@ fn main() -> anyhow::Result<()> {
         std::process::exit(1);
     }
 
+    let comment_locale = args.comments.as_deref().map(parse_locale);
+
     // Parse database option from CLI flag
     let cli_database = args.database.as_deref().map(|d| match d {
         "postgresql" | "postgres" | "pg" => DatabaseOption::PostgreSQL,
@@ -107,9 +683,10 @@ fn main() -> anyhow::Result<()> {
         "none" => DatabaseOption::None,
         other => {
             eprintln!(
-                "\n❌ Invalid database option: '{}'\n\
-                 💡 Valid options: none, postgresql, sqlite, both",
-                other
+                "\n❌ {}: '{}'\n💡 {}: none, postgresql, sqlite, both",
+                locale.text("Invalid database option", "无效的数据库选项"),
+                other,
+                locale.text("Valid options", "有效选项")
             );
             std::process::exit(1);
         }
@@ -117,13 +694,15 @@ fn main() -> anyhow::Result<()> {
 
     // Parse mode from CLI flag
     let cli_mode = args.mode.as_deref().map(|m| match m {
-        "single" => ProjectMode::Single,
-        "workspace" => ProjectMode::Workspace,
+        "single" => (ProjectMode::Single, WorkspaceLayout::Layered),
+        "workspace" => (ProjectMode::Workspace, WorkspaceLayout::Layered),
+        "bin-only-workspace" => (ProjectMode::Workspace, WorkspaceLayout::Simple),
         other => {
             eprintln!(
-                "\n❌ 无效的模式 / Invalid mode: '{}'\n\
-                 💡 有效选项 / Valid options: single, workspace",
-                other
+                "\n❌ {}: '{}'\n💡 {}: single, workspace, bin-only-workspace",
+                locale.text("Invalid mode", "无效的模式"),
+                other,
+                locale.text("Valid options", "有效选项")
             );
             std::process::exit(1);
         }
@@ -136,9 +715,10 @@ fn main() -> anyhow::Result<()> {
         "fullstack" => Preset::Fullstack,
         other => {
             eprintln!(
-                "\n❌ 无效的预设 / Invalid preset: '{}'\n\
-                 💡 有效选项 / Valid options: minimal, api, fullstack",
-                other
+                "\n❌ {}: '{}'\n💡 {}: minimal, api, fullstack",
+                locale.text("Invalid preset", "无效的预设"),
+                other,
+                locale.text("Valid options", "有效选项")
             );
             std::process::exit(1);
         }
@@ -149,13 +729,201 @@ fn main() -> anyhow::Result<()> {
         && !["trace", "debug", "info", "warn", "error"].contains(&level.as_str())
     {
         eprintln!(
-            "\n❌ Invalid log level: '{}'\n\
-                 💡 Valid levels: trace, debug, info, warn, error",
-            level
+            "\n❌ {}: '{}'\n💡 {}: trace, debug, info, warn, error",
+            locale.text("Invalid log level", "无效的日志级别"),
+            level,
+            locale.text("Valid levels", "有效级别")
+        );
+        std::process::exit(1);
+    }
+
+    // Validate log format if provided
+    if let Some(ref format) = args.log_format
+        && !["json", "pretty", "compact"].contains(&format.as_str())
+    {
+        eprintln!(
+            "\n❌ {}: '{}'\n💡 {}: json, pretty, compact",
+            locale.text("Invalid log format", "无效的日志格式"),
+            format,
+            locale.text("Valid options", "有效选项")
         );
         std::process::exit(1);
     }
 
+    // Validate API prefix if provided
+    if let Some(ref prefix) = args.api_prefix
+        && (!prefix.starts_with('/') || prefix.len() < 2 || prefix.ends_with('/'))
+    {
+        eprintln!(
+            "\n❌ {}: '{}'\n💡 {} (e.g. /api)",
+            locale.text("Invalid API prefix", "无效的 API 前缀"),
+            prefix,
+            locale.text(
+                "Prefix must start with '/' and not end with '/'",
+                "前缀必须以 '/' 开头且不以 '/' 结尾"
+            )
+        );
+        std::process::exit(1);
+    }
+
+    // Validate API routes if provided
+    if let Some(ref routes) = args.api_routes
+        && let Some(route) = routes
+            .iter()
+            .find(|r| !API_ROUTE_FEATURES.contains(&r.as_str()))
+    {
+        eprintln!(
+            "\n❌ {}: '{}'\n💡 {}: {}",
+            locale.text("Invalid API route", "无效的 API 路由"),
+            route,
+            locale.text("Valid options", "有效选项"),
+            API_ROUTE_FEATURES.join(", ")
+        );
+        std::process::exit(1);
+    }
+
+    // Validate JWT algorithm if provided
+    if let Some(ref algorithm) = args.jwt_algorithm
+        && let Err(e) = validate_jwt_algorithm(algorithm)
+    {
+        eprintln!("\n{}", e);
+        std::process::exit(1);
+    }
+
+    // Validate host if provided
+    if let Some(ref host) = args.host
+        && let Err(e) = validate_host(host)
+    {
+        eprintln!("\n{}", e);
+        std::process::exit(1);
+    }
+
+    // Validate license if provided
+    if let Some(ref license) = args.license
+        && let Err(e) = validate_license(license)
+    {
+        eprintln!("\n{}", e);
+        std::process::exit(1);
+    }
+
+    // Validate API versions if provided
+    if let Some(ref versions) = args.api_versions {
+        let mut seen = std::collections::HashSet::new();
+        for version in versions {
+            if let Err(e) = validate_api_version(version) {
+                eprintln!("\n{}", e);
+                std::process::exit(1);
+            }
+            if !seen.insert(version) {
+                eprintln!(
+                    "\n❌ {}: '{}'",
+                    locale.text("Duplicate API version", "重复的 API 版本"),
+                    version
+                );
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // Detect the repository URL from the git repository enclosing the target directory
+    let repository = if args.from_git_remote {
+        let remote = detect_git_remote(args.path.as_deref().unwrap_or(Path::new(".")));
+        if remote.is_none() {
+            eprintln!(
+                "\n⚠️  {}",
+                locale.text(
+                    "No git origin remote found, leaving the repository URL unset",
+                    "未找到 git origin 远程仓库，不设置仓库地址"
+                )
+            );
+        }
+        remote
+    } else {
+        None
+    };
+
+    // Parse overwrite policy from CLI flag
+    let cli_overwrite_policy = args.overwrite_policy.as_deref().map(|p| match p {
+        "replace" => OverwritePolicy::Replace,
+        "merge" => OverwritePolicy::Merge,
+        "skip" => OverwritePolicy::Skip,
+        other => {
+            eprintln!(
+                "\n❌ {}: '{}'\n💡 {}: replace, merge, skip",
+                locale.text("Invalid overwrite policy", "无效的覆盖策略"),
+                other,
+                locale.text("Valid options", "有效选项")
+            );
+            std::process::exit(1);
+        }
+    });
+
+    // Parse JSON backend from CLI flag
+    let cli_json_backend = args.json_backend.as_deref().map(|b| match b {
+        "serde" => JsonBackend::Serde,
+        "simd" => JsonBackend::Simd,
+        other => {
+            eprintln!(
+                "\n❌ {}: '{}'\n💡 {}: serde, simd",
+                locale.text("Invalid JSON backend", "无效的 JSON 后端"),
+                other,
+                locale.text("Valid options", "有效选项")
+            );
+            std::process::exit(1);
+        }
+    });
+
+    // Parse CI provider from CLI flag
+    let cli_ci_provider = args.ci_provider.as_deref().map(|p| match p {
+        "github" => CiProvider::GitHub,
+        "gitlab" => CiProvider::GitLab,
+        other => {
+            eprintln!(
+                "\n❌ {}: '{}'\n💡 {}: github, gitlab",
+                locale.text("Invalid CI provider", "无效的 CI 平台"),
+                other,
+                locale.text("Valid options", "有效选项")
+            );
+            std::process::exit(1);
+        }
+    });
+
+    // Parse edition from CLI flag
+    let cli_edition = args.edition.as_deref().map(|e| match e {
+        "2015" => Edition::E2015,
+        "2018" => Edition::E2018,
+        "2021" => Edition::E2021,
+        "2024" => Edition::E2024,
+        other => {
+            eprintln!(
+                "\n❌ {}: '{}'\n💡 {}: 2015, 2018, 2021, 2024",
+                locale.text("Invalid edition", "无效的 Rust 版本"),
+                other,
+                locale.text("Valid options", "有效选项")
+            );
+            std::process::exit(1);
+        }
+    });
+
+    // Validate entity names if provided
+    if let Some(ref entities) = args.entities {
+        let mut seen = std::collections::HashSet::new();
+        for entity in entities {
+            if let Err(e) = validate_entity_name(entity) {
+                eprintln!("\n{}", e);
+                std::process::exit(1);
+            }
+            if !seen.insert(entity) {
+                eprintln!(
+                    "\n❌ {}: '{}'",
+                    locale.text("Duplicate entity", "重复的实体"),
+                    entity
+                );
+                std::process::exit(1);
+            }
+        }
+    }
+
     // Determine if we're in interactive mode
     let interactive = !is_non_interactive(args.non_interactive);
 
@@ -164,34 +932,134 @@ fn main() -> anyhow::Result<()> {
         database: cli_database,
         auth: if args.auth { Some(true) } else { None },
         biz_error: if args.biz_error { Some(true) } else { None },
+        openapi: if args.openapi { Some(true) } else { None },
+        sse: if args.sse { Some(true) } else { None },
+        sessions: if args.sessions { Some(true) } else { None },
+        multipart: if args.multipart { Some(true) } else { None },
+        cache: if args.cache { Some(true) } else { None },
+        cors: if args.cors { Some(true) } else { None },
+        compression: if args.compression { Some(true) } else { None },
+        metrics: if args.metrics { Some(true) } else { None },
+        jwt_algorithm: args.jwt_algorithm,
+        jwt_expiration: args.jwt_expiration,
         log_level: args.log_level,
+        log_format: args.log_format,
         author: args.author,
-        mode: cli_mode,
+        mode: cli_mode.map(|(mode, _)| mode),
+        workspace_layout: cli_mode.map(|(_, layout)| layout),
         preset: cli_preset,
         ci: if args.ci { Some(true) } else { None },
+        ci_provider: cli_ci_provider,
+        cargo_features: if args.cargo_features {
+            Some(true)
+        } else {
+            None
+        },
+        seed_rng: args.seed_rng,
+        api_prefix: args.api_prefix,
+        api_routes: args.api_routes,
+        api_versions: args.api_versions,
+        template_dir: args.template_dir,
+        locale: args.locale.is_some().then_some(locale),
+        comment_locale,
+        entities: args.entities,
+        sql_lint: if args.sql_lint { Some(true) } else { None },
+        normalize_path: if args.normalize_path {
+            Some(true)
+        } else {
+            None
+        },
+        json_backend: cli_json_backend,
+        edition: cli_edition,
+        startup_banner: if args.startup_banner {
+            Some(true)
+        } else {
+            None
+        },
+        testcontainers: if args.testcontainers {
+            Some(true)
+        } else {
+            None
+        },
+        bare: if args.bare { Some(true) } else { None },
+        no_update: if args.no_update { Some(true) } else { None },
+        with_env: if args.with_env { Some(true) } else { None },
+        no_git: if args.no_git { Some(true) } else { None },
+        procfile: if args.procfile { Some(true) } else { None },
+        codeowners: if args.codeowners { Some(true) } else { None },
+        repository,
+        license: args.license,
+        host: args.host,
+        port: args.port,
+        db_url_parts: if args.db_url_parts { Some(true) } else { None },
     };
 
-    // Get project configuration
-    let config = match prompt_project_config(interactive, args.project_name, Some(cli_overrides)) {
+    // Get project configuration (manifest file + CLI overrides, or CLI overrides + prompts)
+    let config = match &args.from_file {
+        Some(path) => ProjectConfig::from_toml_file(path)
+            .map(|config| apply_overrides(config, &cli_overrides))
+            .map_err(|e| e.to_string()),
+        None => {
+            let name = args
+                .name
+                .clone()
+                .or_else(|| args.path.as_deref().and_then(project_name_from_dir));
+            prompt_project_config(interactive, args.yes, name, Some(cli_overrides))
+        }
+    };
+    let mut config = match config {
         Ok(cfg) => cfg,
         Err(e) => {
             eprintln!("\n❌ {}", e);
             std::process::exit(1);
         }
     };
+    config.overwrite_policy = cli_overwrite_policy;
+    config.post_generate_hook = args.post_hook.clone();
+    config.assume_yes = args.yes;
+    if args.quiet {
+        config.verbosity = Verbosity::Quiet;
+    }
 
-    // Determine project directory
-    let project_dir = PathBuf::from(&config.project_name);
+    // Determine project directory (named after the project unless a path was given)
+    let project_dir = args
+        .path
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(&config.project_name));
 
     // Generate project
-    match generate_project(&project_dir, &config, interactive, args.force) {
-        Ok(()) => {
+    match generate_project(&project_dir, &config, interactive, args.force, args.dry_run) {
+        Ok(_) if args.quiet && args.dry_run => {}
+        Ok(_) if args.quiet => println!("{}", project_dir.display()),
+        Ok(report) if args.dry_run => {
+            println!(
+                "\n🔍 {}: {} {}",
+                locale.text("Dry run", "预演"),
+                report.files_created.len(),
+                locale.text(
+                    "files would be created, nothing was written",
+                    "个文件将被创建，未写入任何内容"
+                )
+            );
+        }
+        Ok(report) if report.files_created.is_empty() && !report.skipped_existing.is_empty() => {
+            println!(
+                "\n⏭️  {}: {} {}",
+                locale.text("Skipped", "已跳过"),
+                report.skipped_existing.len(),
+                locale.text(
+                    "files already exist, nothing was written",
+                    "个文件已存在，未写入任何内容"
+                )
+            );
+        }
+        Ok(_) => {
             // Print success message
             let message = get_success_message_with_config(&project_dir, &config);
             println!("{}", message);
         }
         Err(e) => {
-            eprintln!("\n❌ {}", format_error_message(&e));
+            eprintln!("\n❌ {}", format_error_message(&e, locale));
             std::process::exit(1);
         }
     }
@@ -0,0 +1,294 @@
+// Template changelog
+//
+// This module records what changed in the embedded templates for each CLI
+// version, so users can see what an upgrade would bring to their project.
+
+use crate::error::{CliError, Result};
+
+/// 模板变更记录 / A template change shipped in a CLI version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemplateChange {
+    /// CLI 版本 / CLI version that shipped the change
+    pub version: &'static str,
+    /// 变更说明 / Bilingual description of the change
+    pub summary: &'static str,
+}
+
+/// 模板变更日志 / Template changes, oldest version first
+///
+/// Add an entry here whenever a change to `templates/` affects generated output.
+pub const TEMPLATE_CHANGES: &[TemplateChange] = &[
+    TemplateChange {
+        version: "0.2.0",
+        summary: "Workspace mode templates (api, domain, infrastructure, common) / 工作区模式模板",
+    },
+    TemplateChange {
+        version: "0.2.0",
+        summary: "Workspace-aware multi-stage Dockerfile / 适配工作区的多阶段构建 Dockerfile",
+    },
+    TemplateChange {
+        version: "0.2.0",
+        summary: "GitHub Actions workflow with check, test, fmt and clippy jobs / 包含 check、test、fmt、clippy 的 GitHub Actions 工作流",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Test checking biz_errors.yaml against generated error codes / 校验 biz_errors.yaml 与错误码一致的测试",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional features can be exposed as Cargo features / 可选功能可暴露为 Cargo features",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional testcontainers PostgreSQL integration test / 可选的 testcontainers PostgreSQL 集成测试",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Feature routes can be nested under an API prefix / 功能路由可嵌套在 API 前缀下",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "All missing required env vars are reported at startup / 启动时报告所有缺失的必需环境变量",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: ".sqlfluff config and SQL lint CI job / .sqlfluff 配置与 SQL lint CI 任务",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional trailing-slash normalization layer / 可选的尾部斜杠规范化中间件",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Per-entity migrations and models / 按实体生成迁移与模型",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional startup banner / 可选的启动横幅",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Comment language follows --comments / 注释语言跟随 --comments",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional sonic-rs JSON responses / 可选的 sonic-rs JSON 响应",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Panics are logged with backtraces through tracing / 通过 tracing 记录带回溯的 panic",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Dependency lower bounds raised to versions that build / 依赖最低版本提升到可编译的版本",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "docker-compose.yml for database-backed projects / 为数据库项目生成 docker-compose.yml",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional PostgreSQL URL assembled from DB_* variables / 可选的由 DB_* 变量组装的 PostgreSQL 连接串",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional Procfile with a release migration step / 可选的 Procfile（含 release 迁移步骤）",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Cargo.toml authors include the git user email / Cargo.toml 的 authors 包含 git 用户邮箱",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional OpenAPI docs with Swagger UI / 可选的 OpenAPI 文档与 Swagger UI",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional server-sent events endpoint / 可选的服务器推送事件（SSE）端点",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: ".gitignore ignores the generation metadata file / .gitignore 忽略生成元数据文件",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional tower-sessions server-side sessions / 可选的 tower-sessions 服务端会话",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Configurable default HOST and PORT / 可配置的默认 HOST 与 PORT",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional .github/CODEOWNERS with per-crate stubs / 可选的按 crate 划分的 .github/CODEOWNERS",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: ".editorconfig with Rust-friendly indentation / 适合 Rust 的 .editorconfig 缩进配置",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional repository URL in Cargo.toml and the README / Cargo.toml 与 README 中可选的仓库地址",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional multipart file upload endpoint / 可选的 multipart 文件上传端点",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Configurable Rust edition in Cargo.toml / Cargo.toml 中可配置的 Rust 版本",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional Redis cache / 可选的 Redis 缓存",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Docker entrypoint waits for PostgreSQL before starting / Docker 入口脚本在启动前等待 PostgreSQL 就绪",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Versioned API route modules / 按版本划分的 API 路由模块",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "OpenAPI bearer JWT security scheme when auth is enabled / 启用认证时 OpenAPI 声明 JWT Bearer 安全方案",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: ".env.example grouped into commented sections per feature / .env.example 按功能分组并附注释",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "scripts/dev.sh, written executable like docker-entrypoint.sh / 新增可执行的 scripts/dev.sh",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Health response reports process uptime / 健康检查响应包含进程运行时长",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional CORS layer configured from CORS_ALLOWED_ORIGINS / 可选的 CORS 层，由 CORS_ALLOWED_ORIGINS 配置",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Tracing output follows the configured log format (json, pretty, compact) / 日志输出遵循配置的格式（json、pretty、compact）",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Request ID and trace layers when logging is enabled / 启用日志时添加请求 ID 与追踪层",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "CI projects also get CODEOWNERS and SECURITY.md / 启用 CI 时同时生成 CODEOWNERS 与 SECURITY.md",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "GitLab CI pipeline via --ci-provider gitlab / 通过 --ci-provider gitlab 生成 GitLab CI 流水线",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Single-mode /health pings the database pool and answers 503 while it is down / 单包模式的 /health 会检测数据库连接池，不可用时返回 503",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "JWT_ALGORITHM and JWT_EXPIRATION_SECONDS in the env files and Config / 环境文件与 Config 中新增 JWT_ALGORITHM 与 JWT_EXPIRATION_SECONDS",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional LICENSE file(s) and Cargo.toml license field / 可选的 LICENSE 文件与 Cargo.toml license 字段",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "With both databases the initial migration is PostgreSQL only, like entity migrations / 同时启用两种数据库时，初始迁移仅使用 PostgreSQL 语法，与实体迁移一致",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional gzip/brotli CompressionLayer / 可选的 gzip/brotli 响应压缩层 CompressionLayer",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Workspace member lists and api dependencies follow the workspace layout; new two-crate layout / 工作区成员列表与 api 依赖随工作区布局生成；新增两 crate 布局",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "Optional Prometheus /metrics endpoint and request metrics layer / 可选的 Prometheus /metrics 端点与请求指标层",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: ".env is only written with --with-env; main loads it with dotenvy / 仅在 --with-env 时生成 .env；main 通过 dotenvy 加载",
+    },
+    TemplateChange {
+        version: "0.3.0",
+        summary: "PostgreSQL db.rs gains create_user/count_users, used by the testcontainers test / PostgreSQL 的 db.rs 新增 create_user/count_users，供 testcontainers 测试调用",
+    },
+];
+
+/// 获取某版本之后的模板变更 / Template changes shipped after `since`
+///
+/// # Errors
+/// * `CliError::ValidationError` if `since` is not a `major.minor.patch` version
+///
+/// # Examples
+/// ```
+/// use axum_app_create::template::changelog::changes_since;
+///
+/// let changes = changes_since("0.2.0").unwrap();
+/// assert!(changes.iter().all(|change| change.version != "0.2.0"));
+/// ```
+pub fn changes_since(since: &str) -> Result<Vec<&'static TemplateChange>> {
+    let since = parse_version(since).ok_or_else(|| {
+        CliError::ValidationError(format!(
+            "❌ 无效的版本号 / Invalid version '{}'\n\n\
+             💡 修复建议 / Fix: 使用 major.minor.patch 格式 / Use the major.minor.patch format, e.g. 0.2.0",
+            since
+        ))
+    })?;
+
+    Ok(TEMPLATE_CHANGES
+        .iter()
+        .filter(|change| parse_version(change.version).is_some_and(|v| v > since))
+        .collect())
+}
+
+/// Parse `major.minor.patch` (an optional leading `v` is allowed)
+pub(crate) fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version.trim_start_matches('v').split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes_since() {
+        let changes = changes_since("0.1.0").unwrap();
+        assert!(changes.iter().any(|change| change.version == "0.2.0"));
+        assert!(changes.iter().any(|change| change.version == "0.3.0"));
+
+        let changes = changes_since("0.2.0").unwrap();
+        assert!(changes.iter().all(|change| change.version == "0.3.0"));
+
+        assert!(changes_since("9.0.0").unwrap().is_empty());
+        assert!(matches!(
+            changes_since("latest"),
+            Err(CliError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_changelog_versions_are_valid_and_ordered() {
+        let versions: Vec<_> = TEMPLATE_CHANGES
+            .iter()
+            .map(|change| parse_version(change.version).unwrap())
+            .collect();
+        assert!(versions.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
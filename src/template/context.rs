@@ -3,7 +3,14 @@
 // This module builds context data for template rendering.
 
 #[allow(unused_imports)]
-use crate::config::{DatabaseConfig, DatabaseOption, FeatureSet, ProjectConfig, ProjectMode};
+use crate::config::{
+    DatabaseConfig, DatabaseOption, FeatureSet, JsonBackend, ProjectConfig, ProjectMode,
+    WorkspaceLayout,
+};
+use crate::utils::casing::{to_crate_name, to_pascal_case, to_snake_case};
+use crate::utils::naming::pluralize;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::Serialize;
 
 /// Template context data structure
@@ -24,9 +31,15 @@ pub struct TemplateContext {
     /// Project name (PascalCase) for types
     pub project_name_pascal: String,
 
+    /// Crate name Cargo derives from the project name, for `use` paths and log targets
+    pub crate_name: String,
+
     /// Author name
     pub author_name: String,
 
+    /// Author email from git config (empty if unset)
+    pub author_email: String,
+
     /// Project description
     pub description: String,
 
@@ -52,11 +65,132 @@ pub struct TemplateContext {
     /// 是否为工作区模式 / Whether workspace mode
     pub is_workspace: bool,
 
+    /// 工作区布局 / Workspace layout: "layered" or "simple"
+    pub workspace_layout: String,
+
     /// 是否生成 CI/CD / Whether CI/CD is enabled
     pub has_ci: bool,
 
+    /// CI 平台 / CI provider the pipeline targets: "github" or "gitlab"
+    pub ci_provider: String,
+
+    /// 是否规范化尾部斜杠 / Whether to strip trailing slashes from request paths
+    pub normalize_path: bool,
+
+    /// tower-http 功能 / `tower-http` Cargo features the enabled middleware needs (empty = no dependency)
+    pub tower_http_features: Vec<String>,
+
+    /// 是否生成 .env / Whether to write `.env` next to `.env.example`
+    pub with_env: bool,
+
+    /// 是否由分量组装数据库连接串 / Whether the PostgreSQL URL can be assembled from DB_* vars
+    pub db_url_parts: bool,
+
+    /// 是否生成 Procfile / Whether to generate a Procfile
+    pub procfile: bool,
+
+    /// 是否生成 CODEOWNERS / Whether to generate .github/CODEOWNERS
+    pub codeowners: bool,
+
+    /// 代码所有者 / Default CODEOWNERS entry: the author's email, or an `@owner` placeholder
+    pub code_owner: String,
+
+    /// 仓库地址 / Repository URL, if known
+    pub repository: Option<String>,
+
+    /// 许可证 / SPDX license identifier, if one was chosen
+    pub license: Option<String>,
+
+    /// Default bind address of the server
+    pub server: ServerContext,
+
+    /// 是否打印启动横幅 / Whether main prints a startup banner
+    pub startup_banner: bool,
+
+    /// 是否使用 SIMD JSON / Whether responses are serialized with sonic-rs
+    pub json_simd: bool,
+
+    /// Rust 版本 / Edition written to Cargo.toml (e.g. "2024")
+    pub edition: String,
+
+    /// 是否生成 testcontainers 数据库测试 / Whether to generate testcontainers DB tests
+    pub has_testcontainers: bool,
+
+    /// sqlfluff 方言 / sqlfluff dialect for `.sqlfluff` (None if SQL lint disabled)
+    pub sql_lint_dialect: Option<String>,
+
     /// 工作区子 crate 信息 / Workspace crate metadata (None if single mode)
     pub workspace_crates: Option<Vec<WorkspaceCrateInfo>>,
+
+    /// Cargo features 配置 / Cargo features of the generated crate (None if disabled)
+    pub cargo_features: Option<CargoFeaturesContext>,
+
+    /// 路由挂载前缀 / Route mount prefixes
+    pub api_routes: ApiRoutesContext,
+
+    /// 输出语言 / Languages included in generated docs
+    pub locale: LocaleContext,
+
+    /// 注释语言 / Languages included in comments of generated files
+    pub comment_locale: LocaleContext,
+
+    /// 生成的 JWT 密钥 / JWT secret written to `.env` (None if auth disabled)
+    pub jwt_secret: Option<String>,
+
+    /// 是否生成实体 / Whether entity migrations and models are generated
+    pub has_entities: bool,
+
+    /// 是否有数据库迁移 / Whether the project has SQL migrations to run
+    pub has_migrations: bool,
+
+    /// 实体列表 / Entities to generate (empty if no database)
+    pub entities: Vec<EntityContext>,
+
+    /// 当前渲染的实体 / Entity being rendered by a per-entity template
+    pub entity: Option<EntityContext>,
+
+    /// 是否生成 API 版本模块 / Whether versioned API modules are generated
+    pub has_api_versions: bool,
+
+    /// API 版本列表 / API versions nested under the API prefix
+    pub api_versions: Vec<ApiVersionContext>,
+
+    /// 当前渲染的 API 版本 / API version being rendered by a per-version template
+    pub api_version: Option<ApiVersionContext>,
+
+    /// 当前渲染的处理器 / Handler being rendered by `new-handler`
+    pub handler: Option<HandlerContext>,
+}
+
+/// API 版本信息 / API version metadata for versioned route modules
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiVersionContext {
+    /// 版本名称 / Version and module name (e.g. "v1")
+    pub name: String,
+    /// 挂载路径 / Path the version is nested under (e.g. "/api/v1")
+    pub path: String,
+}
+
+/// 处理器信息 / Handler metadata for `new-handler`
+#[derive(Debug, Clone, Serialize)]
+pub struct HandlerContext {
+    /// 模块名称 / Module name (e.g. "orders")
+    pub name: String,
+    /// 路由路径 / Route the handler is served at (e.g. "/orders")
+    pub path: String,
+}
+
+/// 实体信息 / Entity metadata for migrations and models
+#[derive(Debug, Clone, Serialize)]
+pub struct EntityContext {
+    /// 结构体名称 / Struct name (e.g. "BlogPost")
+    pub name: String,
+    /// 模块名称 / Module name (e.g. "blog_post")
+    pub snake: String,
+    /// 表名 / Table name (e.g. "blog_posts")
+    pub table: String,
+    /// 迁移版本号 / Migration version prefix (e.g. "002")
+    pub migration_version: String,
 }
 
 /// 工作区子 crate 信息 / Workspace crate metadata
@@ -72,6 +206,46 @@ pub struct WorkspaceCrateInfo {
     pub workspace_deps: Vec<String>,
 }
 
+/// Languages included in generated docs
+#[derive(Debug, Clone, Serialize)]
+pub struct LocaleContext {
+    /// Include English text
+    pub en: bool,
+
+    /// Include Chinese text
+    pub zh: bool,
+}
+
+/// Default server bind address
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerContext {
+    /// Default `HOST`
+    pub host: String,
+
+    /// Default `PORT`
+    pub port: u16,
+
+    /// URL for reaching the server locally (e.g. `http://127.0.0.1:8080`)
+    pub url: String,
+}
+
+/// Mount prefixes for feature routes
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiRoutesContext {
+    /// Prefix auth routes are nested under ("" = mounted at root)
+    pub auth_prefix: String,
+}
+
+/// Cargo features exposed by the generated crate
+#[derive(Debug, Clone, Serialize)]
+pub struct CargoFeaturesContext {
+    /// Features enabled by default (e.g. ["auth", "postgres"])
+    pub default_features: Vec<String>,
+
+    /// `cfg` predicate gating database code (e.g. `feature = "postgres"`)
+    pub database_cfg: String,
+}
+
 /// Feature flags for template conditionals
 #[derive(Debug, Clone, Serialize)]
 pub struct FeaturesContext {
@@ -92,6 +266,30 @@ pub struct FeaturesContext {
 
     /// Business error support enabled
     pub has_biz_error: bool,
+
+    /// OpenAPI documentation enabled
+    pub has_openapi: bool,
+
+    /// Server-sent events endpoint enabled
+    pub has_sse: bool,
+
+    /// Server-side sessions enabled
+    pub has_sessions: bool,
+
+    /// Multipart file upload endpoint enabled
+    pub has_multipart: bool,
+
+    /// Redis cache enabled
+    pub has_cache: bool,
+
+    /// CORS layer enabled
+    pub has_cors: bool,
+
+    /// Response compression enabled
+    pub has_compression: bool,
+
+    /// Prometheus metrics enabled
+    pub has_metrics: bool,
 }
 
 /// Database context for templates
@@ -177,6 +375,14 @@ impl TemplateContext {
             has_auth: config.features.authentication,
             has_logging: config.features.logging,
             has_biz_error: config.features.biz_error,
+            has_openapi: config.features.openapi,
+            has_sse: config.features.sse,
+            has_sessions: config.features.sessions,
+            has_multipart: config.features.multipart,
+            has_cache: config.features.cache,
+            has_cors: config.features.cors,
+            has_compression: config.features.compression,
+            has_metrics: config.features.metrics,
         };
 
         // Build database context (if enabled)
@@ -194,17 +400,17 @@ impl TemplateContext {
         };
 
         // Build authentication context (if enabled)
-        let authentication = if features.has_auth {
-            config.authentication.as_ref().map(|auth| AuthContext {
-                example_secret: auth.example_secret.clone(),
+        // (defaults when the feature is on without an explicit AuthConfig)
+        let authentication = features.has_auth.then(|| {
+            let auth = config.authentication.clone().unwrap_or_default();
+            AuthContext {
+                example_secret: auth.example_secret,
                 expiration_seconds: auth.expiration_seconds,
-                algorithm: auth.algorithm.clone(),
+                algorithm: auth.algorithm,
                 include_user_model: auth.include_user_model,
                 include_endpoints: auth.include_endpoints,
-            })
-        } else {
-            None
-        };
+            }
+        });
 
         // Build logging context (if enabled)
         let logging = if features.has_logging {
@@ -229,17 +435,88 @@ impl TemplateContext {
             None
         };
 
+        // Build Cargo features context (single mode only)
+        let cargo_features = if config.cargo_features && config.mode == ProjectMode::Single {
+            Some(build_cargo_features(&features))
+        } else {
+            None
+        };
+
+        // Generate secrets from a seeded RNG (reproducible with --seed-rng)
+        let mut rng = match config.seed_rng {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+        let jwt_secret = features.has_auth.then(|| generate_secret(&mut rng));
+
+        // Feature routes listed in api_routes are nested under api_prefix
+        let route_prefix = |feature: &str| {
+            if config.api_routes.iter().any(|r| r == feature) {
+                config.api_prefix.clone()
+            } else {
+                String::new()
+            }
+        };
+        let api_routes = ApiRoutesContext {
+            auth_prefix: route_prefix("auth"),
+        };
+
+        // Entity migrations follow 001_initial.sql when it creates the users table
+        let first_migration = if features.has_auth && config.mode == ProjectMode::Single {
+            2
+        } else {
+            1
+        };
+        let entities: Vec<EntityContext> = if features.has_database {
+            config
+                .entities
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    let snake = to_snake_case(name);
+                    EntityContext {
+                        name: name.clone(),
+                        table: pluralize(&snake),
+                        snake,
+                        migration_version: format!("{:03}", first_migration + i),
+                    }
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        // Workspace projects only get migrations for entities
+        let has_migrations =
+            features.has_database && (config.mode == ProjectMode::Single || !entities.is_empty());
+
+        let api_versions: Vec<ApiVersionContext> = config
+            .api_versions
+            .iter()
+            .map(|name| ApiVersionContext {
+                name: name.clone(),
+                path: format!("{}/{}", config.api_prefix, name),
+            })
+            .collect();
+
         let project_name_snake = to_snake_case(project_name);
+        let author_email = get_git_user_email().unwrap_or_default();
+        let code_owner = if author_email.is_empty() {
+            "@owner".to_string()
+        } else {
+            author_email.clone()
+        };
 
         Self {
             project_name: project_name.clone(),
             project_name_snake: project_name_snake.clone(),
             project_name_snake_alias: project_name_snake,
             project_name_pascal: to_pascal_case(project_name),
+            crate_name: to_crate_name(project_name),
             author_name: config.author_name.clone().unwrap_or_else(|| {
                 // Try to get from git config
                 get_git_user_name().unwrap_or_else(|| "Anonymous".to_string())
             }),
+            author_email: author_email.clone(),
             description: config
                 .description
                 .clone()
@@ -250,65 +527,147 @@ impl TemplateContext {
             authentication,
             logging,
             biz_error,
-            is_workspace: config.mode == ProjectMode::Workspace,
-            has_ci: config.ci,
-            workspace_crates: if config.mode == ProjectMode::Workspace {
-                let project = &config.project_name;
-                Some(vec![
-                    WorkspaceCrateInfo {
-                        name: "api".to_string(),
-                        package_name: format!("{}-api", project),
-                        kind: "bin".to_string(),
-                        workspace_deps: vec![
-                            "domain".to_string(),
-                            "infrastructure".to_string(),
-                            "common".to_string(),
-                        ],
-                    },
-                    WorkspaceCrateInfo {
-                        name: "domain".to_string(),
-                        package_name: format!("{}-domain", project),
-                        kind: "lib".to_string(),
-                        workspace_deps: vec![],
-                    },
-                    WorkspaceCrateInfo {
-                        name: "infrastructure".to_string(),
-                        package_name: format!("{}-infrastructure", project),
-                        kind: "lib".to_string(),
-                        workspace_deps: vec!["domain".to_string()],
-                    },
-                    WorkspaceCrateInfo {
-                        name: "common".to_string(),
-                        package_name: format!("{}-common", project),
-                        kind: "lib".to_string(),
-                        workspace_deps: vec![],
-                    },
-                ])
+            sql_lint_dialect: if config.sql_lint && config.mode == ProjectMode::Single {
+                config.features.database.sql_dialect().map(str::to_string)
             } else {
                 None
             },
+            is_workspace: config.mode == ProjectMode::Workspace,
+            workspace_layout: config.workspace_layout.to_string(),
+            has_ci: config.ci,
+            ci_provider: config.ci_provider.to_string(),
+            normalize_path: config.normalize_path,
+            tower_http_features: [
+                (config.normalize_path, "normalize-path"),
+                (config.features.cors, "cors"),
+                (config.features.compression, "compression-gzip"),
+                (config.features.compression, "compression-br"),
+                (config.features.logging, "request-id"),
+                (config.features.logging, "trace"),
+            ]
+            .into_iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, feature)| feature.to_string())
+            .collect(),
+            with_env: config.with_env,
+            db_url_parts: config.db_url_parts && config.features.database.supports_postgresql(),
+            procfile: config.procfile,
+            codeowners: config.codeowners,
+            code_owner,
+            repository: config.repository.clone(),
+            license: config.license.clone(),
+            server: ServerContext {
+                host: config.server.host.clone(),
+                port: config.server.port,
+                url: config.server.local_url(),
+            },
+            startup_banner: config.startup_banner,
+            json_simd: config.json_backend == JsonBackend::Simd,
+            edition: config.edition.to_string(),
+            has_testcontainers: config.testcontainers
+                && config.features.database == DatabaseOption::PostgreSQL
+                && config.mode == ProjectMode::Single,
+            workspace_crates: (config.mode == ProjectMode::Workspace)
+                .then(|| workspace_crates(&config.project_name, config.workspace_layout)),
+            cargo_features,
+            api_routes,
+            locale: LocaleContext {
+                en: config.locale.includes_en(),
+                zh: config.locale.includes_zh(),
+            },
+            comment_locale: LocaleContext {
+                en: config.comment_locale.includes_en(),
+                zh: config.comment_locale.includes_zh(),
+            },
+            jwt_secret,
+            has_entities: !entities.is_empty(),
+            has_migrations,
+            entities,
+            entity: None,
+            has_api_versions: !api_versions.is_empty(),
+            api_versions,
+            api_version: None,
+            handler: None,
+        }
+    }
+
+    /// Context for rendering a per-entity template for `entity`
+    pub fn with_entity(&self, entity: &EntityContext) -> Self {
+        Self {
+            entity: Some(entity.clone()),
+            ..self.clone()
+        }
+    }
+
+    /// Context for rendering a per-version template for `version`
+    pub fn with_api_version(&self, version: &ApiVersionContext) -> Self {
+        Self {
+            api_version: Some(version.clone()),
+            ..self.clone()
         }
     }
-}
 
-/// Convert kebab-case to snake_case
-fn to_snake_case(name: &str) -> String {
-    name.replace('-', "_")
+    /// Context for rendering the `new-handler` template for `handler`
+    pub fn with_handler(&self, handler: &HandlerContext) -> Self {
+        Self {
+            handler: Some(handler.clone()),
+            ..self.clone()
+        }
+    }
 }
 
-/// Convert kebab-case to PascalCase
-fn to_pascal_case(name: &str) -> String {
-    name.split('-')
-        .map(|word| {
-            let mut chars = word.chars();
-            match chars.next() {
-                None => String::new(),
-                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-            }
+/// Member crates of a workspace with `layout`, in `members` order
+pub(crate) fn workspace_crates(project: &str, layout: WorkspaceLayout) -> Vec<WorkspaceCrateInfo> {
+    let crates: &[(&str, &str, &[&str])] = match layout {
+        WorkspaceLayout::Layered => &[
+            ("api", "bin", &["domain", "infrastructure", "common"]),
+            ("domain", "lib", &[]),
+            ("infrastructure", "lib", &["domain"]),
+            ("common", "lib", &[]),
+        ],
+        WorkspaceLayout::Simple => &[("api", "bin", &["core"]), ("core", "lib", &[])],
+    };
+
+    crates
+        .iter()
+        .map(|(name, kind, deps)| WorkspaceCrateInfo {
+            name: name.to_string(),
+            package_name: format!("{}-{}", project, name),
+            kind: kind.to_string(),
+            workspace_deps: deps.iter().map(|dep| dep.to_string()).collect(),
         })
         .collect()
 }
 
+/// Build the Cargo features exposed by a generated single-mode crate
+fn build_cargo_features(features: &FeaturesContext) -> CargoFeaturesContext {
+    let mut default_features = Vec::new();
+    let mut database_features = Vec::new();
+
+    if features.has_auth {
+        default_features.push("auth".to_string());
+    }
+    if features.has_postgresql {
+        default_features.push("postgres".to_string());
+        database_features.push("feature = \"postgres\"".to_string());
+    }
+    if features.has_sqlite {
+        default_features.push("sqlite".to_string());
+        database_features.push("feature = \"sqlite\"".to_string());
+    }
+
+    let database_cfg = if database_features.len() > 1 {
+        format!("any({})", database_features.join(", "))
+    } else {
+        database_features.join("")
+    };
+
+    CargoFeaturesContext {
+        default_features,
+        database_cfg,
+    }
+}
+
 /// Get user name from git config
 fn get_git_user_name() -> Option<String> {
     use std::process::Command;
@@ -323,25 +682,31 @@ fn get_git_user_name() -> Option<String> {
         })
 }
 
-/// Get current year
-fn get_current_year() -> String {
+/// Get user email from git config
+fn get_git_user_email() -> Option<String> {
     use std::process::Command;
 
-    // Try date command first (more reliable on Linux/macOS)
-    if let Ok(output) = Command::new("date").arg("+%Y").output()
-        && output.status.success()
-        && let Some(year) = String::from_utf8_lossy(&output.stdout)
-            .trim()
-            .chars()
-            .next()
-        && year == '2'
-    {
-        return String::from_utf8_lossy(&output.stdout).trim().to_string();
-    }
+    Command::new("git")
+        .args(["config", "user.email"])
+        .output()
+        .ok()
+        .and_then(|output| {
+            let email = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if email.is_empty() { None } else { Some(email) }
+        })
+}
+
+/// Generate a 256-bit hex-encoded secret
+fn generate_secret(rng: &mut impl Rng) -> String {
+    let bytes: [u8; 32] = rng.random();
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Get current year
+fn get_current_year() -> String {
+    use chrono::Datelike;
 
-    // Fallback to chrono (which we use in the CLI)
-    // This is hardcoded to avoid chrono dependency for just the year
-    "2026".to_string() // Update this when needed
+    chrono::Utc::now().year().to_string()
 }
 
 #[cfg(test)]
@@ -350,15 +715,10 @@ mod tests {
     use crate::config::{DatabaseOption, FeatureSet};
 
     #[test]
-    fn test_to_snake_case() {
-        assert_eq!(to_snake_case("my-app"), "my_app");
-        assert_eq!(to_snake_case("my_axum_app"), "my_axum_app");
-    }
-
-    #[test]
-    fn test_to_pascal_case() {
-        assert_eq!(to_pascal_case("my-app"), "MyApp");
-        assert_eq!(to_pascal_case("axum-app-create"), "AxumAppCreate");
+    fn test_get_current_year() {
+        let year = get_current_year();
+        assert_eq!(year.len(), 4);
+        assert!(year.parse::<i32>().unwrap() >= 2025);
     }
 
     #[test]
@@ -375,6 +735,20 @@ mod tests {
         assert_eq!(ctx.project_name_pascal, "MyTestApp");
     }
 
+    #[test]
+    fn test_template_context_mixed_case_name() {
+        let config = ProjectConfig {
+            project_name: "my-API-app".to_string(),
+            ..Default::default()
+        };
+
+        let ctx = TemplateContext::from_config(&config);
+
+        assert_eq!(ctx.project_name_snake, "my_api_app");
+        assert_eq!(ctx.project_name_pascal, "MyApiApp");
+        assert_eq!(ctx.crate_name, "my_API_app");
+    }
+
     #[test]
     fn test_template_context_with_database() {
         let config = ProjectConfig {
@@ -447,6 +821,247 @@ mod tests {
         assert!(!ctx.has_ci);
     }
 
+    #[test]
+    fn test_template_context_cargo_features() {
+        let config = ProjectConfig {
+            project_name: "my-app".to_string(),
+            features: FeatureSet {
+                database: DatabaseOption::Both,
+                authentication: true,
+                ..Default::default()
+            },
+            cargo_features: true,
+            ..Default::default()
+        };
+
+        let ctx = TemplateContext::from_config(&config);
+        let cargo_features = ctx.cargo_features.unwrap();
+        assert_eq!(
+            cargo_features.default_features,
+            vec!["auth", "postgres", "sqlite"]
+        );
+        assert_eq!(
+            cargo_features.database_cfg,
+            "any(feature = \"postgres\", feature = \"sqlite\")"
+        );
+    }
+
+    #[test]
+    fn test_template_context_cargo_features_disabled() {
+        let config = ProjectConfig {
+            project_name: "my-app".to_string(),
+            ..Default::default()
+        };
+
+        let ctx = TemplateContext::from_config(&config);
+        assert!(ctx.cargo_features.is_none());
+    }
+
+    #[test]
+    fn test_template_context_jwt_secret_seeded() {
+        let config = ProjectConfig {
+            project_name: "my-app".to_string(),
+            features: FeatureSet {
+                authentication: true,
+                ..Default::default()
+            },
+            seed_rng: Some(42),
+            ..Default::default()
+        };
+
+        let first = TemplateContext::from_config(&config).jwt_secret.unwrap();
+        let second = TemplateContext::from_config(&config).jwt_secret.unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 64);
+
+        let other = TemplateContext::from_config(&ProjectConfig {
+            seed_rng: Some(43),
+            ..config
+        });
+        assert_ne!(other.jwt_secret.unwrap(), first);
+    }
+
+    #[test]
+    fn test_template_context_testcontainers_requires_postgresql() {
+        let postgres = ProjectConfig {
+            project_name: "my-app".to_string(),
+            features: FeatureSet {
+                database: DatabaseOption::PostgreSQL,
+                ..Default::default()
+            },
+            testcontainers: true,
+            ..Default::default()
+        };
+        assert!(TemplateContext::from_config(&postgres).has_testcontainers);
+
+        let sqlite = ProjectConfig {
+            features: FeatureSet {
+                database: DatabaseOption::SQLite,
+                ..Default::default()
+            },
+            ..postgres
+        };
+        assert!(!TemplateContext::from_config(&sqlite).has_testcontainers);
+    }
+
+    #[test]
+    fn test_template_context_api_routes() {
+        let config = ProjectConfig {
+            project_name: "my-app".to_string(),
+            api_prefix: "/v1".to_string(),
+            api_routes: vec!["auth".to_string()],
+            ..Default::default()
+        };
+        let ctx = TemplateContext::from_config(&config);
+        assert_eq!(ctx.api_routes.auth_prefix, "/v1");
+
+        let ctx = TemplateContext::from_config(&ProjectConfig::default());
+        assert_eq!(ctx.api_routes.auth_prefix, "");
+    }
+
+    #[test]
+    fn test_template_context_tower_http_features() {
+        let ctx = TemplateContext::from_config(&ProjectConfig::default());
+        assert!(ctx.tower_http_features.is_empty());
+
+        let config = ProjectConfig {
+            project_name: "my-app".to_string(),
+            features: FeatureSet {
+                logging: true,
+                cors: true,
+                compression: true,
+                ..Default::default()
+            },
+            normalize_path: true,
+            ..Default::default()
+        };
+        let ctx = TemplateContext::from_config(&config);
+        assert_eq!(
+            ctx.tower_http_features,
+            [
+                "normalize-path",
+                "cors",
+                "compression-gzip",
+                "compression-br",
+                "request-id",
+                "trace"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_template_context_api_versions() {
+        let config = ProjectConfig {
+            project_name: "my-app".to_string(),
+            api_versions: vec!["v1".to_string(), "v2".to_string()],
+            ..Default::default()
+        };
+        let ctx = TemplateContext::from_config(&config);
+        assert!(ctx.has_api_versions);
+        assert_eq!(ctx.api_versions[1].path, "/api/v2");
+
+        let ctx = TemplateContext::from_config(&ProjectConfig::default());
+        assert!(!ctx.has_api_versions);
+    }
+
+    #[test]
+    fn test_template_context_sql_lint_dialect() {
+        let config = ProjectConfig {
+            project_name: "my-app".to_string(),
+            features: FeatureSet {
+                database: DatabaseOption::SQLite,
+                ..Default::default()
+            },
+            sql_lint: true,
+            ..Default::default()
+        };
+        let ctx = TemplateContext::from_config(&config);
+        assert_eq!(ctx.sql_lint_dialect.as_deref(), Some("sqlite"));
+
+        let no_db = ProjectConfig {
+            features: FeatureSet::default(),
+            ..config
+        };
+        assert!(
+            TemplateContext::from_config(&no_db)
+                .sql_lint_dialect
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_template_context_entities() {
+        let config = ProjectConfig {
+            project_name: "my-app".to_string(),
+            features: FeatureSet {
+                database: DatabaseOption::PostgreSQL,
+                authentication: true,
+                ..Default::default()
+            },
+            entities: vec!["BlogPost".to_string(), "Category".to_string()],
+            ..Default::default()
+        };
+        let ctx = TemplateContext::from_config(&config);
+        assert!(ctx.has_entities);
+        assert_eq!(ctx.entities[0].snake, "blog_post");
+        assert_eq!(ctx.entities[0].table, "blog_posts");
+        // 001_initial.sql holds the users table
+        assert_eq!(ctx.entities[0].migration_version, "002");
+        assert_eq!(ctx.entities[1].table, "categories");
+        assert_eq!(ctx.entities[1].migration_version, "003");
+
+        let no_db = ProjectConfig {
+            features: FeatureSet::default(),
+            ..config
+        };
+        let ctx = TemplateContext::from_config(&no_db);
+        assert!(!ctx.has_entities);
+        assert!(ctx.entities.is_empty());
+    }
+
+    #[test]
+    fn test_template_context_jwt_secret_without_auth() {
+        let config = ProjectConfig {
+            project_name: "my-app".to_string(),
+            seed_rng: Some(42),
+            ..Default::default()
+        };
+
+        let ctx = TemplateContext::from_config(&config);
+        assert!(ctx.jwt_secret.is_none());
+    }
+
+    #[test]
+    fn test_cargo_toml_authors_with_and_without_email() {
+        use crate::template::engine::TemplateEngine;
+
+        let config = ProjectConfig {
+            author_name: Some("Jane Doe".to_string()),
+            ..Default::default()
+        };
+        let mut ctx = TemplateContext::from_config(&config);
+        let engine = TemplateEngine::new();
+        let template = include_str!("templates/single_mode/Cargo.toml.hbs");
+
+        ctx.author_email = "jane@example.com".to_string();
+        let rendered = engine
+            .render_template("Cargo.toml", template, &ctx)
+            .unwrap();
+        assert!(rendered.contains(r#"authors = ["Jane Doe <jane@example.com>"]"#));
+
+        ctx.author_email = String::new();
+        let rendered = engine
+            .render_template("Cargo.toml", template, &ctx)
+            .unwrap();
+        assert!(rendered.contains(r#"authors = ["Jane Doe"]"#));
+
+        ctx.author_name = r#"Dara "DO" O'Brien"#.to_string();
+        let rendered = engine
+            .render_template("Cargo.toml", template, &ctx)
+            .unwrap();
+        assert!(rendered.contains(r#"authors = ["Dara \"DO\" O'Brien"]"#));
+    }
+
     #[test]
     fn test_template_context_workspace_crates_single_mode() {
         let config = ProjectConfig {
@@ -0,0 +1,217 @@
+// Custom template loader
+//
+// This module loads user-provided templates from a directory on disk.
+
+use crate::error::{CliError, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// 局部模板子目录 / Subdirectory of a custom template dir holding Handlebars partials
+pub const PARTIALS_DIR: &str = "partials";
+
+/// 从文件系统加载自定义模板 / Load custom templates from filesystem
+pub struct CustomTemplateLoader;
+
+impl CustomTemplateLoader {
+    /// 递归扫描目录，加载所有模板文件 / Recursively scan a directory and load every template file
+    ///
+    /// Keys are output paths relative to `dir`, using `/` separators and with
+    /// a trailing `.hbs` stripped (`src/main.rs.hbs` → `src/main.rs`). Files
+    /// under [`PARTIALS_DIR`] are partials, not templates, and are left out.
+    ///
+    /// # Errors
+    /// * `CliError::Config` if `dir` does not exist or is not a directory
+    /// * `CliError::Template` if two files map to the same key, including keys
+    ///   that differ only by case (they collide on case-insensitive filesystems)
+    pub fn load(dir: &Path) -> Result<HashMap<String, String>> {
+        let mut templates = HashMap::new();
+        let mut sources: HashMap<String, Vec<PathBuf>> = HashMap::new();
+
+        for path in template_files(dir)? {
+            let relative = path.strip_prefix(dir).unwrap_or(&path).to_path_buf();
+            if relative.starts_with(PARTIALS_DIR) {
+                continue;
+            }
+            let key = template_key(&relative);
+            let content = std::fs::read_to_string(&path)?;
+
+            sources
+                .entry(key.to_lowercase())
+                .or_default()
+                .push(relative);
+            templates.insert(key, content);
+        }
+
+        let mut collisions: Vec<String> = sources
+            .values()
+            .filter(|paths| paths.len() > 1)
+            .map(|paths| {
+                paths
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+
+        if !collisions.is_empty() {
+            collisions.sort();
+            return Err(CliError::Template(format!(
+                "❌ 自定义模板冲突 / Conflicting custom templates map to the same output file:\n  - {}\n\n\
+                 💡 修复建议 / Fix: 每个输出文件只保留一个模板 / Keep only one template per output file",
+                collisions.join("\n  - ")
+            )));
+        }
+
+        Ok(templates)
+    }
+
+    /// 加载局部模板 / Load the Handlebars partials under `dir/partials`
+    ///
+    /// Names are paths relative to the partials directory with `.hbs` stripped,
+    /// so `partials/license_header.hbs` is used as `{{> license_header}}`.
+    ///
+    /// # Errors
+    /// * `CliError::Config` if `dir` does not exist or is not a directory
+    pub fn load_partials(dir: &Path) -> Result<HashMap<String, String>> {
+        let partials_dir = dir.join(PARTIALS_DIR);
+        let mut partials = HashMap::new();
+
+        for path in template_files(dir)? {
+            let Ok(relative) = path.strip_prefix(&partials_dir) else {
+                continue;
+            };
+            partials.insert(template_key(relative), std::fs::read_to_string(&path)?);
+        }
+
+        Ok(partials)
+    }
+}
+
+/// All files under a custom template directory, sorted
+fn template_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Err(CliError::Config(format!(
+            "❌ 自定义模板目录不存在或不是目录 / Custom template directory does not exist or is not a directory: '{}'\n\n\
+             💡 修复建议 / Fix: 检查 --template-dir 路径 / Check the --template-dir path",
+            dir.display()
+        )));
+    }
+
+    let mut files = Vec::new();
+    collect_files(dir, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+/// Recursively collect all files under `dir`, skipping `.git`
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            if path.file_name().is_some_and(|name| name == ".git") {
+                continue;
+            }
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Map a template file's relative path to its output key
+fn template_key(relative: &Path) -> String {
+    let key = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    match key.strip_suffix(".hbs") {
+        Some(stripped) => stripped.to_string(),
+        None => key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_strips_hbs_extension() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+        std::fs::write(temp_dir.path().join("src/main.rs.hbs"), "fn main() {}").unwrap();
+        std::fs::write(temp_dir.path().join("README.md"), "# {{project_name}}").unwrap();
+
+        let templates = CustomTemplateLoader::load(temp_dir.path()).unwrap();
+        assert_eq!(templates.len(), 2);
+        assert_eq!(templates["src/main.rs"], "fn main() {}");
+        assert_eq!(templates["README.md"], "# {{project_name}}");
+    }
+
+    #[test]
+    fn test_load_rejects_extension_collision() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Cargo.toml.hbs"), "a").unwrap();
+        std::fs::write(temp_dir.path().join("Cargo.toml"), "b").unwrap();
+
+        let err = CustomTemplateLoader::load(temp_dir.path()).unwrap_err();
+        let msg = err.to_string();
+        assert!(matches!(err, CliError::Template(_)));
+        assert!(msg.contains("Cargo.toml.hbs"));
+        assert!(msg.contains("Cargo.toml,") || msg.contains(", Cargo.toml"));
+    }
+
+    #[test]
+    fn test_load_rejects_case_collision() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("README.md.hbs"), "a").unwrap();
+        std::fs::write(temp_dir.path().join("readme.md.hbs"), "b").unwrap();
+
+        // Case-insensitive filesystems keep only one of the two files
+        let files = std::fs::read_dir(temp_dir.path()).unwrap().count();
+        if files < 2 {
+            return;
+        }
+
+        let err = CustomTemplateLoader::load(temp_dir.path()).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("README.md.hbs"));
+        assert!(msg.contains("readme.md.hbs"));
+    }
+
+    #[test]
+    fn test_load_separates_partials() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("partials/rust")).unwrap();
+        std::fs::write(
+            temp_dir.path().join("partials/license_header.hbs"),
+            "// MIT",
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("partials/rust/tracing.hbs"), "init()").unwrap();
+        std::fs::write(
+            temp_dir.path().join("README.md.hbs"),
+            "{{> license_header}}",
+        )
+        .unwrap();
+
+        let templates = CustomTemplateLoader::load(temp_dir.path()).unwrap();
+        assert_eq!(templates.keys().collect::<Vec<_>>(), vec!["README.md"]);
+
+        let partials = CustomTemplateLoader::load_partials(temp_dir.path()).unwrap();
+        assert_eq!(partials.len(), 2);
+        assert_eq!(partials["license_header"], "// MIT");
+        assert_eq!(partials["rust/tracing"], "init()");
+    }
+
+    #[test]
+    fn test_load_missing_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = CustomTemplateLoader::load(&temp_dir.path().join("missing"));
+        assert!(matches!(result, Err(CliError::Config(_))));
+    }
+}
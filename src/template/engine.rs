@@ -4,7 +4,11 @@
 
 use crate::error::{CliError, Result};
 use crate::template::context::TemplateContext;
+use crate::utils::casing::{to_pascal_case, to_snake_case};
+use diffy::DiffOptions;
 use handlebars::Handlebars;
+use std::collections::HashMap;
+use toml_edit::{DocumentMut, Item};
 
 /// Template rendering engine
 pub struct TemplateEngine {
@@ -25,6 +29,33 @@ impl TemplateEngine {
         Self { handlebars }
     }
 
+    /// Create a template engine with Handlebars partials registered
+    ///
+    /// Templates can then inline a partial with `{{> name}}`.
+    ///
+    /// # Errors
+    /// * `CliError::Template` if a partial has invalid syntax
+    pub fn with_partials(partials: &HashMap<String, String>) -> Result<Self> {
+        let mut engine = Self::new();
+
+        for (name, content) in partials {
+            engine
+                .handlebars
+                .register_partial(name, content)
+                .map_err(|e| {
+                    CliError::Template(format!(
+                        "❌ 局部模板注册失败 / Partial registration failed\n\n\
+                         📄 局部模板名称 / Partial name: {}\n\n\
+                         💡 修复建议 / Fix: 检查模板语法 / Check template syntax\n\n\
+                         ❌ 错误详情 / Error: {}",
+                        name, e
+                    ))
+                })?;
+        }
+
+        Ok(engine)
+    }
+
     /// Render a template string with the given context
     ///
     /// # Arguments
@@ -125,6 +156,116 @@ impl Default for TemplateEngine {
     }
 }
 
+/// 渲染差异 / Unified diff from the `current` content of `path` to its freshly `rendered` content
+///
+/// Returns `None` if the two are identical.
+///
+/// # Examples
+/// ```
+/// use axum_app_create::template::engine::render_diff;
+///
+/// let diff = render_diff("README.md", "# app\n", "# my-app\n").unwrap();
+/// assert!(diff.contains("@@ -1 +1 @@"));
+/// assert!(render_diff("README.md", "same\n", "same\n").is_none());
+/// ```
+pub fn render_diff(path: &str, current: &str, rendered: &str) -> Option<String> {
+    if current == rendered {
+        return None;
+    }
+    let patch = DiffOptions::new()
+        .set_original_filename(format!("a/{}", path))
+        .set_modified_filename(format!("b/{}", path))
+        .create_patch(current, rendered);
+    Some(patch.to_string())
+}
+
+/// Cargo.toml tables whose generated keys are merged into an edited manifest
+const MERGED_MANIFEST_TABLES: &[&[&str]] = &[
+    &["dependencies"],
+    &["dev-dependencies"],
+    &["build-dependencies"],
+    &["features"],
+    &["workspace", "dependencies"],
+];
+
+/// 合并清单 / Add the keys a freshly `generated` Cargo.toml introduces to the `current` one
+///
+/// Both manifests are parsed with `toml_edit`. Every key of the dependency and
+/// `[features]` tables that `generated` has and `current` lacks is added;
+/// keys the project already has keep its value, so user-added dependencies,
+/// pinned versions and comments survive. Everything outside those tables is
+/// left as it is in `current`.
+///
+/// Returns `None` if either manifest is not valid TOML.
+///
+/// # Examples
+/// ```
+/// use axum_app_create::template::engine::merge_manifest;
+///
+/// let current = "[dependencies]\n# pinned\naxum = \"=0.8.1\"\nreqwest = \"0.12\"\n";
+/// let generated = "[dependencies]\naxum = \"0.8\"\njsonwebtoken = \"9\"\n";
+///
+/// let merged = merge_manifest(current, generated).unwrap();
+/// assert!(merged.contains("# pinned\naxum = \"=0.8.1\""));
+/// assert!(merged.contains("reqwest = \"0.12\""));
+/// assert!(merged.contains("jsonwebtoken = \"9\""));
+/// ```
+pub fn merge_manifest(current: &str, generated: &str) -> Option<String> {
+    let mut merged: DocumentMut = current.parse().ok()?;
+    let generated: DocumentMut = generated.parse().ok()?;
+
+    for path in MERGED_MANIFEST_TABLES {
+        let Some(new) = path
+            .iter()
+            .try_fold(generated.as_item(), |item, key| item.get(key))
+        else {
+            continue;
+        };
+        let (table, parents) = path.split_last()?;
+        let Some(parent) = parents
+            .iter()
+            .try_fold(merged.as_item_mut(), |item, key| item.get_mut(key))
+            .and_then(Item::as_table_like_mut)
+        else {
+            continue;
+        };
+
+        if !parent.contains_key(table) {
+            parent.insert(table, new.clone());
+            continue;
+        }
+        let (Some(target), Some(new)) = (
+            parent.get_mut(table).and_then(Item::as_table_like_mut),
+            new.as_table_like(),
+        ) else {
+            continue;
+        };
+        for (key, item) in new.iter() {
+            if !target.contains_key(key) {
+                target.insert(key, item.clone());
+            }
+        }
+    }
+
+    Some(merged.to_string())
+}
+
+/// Escape a string for a TOML basic string: backslashes, quotes and control characters
+fn toml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// Register custom Handlebars helpers
 fn register_custom_helpers(handlebars: &mut Handlebars) {
     use handlebars::{Output, RenderErrorReason};
@@ -203,24 +344,74 @@ fn register_custom_helpers(handlebars: &mut Handlebars) {
             },
         ),
     );
-}
 
-/// Convert kebab-case to snake_case
-fn to_snake_case(name: &str) -> String {
-    name.replace('-', "_")
-}
+    // Helper: toml_escape
+    // Escapes a string for use inside a TOML basic ("...") string
+    handlebars.register_helper(
+        "toml_escape",
+        Box::new(
+            |h: &handlebars::Helper<'_>,
+             _r: &handlebars::Handlebars<'_>,
+             _: &handlebars::Context,
+             _rc: &mut handlebars::RenderContext<'_, '_>,
+             out: &mut dyn Output|
+             -> handlebars::HelperResult {
+                let param = h.param(0).ok_or_else(|| {
+                    RenderErrorReason::Other("Missing parameter for toml_escape".into())
+                })?;
+                let value = param
+                    .value()
+                    .as_str()
+                    .ok_or_else(|| RenderErrorReason::Other("Parameter must be a string".into()))?;
+                out.write(&toml_escape(value))?;
+                Ok(())
+            },
+        ),
+    );
 
-/// Convert kebab-case to PascalCase
-fn to_pascal_case(name: &str) -> String {
-    name.split('-')
-        .map(|word| {
-            let mut chars = word.chars();
-            match chars.next() {
-                None => String::new(),
-                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-            }
-        })
-        .collect()
+    // Helper: t
+    // Picks text for the configured locale: {{t "Quick Start" "快速开始"}}
+    // Bilingual output joins both with the `sep` hash argument (default " ")
+    // The `locale` hash argument selects another locale, e.g. locale=comment_locale
+    handlebars.register_helper(
+        "t",
+        Box::new(
+            |h: &handlebars::Helper<'_>,
+             _r: &handlebars::Handlebars<'_>,
+             ctx: &handlebars::Context,
+             _rc: &mut handlebars::RenderContext<'_, '_>,
+             out: &mut dyn Output|
+             -> handlebars::HelperResult {
+                let text = |index: usize| {
+                    h.param(index)
+                        .and_then(|p| p.value().as_str())
+                        .ok_or_else(|| {
+                            RenderErrorReason::Other("t expects English and Chinese strings".into())
+                        })
+                };
+                let (en, zh) = (text(0)?, text(1)?);
+                let sep = h
+                    .hash_get("sep")
+                    .and_then(|v| v.value().as_str())
+                    .unwrap_or(" ");
+
+                let locale = match h.hash_get("locale") {
+                    Some(locale) => locale.value(),
+                    None => &ctx.data()["locale"],
+                };
+                let include_en = locale["en"].as_bool().unwrap_or(true);
+                let include_zh = locale["zh"].as_bool().unwrap_or(true);
+
+                let result = match (include_en, include_zh) {
+                    (true, false) => en.to_string(),
+                    (false, true) => zh.to_string(),
+                    _ => format!("{}{}{}", en, sep, zh),
+                };
+                out.write(&result)?;
+                Ok(())
+            },
+        ),
+    );
 }
 
 #[cfg(test)]
@@ -228,17 +419,48 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_to_snake_case() {
-        assert_eq!(to_snake_case("my-app"), "my_app");
-        assert_eq!(to_snake_case("my_axum_app"), "my_axum_app");
-        assert_eq!(to_snake_case("myapp"), "myapp");
+    fn test_with_partials_inlines_partial() {
+        let partials =
+            HashMap::from([("greeting".to_string(), "hello {{project_name}}".to_string())]);
+        let engine = TemplateEngine::with_partials(&partials).unwrap();
+        let ctx = TemplateContext::from_config(&crate::config::ProjectConfig {
+            project_name: "partial-app".to_string(),
+            ..Default::default()
+        });
+
+        let rendered = engine
+            .render_template("test", "{{> greeting}}!", &ctx)
+            .unwrap();
+        assert_eq!(rendered, "hello partial-app!");
+
+        let broken = HashMap::from([("broken".to_string(), "{{#if}}".to_string())]);
+        assert!(matches!(
+            TemplateEngine::with_partials(&broken),
+            Err(CliError::Template(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge_manifest() {
+        let current =
+            "[workspace]\nmembers = [\"api\"]\n\n[workspace.dependencies]\nserde = \"1\"\n";
+        let generated = "[workspace]\nmembers = [\"api\", \"common\"]\n\n\
+                         [workspace.dependencies]\nserde = \"1\"\ntokio = \"1\"\n\n\
+                         [features]\nauth = []\n";
+
+        let merged = merge_manifest(current, generated).unwrap();
+        assert!(merged.contains("members = [\"api\"]\n"), "{}", merged);
+        assert!(merged.contains("tokio = \"1\""), "{}", merged);
+        assert!(merged.contains("[features]\nauth = []"), "{}", merged);
+
+        assert!(merge_manifest("[dependencies\n", generated).is_none());
     }
 
     #[test]
-    fn test_to_pascal_case() {
-        assert_eq!(to_pascal_case("my-app"), "MyApp");
-        assert_eq!(to_pascal_case("my-axum-app"), "MyAxumApp");
-        assert_eq!(to_pascal_case("myapp"), "Myapp");
+    fn test_toml_escape() {
+        assert_eq!(toml_escape("O'Brien"), "O'Brien");
+        assert_eq!(toml_escape(r#"a "b" \c"#), r#"a \"b\" \\c"#);
+        assert_eq!(toml_escape("a\nb\u{7}"), r"a\nb\u0007");
     }
 
     #[test]
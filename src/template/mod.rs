@@ -2,6 +2,10 @@
 //
 // This module handles template rendering with Handlebars.
 
+pub mod changelog;
 pub mod context;
+pub mod custom_loader;
 pub mod engine;
+pub mod resolver;
 pub mod templates;
+pub mod validate;
@@ -0,0 +1,295 @@
+// Template resolver
+//
+// This module merges built-in templates with user-provided custom templates.
+
+use crate::config::{CiProvider, ProjectMode, WorkspaceLayout};
+use crate::error::{CliError, Result};
+use crate::template::custom_loader::CustomTemplateLoader;
+use crate::template::templates::{
+    get_ci_templates, get_license_templates, get_single_mode_templates,
+    get_workspace_mode_templates,
+};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+/// 模板解析器：合并内置模板与自定义模板 / Template resolver: merges built-in and custom templates
+pub struct TemplateResolver {
+    custom_template_dir: Option<PathBuf>,
+}
+
+/// 解析后的模板 / A resolved template ready for rendering
+#[derive(Debug, Clone)]
+pub struct ResolvedTemplate {
+    /// 输出文件的相对路径 / Relative path in generated project
+    pub path: String,
+    /// 模板内容 / Template content (Handlebars syntax)
+    pub content: String,
+    /// 是否可执行 / Whether the file is executable
+    pub executable: bool,
+}
+
+impl TemplateResolver {
+    /// Create a resolver, optionally layering templates from `custom_template_dir`
+    pub fn new(custom_template_dir: Option<PathBuf>) -> Self {
+        Self {
+            custom_template_dir,
+        }
+    }
+
+    /// 解析最终模板集合 / Resolve the final template set
+    ///
+    /// Built-in templates for `mode` and `layout` (plus CI and license templates if enabled) are loaded
+    /// first. Custom templates then replace built-ins with the same output path,
+    /// and custom templates with new paths are added.
+    pub fn resolve(
+        &self,
+        mode: ProjectMode,
+        layout: WorkspaceLayout,
+        ci: Option<CiProvider>,
+        license: Option<&str>,
+    ) -> Result<HashMap<String, ResolvedTemplate>> {
+        let mut builtin = match mode {
+            ProjectMode::Single => get_single_mode_templates(),
+            ProjectMode::Workspace => get_workspace_mode_templates(layout),
+        };
+
+        if let Some(provider) = ci {
+            builtin.extend(get_ci_templates(provider));
+        }
+        if let Some(license) = license {
+            builtin.extend(get_license_templates(license));
+        }
+
+        let mut resolved: HashMap<String, ResolvedTemplate> = builtin
+            .into_iter()
+            .map(|(name, file)| {
+                (
+                    name.to_string(),
+                    ResolvedTemplate {
+                        path: file.path.to_string(),
+                        content: file.content.to_string(),
+                        executable: file.executable,
+                    },
+                )
+            })
+            .collect();
+
+        if let Some(dir) = &self.custom_template_dir {
+            for (name, content) in CustomTemplateLoader::load(dir)? {
+                validate_output_path(&name)?;
+                let executable = resolved.get(&name).is_some_and(|t| t.executable);
+                resolved.insert(
+                    name.clone(),
+                    ResolvedTemplate {
+                        path: name,
+                        content,
+                        executable,
+                    },
+                );
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// 解析局部模板 / Resolve the Handlebars partials of the custom template dir
+    ///
+    /// Built-in templates use no partials, so this is empty without a custom dir.
+    pub fn partials(&self) -> Result<HashMap<String, String>> {
+        match &self.custom_template_dir {
+            Some(dir) => CustomTemplateLoader::load_partials(dir),
+            None => Ok(HashMap::new()),
+        }
+    }
+}
+
+/// 模板目录环境变量 / Environment variable naming a custom template directory
+pub const TEMPLATE_DIR_ENV: &str = "AXUM_APP_CREATE_TEMPLATE_DIR";
+
+/// 选择自定义模板目录 / Pick the custom template directory from its sources
+///
+/// Precedence: `--template-dir` > `AXUM_APP_CREATE_TEMPLATE_DIR` > `template_dir`
+/// in `~/.axum-app-create.toml` > none (built-in templates only).
+///
+/// # Examples
+/// ```
+/// use axum_app_create::template::resolver::resolve_template_dir;
+/// use std::path::PathBuf;
+///
+/// let env = Some(PathBuf::from("/ci/templates"));
+/// let user = Some(PathBuf::from("/home/me/templates"));
+/// assert_eq!(resolve_template_dir(None, env.clone(), user), env);
+/// ```
+pub fn resolve_template_dir(
+    cli: Option<PathBuf>,
+    env: Option<PathBuf>,
+    user_config: Option<PathBuf>,
+) -> Option<PathBuf> {
+    cli.or(env).or(user_config)
+}
+
+/// 读取环境变量中的模板目录 / `AXUM_APP_CREATE_TEMPLATE_DIR`, ignoring an empty value
+pub fn template_dir_from_env() -> Option<PathBuf> {
+    std::env::var_os(TEMPLATE_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// 读取用户配置中的模板目录 / The `template_dir` key of the user config at `path`
+///
+/// A missing or malformed file yields `None`; `axum-app-create doctor` reports
+/// parse errors.
+pub fn template_dir_from_user_config(path: Option<&Path>) -> Option<PathBuf> {
+    let content = std::fs::read_to_string(path?).ok()?;
+    let table: toml::Table = toml::from_str(&content).ok()?;
+    table
+        .get("template_dir")?
+        .as_str()
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// 校验输出路径位于项目目录内 / Ensure an output path stays inside the project directory
+///
+/// # Errors
+/// * `CliError::Template` naming `path` if it is absolute or contains a `..` component
+pub fn validate_output_path(path: &str) -> Result<()> {
+    let escapes = Path::new(path).components().any(|c| {
+        matches!(
+            c,
+            Component::ParentDir | Component::RootDir | Component::Prefix(_)
+        )
+    });
+
+    if escapes {
+        return Err(CliError::Template(format!(
+            "❌ 输出路径超出项目目录 / Output path escapes the project directory: '{}'\n\n\
+             💡 修复建议 / Fix: 模板路径必须是不含 '..' 的相对路径 / Template paths must be relative and must not contain '..'",
+            path
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_resolve_builtin_only() {
+        let resolver = TemplateResolver::new(None);
+        let templates = resolver
+            .resolve(ProjectMode::Single, WorkspaceLayout::Layered, None, None)
+            .unwrap();
+        assert!(templates.contains_key("Cargo.toml"));
+        assert!(!templates.contains_key(".github/workflows/ci.yml"));
+        assert!(!templates.contains_key("LICENSE"));
+
+        let templates = resolver
+            .resolve(
+                ProjectMode::Single,
+                WorkspaceLayout::Layered,
+                Some(CiProvider::GitHub),
+                None,
+            )
+            .unwrap();
+        assert!(templates.contains_key(".github/workflows/ci.yml"));
+        assert!(!templates.contains_key(".gitlab-ci.yml"));
+
+        let templates = resolver
+            .resolve(
+                ProjectMode::Single,
+                WorkspaceLayout::Layered,
+                Some(CiProvider::GitLab),
+                None,
+            )
+            .unwrap();
+        assert!(templates.contains_key(".gitlab-ci.yml"));
+        assert!(!templates.contains_key(".github/workflows/ci.yml"));
+
+        let templates = resolver
+            .resolve(
+                ProjectMode::Single,
+                WorkspaceLayout::Layered,
+                None,
+                Some("MIT OR Apache-2.0"),
+            )
+            .unwrap();
+        assert!(templates.contains_key("LICENSE-MIT"));
+        assert!(templates.contains_key("LICENSE-APACHE"));
+        assert!(!templates.contains_key("LICENSE"));
+    }
+
+    #[test]
+    fn test_resolve_custom_overrides_and_adds() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("README.md.hbs"), "# custom").unwrap();
+        std::fs::write(temp_dir.path().join("NOTES.md"), "notes").unwrap();
+
+        let resolver = TemplateResolver::new(Some(temp_dir.path().to_path_buf()));
+        let templates = resolver
+            .resolve(ProjectMode::Single, WorkspaceLayout::Layered, None, None)
+            .unwrap();
+
+        assert_eq!(templates["README.md"].content, "# custom");
+        assert_eq!(templates["NOTES.md"].path, "NOTES.md");
+        assert!(templates.contains_key("Cargo.toml"));
+    }
+
+    fn arb_dir() -> impl Strategy<Value = Option<PathBuf>> {
+        proptest::option::of("[a-z]{1,8}".prop_map(PathBuf::from))
+    }
+
+    // CLI > env > user config > none, whichever sources are set
+    proptest! {
+        #[test]
+        fn prop_template_dir_priority(cli in arb_dir(), env in arb_dir(), user in arb_dir()) {
+            let resolved = resolve_template_dir(cli.clone(), env.clone(), user.clone());
+
+            let expected = match (&cli, &env, &user) {
+                (Some(dir), _, _) | (None, Some(dir), _) | (None, None, Some(dir)) => Some(dir.clone()),
+                (None, None, None) => None,
+            };
+            prop_assert_eq!(resolved, expected);
+        }
+    }
+
+    #[test]
+    fn test_template_dir_from_user_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(".axum-app-create.toml");
+
+        assert_eq!(template_dir_from_user_config(None), None);
+        assert_eq!(template_dir_from_user_config(Some(&path)), None);
+
+        std::fs::write(&path, "template_dir = \"/opt/templates\"\n").unwrap();
+        assert_eq!(
+            template_dir_from_user_config(Some(&path)),
+            Some(PathBuf::from("/opt/templates"))
+        );
+
+        std::fs::write(&path, "template_dir = \n").unwrap();
+        assert_eq!(template_dir_from_user_config(Some(&path)), None);
+    }
+
+    #[test]
+    fn test_validate_output_path() {
+        assert!(validate_output_path("src/main.rs").is_ok());
+        assert!(validate_output_path("./README.md").is_ok());
+        assert!(validate_output_path("docs/v1..2.md").is_ok());
+
+        for path in ["../../etc/passwd", "src/../../escape.rs", "/etc/passwd"] {
+            assert!(
+                matches!(
+                    validate_output_path(path),
+                    Err(CliError::Template(msg)) if msg.contains(path)
+                ),
+                "'{}' should be rejected",
+                path
+            );
+        }
+    }
+}
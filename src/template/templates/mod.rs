@@ -5,6 +5,7 @@
 // Templates are embedded at compile time using include_str! macro
 // This allows the CLI tool to work offline after installation
 
+use crate::config::{CiProvider, ProjectMode, WorkspaceLayout};
 use std::collections::HashMap;
 
 /// Template file descriptor
@@ -94,6 +95,16 @@ pub fn get_single_mode_templates() -> HashMap<&'static str, TemplateFile> {
         },
     );
 
+    // .env (with generated secrets, only with --with-env)
+    templates.insert(
+        ".env",
+        TemplateFile {
+            path: ".env",
+            content: include_str!("single_mode/.env.hbs"),
+            executable: false,
+        },
+    );
+
     // .gitignore
     templates.insert(
         ".gitignore",
@@ -104,6 +115,16 @@ pub fn get_single_mode_templates() -> HashMap<&'static str, TemplateFile> {
         },
     );
 
+    // .editorconfig
+    templates.insert(
+        ".editorconfig",
+        TemplateFile {
+            path: ".editorconfig",
+            content: include_str!("single_mode/.editorconfig"),
+            executable: false,
+        },
+    );
+
     // README.md
     templates.insert(
         "README.md",
@@ -124,6 +145,15 @@ pub fn get_single_mode_templates() -> HashMap<&'static str, TemplateFile> {
         },
     );
 
+    templates.insert(
+        "src/models/mod.rs",
+        TemplateFile {
+            path: "src/models/mod.rs",
+            content: include_str!("single_mode/src/models/mod.rs.hbs"),
+            executable: false,
+        },
+    );
+
     templates.insert(
         "migrations/001_initial.sql",
         TemplateFile {
@@ -143,6 +173,56 @@ pub fn get_single_mode_templates() -> HashMap<&'static str, TemplateFile> {
         },
     );
 
+    // Cache templates (conditional based on {{#if has_cache}})
+    templates.insert(
+        "src/cache.rs",
+        TemplateFile {
+            path: "src/cache.rs",
+            content: include_str!("single_mode/src/cache.rs.hbs"),
+            executable: false,
+        },
+    );
+
+    // Server-sent events templates (conditional based on {{#if has_sse}})
+    templates.insert(
+        "src/handlers/events.rs",
+        TemplateFile {
+            path: "src/handlers/events.rs",
+            content: include_str!("single_mode/src/handlers/events.rs.hbs"),
+            executable: false,
+        },
+    );
+
+    // Session templates (conditional based on {{#if has_sessions}})
+    templates.insert(
+        "src/handlers/session.rs",
+        TemplateFile {
+            path: "src/handlers/session.rs",
+            content: include_str!("single_mode/src/handlers/session.rs.hbs"),
+            executable: false,
+        },
+    );
+
+    // File upload templates (conditional based on {{#if has_multipart}})
+    templates.insert(
+        "src/handlers/upload.rs",
+        TemplateFile {
+            path: "src/handlers/upload.rs",
+            content: include_str!("single_mode/src/handlers/upload.rs.hbs"),
+            executable: false,
+        },
+    );
+
+    // API versioning templates (conditional based on {{#if has_api_versions}})
+    templates.insert(
+        "src/versions/mod.rs",
+        TemplateFile {
+            path: "src/versions/mod.rs",
+            content: include_str!("single_mode/src/versions/mod.rs.hbs"),
+            executable: false,
+        },
+    );
+
     // Biz-error feature templates (conditional based on {{#if has_biz_error}})
     templates.insert(
         "biz_errors.yaml",
@@ -163,6 +243,36 @@ pub fn get_single_mode_templates() -> HashMap<&'static str, TemplateFile> {
         },
     );
 
+    // tests/biz_errors.rs (checks biz_errors.yaml against the generated codes)
+    templates.insert(
+        "tests/biz_errors.rs",
+        TemplateFile {
+            path: "tests/biz_errors.rs",
+            content: include_str!("single_mode/tests/biz_errors.rs.hbs"),
+            executable: false,
+        },
+    );
+
+    // .sqlfluff (SQL lint config for migrations)
+    templates.insert(
+        ".sqlfluff",
+        TemplateFile {
+            path: ".sqlfluff",
+            content: include_str!("single_mode/.sqlfluff.hbs"),
+            executable: false,
+        },
+    );
+
+    // tests/db_integration.rs (PostgreSQL via testcontainers)
+    templates.insert(
+        "tests/db_integration.rs",
+        TemplateFile {
+            path: "tests/db_integration.rs",
+            content: include_str!("single_mode/tests/db_integration.rs.hbs"),
+            executable: false,
+        },
+    );
+
     // Dockerfile
     templates.insert(
         "Dockerfile",
@@ -173,6 +283,56 @@ pub fn get_single_mode_templates() -> HashMap<&'static str, TemplateFile> {
         },
     );
 
+    // docker-entrypoint.sh (waits for PostgreSQL before starting the server)
+    templates.insert(
+        "docker-entrypoint.sh",
+        TemplateFile {
+            path: "docker-entrypoint.sh",
+            content: include_str!("single_mode/docker-entrypoint.sh.hbs"),
+            executable: true,
+        },
+    );
+
+    // scripts/dev.sh (local run loop)
+    templates.insert(
+        "scripts/dev.sh",
+        TemplateFile {
+            path: "scripts/dev.sh",
+            content: include_str!("single_mode/scripts/dev.sh.hbs"),
+            executable: true,
+        },
+    );
+
+    // Procfile (--procfile)
+    templates.insert(
+        "Procfile",
+        TemplateFile {
+            path: "Procfile",
+            content: include_str!("single_mode/Procfile.hbs"),
+            executable: false,
+        },
+    );
+
+    // CODEOWNERS (--codeowners)
+    templates.insert(
+        ".github/CODEOWNERS",
+        TemplateFile {
+            path: ".github/CODEOWNERS",
+            content: include_str!("single_mode/.github/CODEOWNERS.hbs"),
+            executable: false,
+        },
+    );
+
+    // docker-compose.yml (database-backed projects only)
+    templates.insert(
+        "docker-compose.yml",
+        TemplateFile {
+            path: "docker-compose.yml",
+            content: include_str!("single_mode/docker-compose.yml.hbs"),
+            executable: false,
+        },
+    );
+
     // .dockerignore
     templates.insert(
         ".dockerignore",
@@ -188,8 +348,11 @@ pub fn get_single_mode_templates() -> HashMap<&'static str, TemplateFile> {
 
 /// Get all templates for workspace mode (multi-crate)
 ///
-/// Returns a map of template paths to their contents
-pub fn get_workspace_mode_templates() -> HashMap<&'static str, TemplateFile> {
+/// Returns a map of template paths to their contents. The `api` crate and
+/// root files are shared; `layout` picks the library crates next to them.
+pub fn get_workspace_mode_templates(
+    layout: WorkspaceLayout,
+) -> HashMap<&'static str, TemplateFile> {
     let mut templates = HashMap::new();
 
     // Root files
@@ -209,6 +372,14 @@ pub fn get_workspace_mode_templates() -> HashMap<&'static str, TemplateFile> {
             executable: false,
         },
     );
+    templates.insert(
+        ".env",
+        TemplateFile {
+            path: ".env",
+            content: include_str!("workspace_mode/root/.env.hbs"),
+            executable: false,
+        },
+    );
     templates.insert(
         ".gitignore",
         TemplateFile {
@@ -217,6 +388,14 @@ pub fn get_workspace_mode_templates() -> HashMap<&'static str, TemplateFile> {
             executable: false,
         },
     );
+    templates.insert(
+        ".editorconfig",
+        TemplateFile {
+            path: ".editorconfig",
+            content: include_str!("workspace_mode/root/.editorconfig"),
+            executable: false,
+        },
+    );
     templates.insert(
         ".dockerignore",
         TemplateFile {
@@ -241,6 +420,48 @@ pub fn get_workspace_mode_templates() -> HashMap<&'static str, TemplateFile> {
             executable: false,
         },
     );
+    templates.insert(
+        "docker-entrypoint.sh",
+        TemplateFile {
+            path: "docker-entrypoint.sh",
+            content: include_str!("workspace_mode/root/docker-entrypoint.sh.hbs"),
+            executable: true,
+        },
+    );
+
+    // scripts/dev.sh (local run loop)
+    templates.insert(
+        "scripts/dev.sh",
+        TemplateFile {
+            path: "scripts/dev.sh",
+            content: include_str!("single_mode/scripts/dev.sh.hbs"),
+            executable: true,
+        },
+    );
+    templates.insert(
+        "Procfile",
+        TemplateFile {
+            path: "Procfile",
+            content: include_str!("workspace_mode/root/Procfile.hbs"),
+            executable: false,
+        },
+    );
+    templates.insert(
+        ".github/CODEOWNERS",
+        TemplateFile {
+            path: ".github/CODEOWNERS",
+            content: include_str!("workspace_mode/root/.github/CODEOWNERS.hbs"),
+            executable: false,
+        },
+    );
+    templates.insert(
+        "docker-compose.yml",
+        TemplateFile {
+            path: "docker-compose.yml",
+            content: include_str!("workspace_mode/root/docker-compose.yml.hbs"),
+            executable: false,
+        },
+    );
 
     // api crate
     templates.insert(
@@ -291,6 +512,41 @@ pub fn get_workspace_mode_templates() -> HashMap<&'static str, TemplateFile> {
             executable: false,
         },
     );
+    templates.insert(
+        "api/src/handlers/events.rs",
+        TemplateFile {
+            path: "api/src/handlers/events.rs",
+            content: include_str!("workspace_mode/api/src/handlers/events.rs.hbs"),
+            executable: false,
+        },
+    );
+    templates.insert(
+        "api/src/handlers/session.rs",
+        TemplateFile {
+            path: "api/src/handlers/session.rs",
+            content: include_str!("workspace_mode/api/src/handlers/session.rs.hbs"),
+            executable: false,
+        },
+    );
+    templates.insert(
+        "api/src/handlers/upload.rs",
+        TemplateFile {
+            path: "api/src/handlers/upload.rs",
+            content: include_str!("workspace_mode/api/src/handlers/upload.rs.hbs"),
+            executable: false,
+        },
+    );
+
+    // API versioning templates (conditional based on {{#if has_api_versions}})
+    templates.insert(
+        "api/src/versions/mod.rs",
+        TemplateFile {
+            path: "api/src/versions/mod.rs",
+            content: include_str!("single_mode/src/versions/mod.rs.hbs"),
+            executable: false,
+        },
+    );
+
     templates.insert(
         "api/src/handlers/auth.rs",
         TemplateFile {
@@ -308,6 +564,18 @@ pub fn get_workspace_mode_templates() -> HashMap<&'static str, TemplateFile> {
         },
     );
 
+    templates.extend(match layout {
+        WorkspaceLayout::Layered => layered_crate_templates(),
+        WorkspaceLayout::Simple => simple_crate_templates(),
+    });
+
+    templates
+}
+
+/// Library crates of the layered workspace: `domain`, `infrastructure` and `common`
+fn layered_crate_templates() -> HashMap<&'static str, TemplateFile> {
+    let mut templates = HashMap::new();
+
     // domain crate
     templates.insert(
         "domain/Cargo.toml",
@@ -367,6 +635,14 @@ pub fn get_workspace_mode_templates() -> HashMap<&'static str, TemplateFile> {
             executable: false,
         },
     );
+    templates.insert(
+        "infrastructure/src/cache.rs",
+        TemplateFile {
+            path: "infrastructure/src/cache.rs",
+            content: include_str!("workspace_mode/infrastructure/src/cache.rs.hbs"),
+            executable: false,
+        },
+    );
 
     // common crate
     templates.insert(
@@ -397,17 +673,260 @@ pub fn get_workspace_mode_templates() -> HashMap<&'static str, TemplateFile> {
     templates
 }
 
+/// Library crate of the simple workspace: `core`
+fn simple_crate_templates() -> HashMap<&'static str, TemplateFile> {
+    let mut templates = HashMap::new();
+
+    // core crate: domain, infrastructure and common in one library
+    templates.insert(
+        "core/Cargo.toml",
+        TemplateFile {
+            path: "core/Cargo.toml",
+            content: include_str!("workspace_mode/core/Cargo.toml.hbs"),
+            executable: false,
+        },
+    );
+    templates.insert(
+        "core/src/lib.rs",
+        TemplateFile {
+            path: "core/src/lib.rs",
+            content: include_str!("workspace_mode/core/src/lib.rs.hbs"),
+            executable: false,
+        },
+    );
+    templates.insert(
+        "core/src/entities/mod.rs",
+        TemplateFile {
+            path: "core/src/entities/mod.rs",
+            content: include_str!("workspace_mode/domain/src/entities/mod.rs.hbs"),
+            executable: false,
+        },
+    );
+    templates.insert(
+        "core/src/traits/mod.rs",
+        TemplateFile {
+            path: "core/src/traits/mod.rs",
+            content: include_str!("workspace_mode/domain/src/traits/mod.rs.hbs"),
+            executable: false,
+        },
+    );
+    templates.insert(
+        "core/src/db.rs",
+        TemplateFile {
+            path: "core/src/db.rs",
+            content: include_str!("workspace_mode/infrastructure/src/db.rs.hbs"),
+            executable: false,
+        },
+    );
+    templates.insert(
+        "core/src/cache.rs",
+        TemplateFile {
+            path: "core/src/cache.rs",
+            content: include_str!("workspace_mode/infrastructure/src/cache.rs.hbs"),
+            executable: false,
+        },
+    );
+    templates.insert(
+        "core/src/error.rs",
+        TemplateFile {
+            path: "core/src/error.rs",
+            content: include_str!("workspace_mode/common/src/error.rs.hbs"),
+            executable: false,
+        },
+    );
+
+    templates
+}
+
 /// Get CI/CD templates
 ///
-/// Returns CI workflow templates that can be appended to any mode's template set
-pub fn get_ci_templates() -> HashMap<&'static str, TemplateFile> {
+/// Returns the pipeline for `provider` (plus governance files) that can be
+/// appended to any mode's template set
+pub fn get_ci_templates(provider: CiProvider) -> HashMap<&'static str, TemplateFile> {
     let mut templates = HashMap::new();
 
+    match provider {
+        CiProvider::GitHub => {
+            templates.insert(
+                ".github/workflows/ci.yml",
+                TemplateFile {
+                    path: ".github/workflows/ci.yml",
+                    content: include_str!("ci/.github/workflows/ci.yml.hbs"),
+                    executable: false,
+                },
+            );
+        }
+        CiProvider::GitLab => {
+            templates.insert(
+                ".gitlab-ci.yml",
+                TemplateFile {
+                    path: ".gitlab-ci.yml",
+                    content: include_str!("ci/.gitlab-ci.yml.hbs"),
+                    executable: false,
+                },
+            );
+        }
+    }
+
+    // Minimal disclosure policy; CODEOWNERS is also rendered when CI is enabled
     templates.insert(
-        ".github/workflows/ci.yml",
+        "SECURITY.md",
         TemplateFile {
-            path: ".github/workflows/ci.yml",
-            content: include_str!("ci/.github/workflows/ci.yml.hbs"),
+            path: "SECURITY.md",
+            content: include_str!("ci/SECURITY.md.hbs"),
+            executable: false,
+        },
+    );
+
+    templates
+}
+
+/// Get the license text templates for an SPDX identifier from `--license`
+///
+/// A single license is written to `LICENSE`; `MIT OR Apache-2.0` follows the
+/// Rust convention of `LICENSE-MIT` plus `LICENSE-APACHE`. Unknown identifiers
+/// yield no templates.
+pub fn get_license_templates(license: &str) -> HashMap<&'static str, TemplateFile> {
+    const MIT: &str = include_str!("licenses/MIT.hbs");
+    const APACHE: &str = include_str!("licenses/Apache-2.0.hbs");
+
+    let files: &[(&'static str, &'static str)] = match license {
+        "MIT" => &[("LICENSE", MIT)],
+        "Apache-2.0" => &[("LICENSE", APACHE)],
+        "MIT OR Apache-2.0" => &[("LICENSE-MIT", MIT), ("LICENSE-APACHE", APACHE)],
+        "BSD-3-Clause" => &[("LICENSE", include_str!("licenses/BSD-3-Clause.hbs"))],
+        "ISC" => &[("LICENSE", include_str!("licenses/ISC.hbs"))],
+        _ => &[],
+    };
+
+    files
+        .iter()
+        .map(|&(path, content)| {
+            (
+                path,
+                TemplateFile {
+                    path,
+                    content,
+                    executable: false,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Get the template `new-handler` scaffolds into an existing project
+///
+/// The path is a Handlebars template rendered with the handler context
+/// (e.g. `src/handlers/{{handler.name}}.rs`).
+pub fn get_handler_template(mode: ProjectMode) -> TemplateFile {
+    let path = match mode {
+        ProjectMode::Single => "src/handlers/{{handler.name}}.rs",
+        ProjectMode::Workspace => "api/src/handlers/{{handler.name}}.rs",
+    };
+
+    TemplateFile {
+        path,
+        content: include_str!("handler.rs.hbs"),
+        executable: false,
+    }
+}
+
+/// Get per-entity templates for `--entities`
+///
+/// Each template is rendered once per entity. Paths are Handlebars templates
+/// themselves, rendered with the same context (e.g. `src/models/{{entity.snake}}.rs`).
+/// In a workspace, `layout` picks the crate the migration and model go to.
+pub fn get_entity_templates(
+    mode: ProjectMode,
+    layout: WorkspaceLayout,
+) -> HashMap<&'static str, TemplateFile> {
+    let mut templates = HashMap::new();
+
+    match (mode, layout) {
+        (ProjectMode::Single, _) => {
+            templates.insert(
+                "migrations/create_entity.sql",
+                TemplateFile {
+                    path: "migrations/{{entity.migration_version}}_create_{{entity.table}}.sql",
+                    content: include_str!("single_mode/migrations/create_entity.sql.hbs"),
+                    executable: false,
+                },
+            );
+
+            templates.insert(
+                "src/models/entity.rs",
+                TemplateFile {
+                    path: "src/models/{{entity.snake}}.rs",
+                    content: include_str!("single_mode/src/models/entity.rs.hbs"),
+                    executable: false,
+                },
+            );
+        }
+        (ProjectMode::Workspace, WorkspaceLayout::Layered) => {
+            templates.insert(
+                "infrastructure/migrations/create_entity.sql",
+                TemplateFile {
+                    path: "infrastructure/migrations/{{entity.migration_version}}_create_{{entity.table}}.sql",
+                    content: include_str!("single_mode/migrations/create_entity.sql.hbs"),
+                    executable: false,
+                },
+            );
+
+            templates.insert(
+                "domain/src/entities/entity.rs",
+                TemplateFile {
+                    path: "domain/src/entities/{{entity.snake}}.rs",
+                    content: include_str!("workspace_mode/domain/src/entities/entity.rs.hbs"),
+                    executable: false,
+                },
+            );
+        }
+        (ProjectMode::Workspace, WorkspaceLayout::Simple) => {
+            templates.insert(
+                "core/migrations/create_entity.sql",
+                TemplateFile {
+                    path: "core/migrations/{{entity.migration_version}}_create_{{entity.table}}.sql",
+                    content: include_str!("single_mode/migrations/create_entity.sql.hbs"),
+                    executable: false,
+                },
+            );
+
+            templates.insert(
+                "core/src/entities/entity.rs",
+                TemplateFile {
+                    path: "core/src/entities/{{entity.snake}}.rs",
+                    content: include_str!("workspace_mode/domain/src/entities/entity.rs.hbs"),
+                    executable: false,
+                },
+            );
+        }
+    }
+
+    templates
+}
+
+/// Get per-version templates for `--api-versions`
+///
+/// Each template is rendered once per version with `api_version` set, like
+/// [`get_entity_templates`].
+pub fn get_api_version_templates(mode: ProjectMode) -> HashMap<&'static str, TemplateFile> {
+    let mut templates = HashMap::new();
+
+    let (name, path) = match mode {
+        ProjectMode::Single => (
+            "src/versions/version.rs",
+            "src/versions/{{api_version.name}}.rs",
+        ),
+        ProjectMode::Workspace => (
+            "api/src/versions/version.rs",
+            "api/src/versions/{{api_version.name}}.rs",
+        ),
+    };
+    templates.insert(
+        name,
+        TemplateFile {
+            path,
+            content: include_str!("single_mode/src/versions/version.rs.hbs"),
             executable: false,
         },
     );
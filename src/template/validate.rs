@@ -0,0 +1,113 @@
+// Custom template validation
+//
+// This module checks a custom template directory before it is used for
+// generation, reporting every broken file instead of stopping at the first.
+
+use crate::config::ProjectConfig;
+use crate::error::Result;
+use crate::template::context::TemplateContext;
+use crate::template::custom_loader::{CustomTemplateLoader, PARTIALS_DIR};
+use crate::template::engine::TemplateEngine;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// 模板检查结果 / Result of checking one custom template or partial
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateCheck {
+    /// 相对路径 / Template key, or `partials/<name>` for a partial
+    pub path: String,
+    /// 错误信息 / Rendering error, `None` if the file is valid
+    pub error: Option<String>,
+}
+
+/// 校验自定义模板目录 / Render every template in `dir` against a default context
+///
+/// Partials are registered one by one so a broken partial is reported on its
+/// own; templates are then rendered with the valid partials available.
+/// Results are sorted by path.
+///
+/// # Errors
+/// * `CliError::Config` if `dir` does not exist or is not a directory
+/// * `CliError::Template` if two templates map to the same output file
+pub fn validate_template_dir(dir: &Path) -> Result<Vec<TemplateCheck>> {
+    let templates = CustomTemplateLoader::load(dir)?;
+    let partials = CustomTemplateLoader::load_partials(dir)?;
+
+    let mut checks = Vec::new();
+    let mut valid_partials = HashMap::new();
+    for (name, content) in partials {
+        let single = HashMap::from([(name.clone(), content.clone())]);
+        let error = TemplateEngine::with_partials(&single)
+            .err()
+            .map(|e| e.to_string());
+        if error.is_none() {
+            valid_partials.insert(name.clone(), content);
+        }
+        checks.push(TemplateCheck {
+            path: format!("{}/{}", PARTIALS_DIR, name),
+            error,
+        });
+    }
+
+    let engine = TemplateEngine::with_partials(&valid_partials)?;
+    let ctx = TemplateContext::from_config(&ProjectConfig::default());
+    for (name, content) in templates {
+        let error = engine
+            .render_template(&name, &content, &ctx)
+            .err()
+            .map(|e| e.to_string());
+        checks.push(TemplateCheck { path: name, error });
+    }
+
+    checks.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_validate_reports_broken_template() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("README.md.hbs"), "# {{project_name}}").unwrap();
+        std::fs::write(temp_dir.path().join("NOTES.md.hbs"), "{{#if has_auth}}").unwrap();
+
+        let checks = validate_template_dir(temp_dir.path()).unwrap();
+
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].path, "NOTES.md");
+        assert!(
+            checks[0]
+                .error
+                .as_deref()
+                .is_some_and(|e| e.contains("Template name: NOTES.md"))
+        );
+        assert_eq!(
+            checks[1],
+            TemplateCheck {
+                path: "README.md".to_string(),
+                error: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_validate_checks_partials() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("partials")).unwrap();
+        std::fs::write(temp_dir.path().join("partials/header.hbs"), "// header").unwrap();
+        std::fs::write(temp_dir.path().join("partials/broken.hbs"), "{{#each}}").unwrap();
+        std::fs::write(temp_dir.path().join("main.rs.hbs"), "{{> header}}").unwrap();
+
+        let checks = validate_template_dir(temp_dir.path()).unwrap();
+
+        let failed: Vec<_> = checks
+            .iter()
+            .filter(|c| c.error.is_some())
+            .map(|c| c.path.as_str())
+            .collect();
+        assert_eq!(failed, vec!["partials/broken"]);
+    }
+}
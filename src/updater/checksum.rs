@@ -0,0 +1,61 @@
+// Checksum calculation
+//
+// This module computes SHA-256 checksums of generated files.
+
+use crate::error::Result;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// SHA-256 校验和计算 / SHA-256 checksum calculation
+pub struct ChecksumCalculator;
+
+impl ChecksumCalculator {
+    /// 计算内容的 SHA-256 校验和 / Calculate the hex-encoded SHA-256 checksum of `content`
+    pub fn calculate(content: &[u8]) -> String {
+        Sha256::digest(content)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    /// 计算目录中指定文件的校验和 / Calculate checksums for `files` relative to `project_dir`
+    ///
+    /// Files that no longer exist are skipped.
+    pub fn calculate_all(project_dir: &Path, files: &[String]) -> Result<HashMap<String, String>> {
+        let mut checksums = HashMap::new();
+        for file in files {
+            let path = project_dir.join(file);
+            if path.is_file() {
+                checksums.insert(file.clone(), Self::calculate(&std::fs::read(path)?));
+            }
+        }
+        Ok(checksums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_calculate_known_digest() {
+        assert_eq!(
+            ChecksumCalculator::calculate(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_calculate_all_skips_missing_files() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("a.txt"), "abc").unwrap();
+
+        let files = vec!["a.txt".to_string(), "missing.txt".to_string()];
+        let checksums = ChecksumCalculator::calculate_all(temp_dir.path(), &files).unwrap();
+
+        assert_eq!(checksums.len(), 1);
+        assert_eq!(checksums["a.txt"], ChecksumCalculator::calculate(b"abc"));
+    }
+}
@@ -0,0 +1,151 @@
+// Handler scaffolding
+//
+// This module backs the `new-handler` subcommand: it adds a handler module to
+// an existing project, registers it in the handlers module and mounts its
+// router in the binary's `main.rs`.
+
+use crate::config::ProjectMode;
+use crate::error::{CliError, Result};
+use crate::template::context::{HandlerContext, TemplateContext};
+use crate::template::engine::TemplateEngine;
+use crate::template::templates::get_handler_template;
+use crate::updater::metadata::MetadataManager;
+use crate::utils::validator::validate_handler_name;
+use std::path::Path;
+
+/// 新增处理器报告 / Files touched by [`new_handler`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewHandlerReport {
+    /// 新建的处理器文件 / Created handler file, relative to the project root
+    pub created: String,
+    /// 修改的文件 / Files the handler was registered in, relative to the project root
+    pub updated: Vec<String>,
+}
+
+/// 新增处理器 / Scaffold a handler named `name` into a generated project
+///
+/// The project mode is read from its generation metadata. The handler is
+/// written to `src/handlers/<name>.rs` (or `api/src/handlers/<name>.rs` in a
+/// workspace), declared in the handlers module, and its `router()` is merged
+/// next to the health check router in `main.rs`.
+///
+/// Nothing is written unless every file can be updated.
+///
+/// # Errors
+/// * `CliError::ValidationError` if `name` is not a snake_case identifier
+/// * `CliError::Config` if the project has no generation metadata
+/// * `CliError::Generation` if the handler already exists, or `main.rs` no
+///   longer merges the health check router
+pub fn new_handler(project_dir: &Path, name: &str) -> Result<NewHandlerReport> {
+    validate_handler_name(name).map_err(CliError::ValidationError)?;
+
+    let metadata = MetadataManager::read(project_dir)?;
+    let mode = metadata.config.mode;
+    let handler = HandlerContext {
+        name: name.to_string(),
+        path: format!("/{}", name),
+    };
+    let ctx = TemplateContext::from_config(&metadata.config).with_handler(&handler);
+    let engine = TemplateEngine::new();
+    let template = get_handler_template(mode);
+    let handler_file = engine.render_template("handler", template.path, &ctx)?;
+
+    if project_dir.join(&handler_file).exists() {
+        return Err(CliError::Generation(format!(
+            "❌ 处理器已存在 / Handler already exists: {}\n\n\
+             💡 修复建议 / Fix: 换一个名称或删除现有文件 / Choose another name or remove the existing file",
+            handler_file
+        )));
+    }
+
+    let (mod_file, main_file) = match mode {
+        ProjectMode::Single => ("src/handlers/mod.rs", "src/main.rs"),
+        ProjectMode::Workspace => ("api/src/handlers/mod.rs", "api/src/main.rs"),
+    };
+    let main = std::fs::read_to_string(project_dir.join(main_file))?;
+    let main = mount_router(&main, name, mode).ok_or_else(|| {
+        CliError::Generation(format!(
+            "❌ 找不到健康检查路由 / Could not find the health check router in {}\n\n\
+             💡 修复建议 / Fix: 手动挂载 / Mount the handler by hand with `.merge({}::router())`",
+            main_file, name
+        ))
+    })?;
+    let mut handlers = std::fs::read_to_string(project_dir.join(mod_file))?;
+    if !handlers.is_empty() && !handlers.ends_with('\n') {
+        handlers.push('\n');
+    }
+    handlers.push_str(&format!("\n// {0} handler\npub mod {0};\n", name));
+
+    let content = engine.render_template("handler", template.content, &ctx)?;
+    std::fs::write(project_dir.join(&handler_file), content)?;
+    std::fs::write(project_dir.join(mod_file), handlers)?;
+    std::fs::write(project_dir.join(main_file), main)?;
+
+    Ok(NewHandlerReport {
+        created: handler_file,
+        updated: vec![mod_file.to_string(), main_file.to_string()],
+    })
+}
+
+/// Merge the `name` router after the health check router in `main`
+///
+/// Single-mode binaries include each handler with a `#[path]` module, so the
+/// module is declared after `mod health;` as well. Returns `None` if either
+/// anchor is missing.
+fn mount_router(main: &str, name: &str, mode: ProjectMode) -> Option<String> {
+    let (merge_anchor, merge) = match mode {
+        ProjectMode::Single => (
+            ".merge(health::router())",
+            format!(".merge({}::router())", name),
+        ),
+        ProjectMode::Workspace => (
+            ".merge(handlers::health::router())",
+            format!(".merge(handlers::{}::router())", name),
+        ),
+    };
+
+    let mut lines: Vec<String> = main.lines().map(str::to_string).collect();
+    let merge_at = lines.iter().position(|line| line.trim() == merge_anchor)?;
+    let indent: String = lines[merge_at]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+    lines.insert(merge_at + 1, format!("{}{}", indent, merge));
+
+    if mode == ProjectMode::Single {
+        let mod_at = lines.iter().position(|line| line.trim() == "mod health;")?;
+        lines.insert(mod_at + 1, format!("#[path = \"handlers/{}.rs\"]", name));
+        lines.insert(mod_at + 2, format!("mod {};", name));
+    }
+
+    let mut mounted = lines.join("\n");
+    if main.ends_with('\n') {
+        mounted.push('\n');
+    }
+    Some(mounted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mount_router_single() {
+        let main = "#[path = \"handlers/health.rs\"]\nmod health;\n\n\
+                    fn main() {\n    let app = Router::new()\n        .merge(health::router())\n        .layer(trace);\n}\n";
+        let mounted = mount_router(main, "orders", ProjectMode::Single).unwrap();
+
+        assert!(mounted.contains("mod health;\n#[path = \"handlers/orders.rs\"]\nmod orders;\n"));
+        assert!(
+            mounted
+                .contains("        .merge(health::router())\n        .merge(orders::router())\n")
+        );
+        assert!(mounted.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_mount_router_missing_anchor() {
+        let main = "mod handlers;\n\nfn main() {}\n";
+        assert_eq!(mount_router(main, "orders", ProjectMode::Workspace), None);
+    }
+}
@@ -0,0 +1,313 @@
+// Generation metadata
+//
+// This module reads and writes `.axum-app-create.json`, which records the
+// configuration a project was generated with, checksums of its files and
+// snapshots of their generated content.
+
+use crate::config::{Locale, ProjectConfig};
+use crate::error::{CliError, Result};
+use crate::template::changelog::parse_version;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// 元数据文件名 / Metadata file name, relative to the project root
+pub const METADATA_FILE: &str = ".axum-app-create.json";
+
+/// 元数据格式版本 / Schema version of the metadata file
+///
+/// Bump the major version when a change would make older files unreadable,
+/// and teach [`MetadataManager::migrate`] how to upgrade them.
+pub const CURRENT_METADATA_VERSION: &str = "1.1";
+
+/// Schema version assumed for files written before the version field existed
+const LEGACY_METADATA_VERSION: &str = "0.1";
+
+/// 生成元数据管理 / Generation metadata management
+pub struct MetadataManager;
+
+/// 生成元数据结构 / Generation metadata structure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerationMetadata {
+    /// 元数据格式版本 / Schema version of this file
+    pub metadata_version: String,
+    /// 生成器版本 / Version of axum-app-create that generated the project
+    pub version: String,
+    /// 生成时间 / Generation time (RFC 3339, UTC)
+    pub generated_at: String,
+    /// 生成配置 / Configuration used for generation
+    pub config: ProjectConfig,
+    /// 文件校验和 / SHA-256 checksum of each generated file
+    pub file_checksums: HashMap<String, String>,
+    /// 文件快照 / Content each file was generated with, the base for three-way merges
+    ///
+    /// Missing from metadata written before schema 1.1.
+    #[serde(default)]
+    pub file_snapshots: HashMap<String, String>,
+}
+
+impl GenerationMetadata {
+    /// 版本说明 / Tool versions involved in an update (e.g. `generated by v0.3.0, updating with v0.4.0`)
+    pub fn version_summary(&self, locale: Locale) -> String {
+        locale.text(
+            &format!(
+                "generated by v{}, updating with v{}",
+                self.version,
+                env!("CARGO_PKG_VERSION")
+            ),
+            &format!(
+                "由 v{} 生成，使用 v{} 更新",
+                self.version,
+                env!("CARGO_PKG_VERSION")
+            ),
+        )
+    }
+
+    /// Whether the running tool is older than the one that generated the project
+    ///
+    /// Versions that cannot be parsed are treated as compatible.
+    pub fn generated_by_newer_tool(&self) -> bool {
+        is_older(env!("CARGO_PKG_VERSION"), &self.version)
+    }
+}
+
+impl MetadataManager {
+    /// 创建元数据 / Create metadata for a newly generated project
+    pub fn create(
+        project_dir: &Path,
+        config: &ProjectConfig,
+        file_checksums: HashMap<String, String>,
+        file_snapshots: HashMap<String, String>,
+    ) -> Result<()> {
+        let metadata = GenerationMetadata {
+            metadata_version: CURRENT_METADATA_VERSION.to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            generated_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            config: config.clone(),
+            file_checksums,
+            file_snapshots,
+        };
+        Self::write(project_dir, &metadata)
+    }
+
+    /// 读取元数据 / Read metadata from an existing project
+    ///
+    /// # Errors
+    /// * `CliError::Config` if the metadata file is missing or malformed
+    /// * `CliError::Generation` if the file uses an incompatible schema version
+    pub fn read(project_dir: &Path) -> Result<GenerationMetadata> {
+        let path = project_dir.join(METADATA_FILE);
+        let value = Self::read_value(&path)?;
+
+        let version = schema_version(&value);
+        if major(version) != major(CURRENT_METADATA_VERSION) {
+            return Err(CliError::Generation(format!(
+                "❌ 生成元数据版本不兼容 / Incompatible generation metadata '{}': schema {} (expected {})\n\n\
+                 💡 修复建议 / Fix: 请重新生成项目或迁移元数据 / Please regenerate the project or migrate its metadata",
+                path.display(),
+                version,
+                CURRENT_METADATA_VERSION
+            )));
+        }
+
+        serde_json::from_value(value).map_err(|e| malformed(&path, e))
+    }
+
+    /// 迁移元数据 / Upgrade metadata written with an older schema in place
+    ///
+    /// Returns `true` if the file was rewritten, `false` if it was already current.
+    ///
+    /// # Errors
+    /// * `CliError::Config` if the metadata file is missing or malformed
+    /// * `CliError::Generation` if the schema version is unknown
+    pub fn migrate(project_dir: &Path) -> Result<bool> {
+        let path = project_dir.join(METADATA_FILE);
+        let mut value = Self::read_value(&path)?;
+
+        let version = schema_version(&value).to_string();
+        if major(&version) == major(CURRENT_METADATA_VERSION) {
+            return Ok(false);
+        }
+
+        match major(&version) {
+            // 0.x: identical layout, written before `metadata_version` existed
+            "0" => {
+                value["metadata_version"] = CURRENT_METADATA_VERSION.into();
+            }
+            _ => {
+                return Err(CliError::Generation(format!(
+                    "❌ 未知的元数据版本 / Unknown generation metadata schema {} in '{}'\n\n\
+                     💡 修复建议 / Fix: 请重新生成项目 / Please regenerate the project",
+                    version,
+                    path.display()
+                )));
+            }
+        }
+
+        let metadata: GenerationMetadata =
+            serde_json::from_value(value).map_err(|e| malformed(&path, e))?;
+        Self::write(project_dir, &metadata)?;
+        Ok(true)
+    }
+
+    /// 更新元数据 / Replace the file checksums and snapshots after an update operation
+    pub fn update(
+        project_dir: &Path,
+        file_checksums: HashMap<String, String>,
+        file_snapshots: HashMap<String, String>,
+    ) -> Result<()> {
+        let mut metadata = Self::read(project_dir)?;
+        metadata.file_checksums = file_checksums;
+        metadata.file_snapshots = file_snapshots;
+        Self::write(project_dir, &metadata)
+    }
+
+    fn read_value(path: &Path) -> Result<serde_json::Value> {
+        let content = std::fs::read_to_string(path).map_err(|_| {
+            CliError::Config(format!(
+                "❌ 未找到生成元数据 / Generation metadata not found: '{}'\n\n\
+                 💡 修复建议 / Fix: 在 axum-app-create 生成的项目根目录中运行 / Run inside the root of a project generated by axum-app-create",
+                path.display()
+            ))
+        })?;
+        serde_json::from_str(&content).map_err(|e| malformed(path, e))
+    }
+
+    fn write(project_dir: &Path, metadata: &GenerationMetadata) -> Result<()> {
+        let json = serde_json::to_string_pretty(metadata).map_err(|e| {
+            CliError::Generation(format!("Failed to serialize generation metadata: {}", e))
+        })?;
+        std::fs::write(project_dir.join(METADATA_FILE), json + "\n")?;
+        Ok(())
+    }
+}
+
+/// Schema version recorded in a metadata document
+fn schema_version(value: &serde_json::Value) -> &str {
+    value
+        .get("metadata_version")
+        .and_then(|v| v.as_str())
+        .unwrap_or(LEGACY_METADATA_VERSION)
+}
+
+/// Whether `current` is an older `major.minor.patch` release than `generated`
+fn is_older(current: &str, generated: &str) -> bool {
+    matches!(
+        (parse_version(current), parse_version(generated)),
+        (Some(current), Some(generated)) if current < generated
+    )
+}
+
+/// Major component of a `major.minor` version string
+fn major(version: &str) -> &str {
+    version.split('.').next().unwrap_or(version)
+}
+
+fn malformed(path: &Path, e: serde_json::Error) -> CliError {
+    CliError::Config(format!(
+        "❌ 生成元数据格式错误 / Malformed generation metadata '{}': {}",
+        path.display(),
+        e
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_metadata_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = ProjectConfig {
+            project_name: "meta-app".to_string(),
+            ..Default::default()
+        };
+        let checksums = HashMap::from([("Cargo.toml".to_string(), "abc".to_string())]);
+        let snapshots = HashMap::from([("Cargo.toml".to_string(), "[package]\n".to_string())]);
+
+        MetadataManager::create(
+            temp_dir.path(),
+            &config,
+            checksums.clone(),
+            snapshots.clone(),
+        )
+        .unwrap();
+        let metadata = MetadataManager::read(temp_dir.path()).unwrap();
+
+        assert_eq!(metadata.metadata_version, CURRENT_METADATA_VERSION);
+        assert_eq!(metadata.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(metadata.config.project_name, "meta-app");
+        assert_eq!(metadata.file_checksums, checksums);
+        assert_eq!(metadata.file_snapshots, snapshots);
+
+        let updated = HashMap::from([("src/main.rs".to_string(), "def".to_string())]);
+        MetadataManager::update(temp_dir.path(), updated.clone(), HashMap::new()).unwrap();
+        let metadata = MetadataManager::read(temp_dir.path()).unwrap();
+        assert_eq!(metadata.file_checksums, updated);
+        assert!(metadata.file_snapshots.is_empty());
+    }
+
+    #[test]
+    fn test_is_older() {
+        assert!(is_older("0.3.0", "0.4.0"));
+        assert!(is_older("0.9.9", "1.0.0"));
+        assert!(!is_older("0.4.0", "0.3.0"));
+        assert!(!is_older("0.3.0", "0.3.0"));
+        assert!(!is_older("0.3.0", "unknown"));
+    }
+
+    #[test]
+    fn test_read_missing_metadata() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = MetadataManager::read(temp_dir.path());
+        assert!(matches!(result, Err(CliError::Config(_))));
+    }
+
+    #[test]
+    fn test_read_rejects_old_schema_and_migrate_upgrades_it() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = serde_json::to_value(ProjectConfig::default()).unwrap();
+        let legacy = serde_json::json!({
+            "version": "0.2.0",
+            "generated_at": "2026-01-01T00:00:00Z",
+            "config": config,
+            "file_checksums": { "Cargo.toml": "abc" }
+        });
+        std::fs::write(temp_dir.path().join(METADATA_FILE), legacy.to_string()).unwrap();
+
+        match MetadataManager::read(temp_dir.path()) {
+            Err(CliError::Generation(message)) => {
+                assert!(message.contains("regenerate"));
+                assert!(message.contains("migrate"));
+            }
+            other => panic!("expected a schema version error, got {:?}", other),
+        }
+
+        assert!(MetadataManager::migrate(temp_dir.path()).unwrap());
+        let metadata = MetadataManager::read(temp_dir.path()).unwrap();
+        assert_eq!(metadata.metadata_version, CURRENT_METADATA_VERSION);
+        assert_eq!(metadata.file_checksums["Cargo.toml"], "abc");
+        assert!(metadata.file_snapshots.is_empty());
+        assert!(!MetadataManager::migrate(temp_dir.path()).unwrap());
+    }
+
+    #[test]
+    fn test_migrate_rejects_unknown_schema() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join(METADATA_FILE),
+            r#"{"metadata_version": "9.0"}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            MetadataManager::read(temp_dir.path()),
+            Err(CliError::Generation(_))
+        ));
+        assert!(matches!(
+            MetadataManager::migrate(temp_dir.path()),
+            Err(CliError::Generation(_))
+        ));
+    }
+}
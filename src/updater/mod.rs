@@ -0,0 +1,9 @@
+// Updater module
+//
+// This module tracks generated files so projects can be updated or repaired later.
+
+pub mod checksum;
+pub mod handler;
+pub mod metadata;
+pub mod regen;
+pub mod repair;
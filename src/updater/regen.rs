@@ -0,0 +1,450 @@
+// File regeneration
+//
+// This module restores one generated file, or refreshes the Cargo manifests,
+// from templates using the configuration recorded in the project's generation
+// metadata. Locally modified files can be three-way merged with the new output.
+
+use crate::config::Locale;
+use crate::error::{CliError, Result};
+use crate::generator::project::{render_project_files, write_file};
+use crate::template::engine::{merge_manifest, render_diff};
+use crate::updater::checksum::ChecksumCalculator;
+use crate::updater::metadata::{GenerationMetadata, MetadataManager};
+use diffy::{ConflictStyle, MergeOptions};
+use serde::Serialize;
+use std::path::Path;
+
+/// 重新生成方式 / How a regenerated file was written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileClassification {
+    /// 已写入 / Replaced with the freshly generated content
+    Written,
+    /// 已合并 / Local modifications three-way merged with the generated content
+    Merged {
+        /// `true` if the merge left `<<<<<<<` / `=======` / `>>>>>>>` markers to resolve
+        conflicts: bool,
+    },
+}
+
+/// 重新生成的文件 / A regenerated file and how it was written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegeneratedFile {
+    /// 文件路径 / Path relative to the project root
+    pub path: String,
+    /// 写入方式 / How the file was written
+    pub classification: FileClassification,
+    /// 备份路径 / Where the previous content was copied with `--backup` (e.g. `src/main.rs.bak`)
+    pub backup: Option<String>,
+    /// 差异 / Unified diff from the previous content, if the file existed and changed
+    pub diff: Option<String>,
+}
+
+/// 重新生成报告 / Files written by a regen run, grouped by how they were written
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RegenReport {
+    /// 已写入的文件 / Files replaced with the freshly generated content
+    pub files_written: Vec<String>,
+    /// 已干净合并的文件 / Files whose local modifications merged cleanly
+    pub files_merged: Vec<String>,
+    /// 存在冲突的文件 / Merged files left with conflict markers to resolve
+    pub files_conflicted: Vec<String>,
+    /// 备份文件 / Backups of the previous content written with `--backup`
+    pub files_backed_up: Vec<String>,
+    /// 差异 / Unified diffs of the files that changed, printed with `--verbose`
+    #[serde(skip)]
+    pub diffs: Vec<String>,
+}
+
+impl RegenReport {
+    /// Group regenerated files by their [`FileClassification`]
+    pub fn new(files: Vec<RegeneratedFile>) -> Self {
+        let mut report = Self::default();
+        for file in files {
+            report.files_backed_up.extend(file.backup);
+            report.diffs.extend(file.diff);
+            match file.classification {
+                FileClassification::Written => report.files_written.push(file.path),
+                FileClassification::Merged { conflicts: false } => {
+                    report.files_merged.push(file.path)
+                }
+                FileClassification::Merged { conflicts: true } => {
+                    report.files_conflicted.push(file.path)
+                }
+            }
+        }
+        report
+    }
+
+    /// 机器可读输出 / The report as JSON, for CI steps that parse it
+    ///
+    /// # Examples
+    /// ```
+    /// use axum_app_create::updater::regen::{FileClassification, RegenReport, RegeneratedFile};
+    ///
+    /// let report = RegenReport::new(vec![RegeneratedFile {
+    ///     path: "src/main.rs".to_string(),
+    ///     classification: FileClassification::Merged { conflicts: true },
+    ///     backup: None,
+    ///     diff: None,
+    /// }]);
+    /// assert_eq!(report.to_json()["files_conflicted"][0], "src/main.rs");
+    /// ```
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    /// 报告摘要 / Human-readable summary in `locale`, one line per file
+    pub fn summary(&self, locale: Locale) -> String {
+        let mut lines = Vec::new();
+        for path in &self.files_written {
+            lines.push(format!(
+                "✓ {} {}",
+                locale.text("Regenerated", "已重新生成"),
+                path
+            ));
+        }
+        for path in &self.files_merged {
+            lines.push(format!("✓ {} {}", locale.text("Merged", "已合并"), path));
+        }
+        for path in &self.files_conflicted {
+            lines.push(format!(
+                "⚠️  {}",
+                locale.text(
+                    &format!(
+                        "Merged {} with conflicts: resolve the <<<<<<< markers",
+                        path
+                    ),
+                    &format!("已合并 {}，存在冲突：请解决 <<<<<<< 标记", path)
+                )
+            ));
+        }
+        for path in &self.files_backed_up {
+            lines.push(format!(
+                "✓ {}",
+                locale.text(
+                    &format!("Backed up previous content to {}", path),
+                    &format!("已将原内容备份到 {}", path)
+                )
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// 重新生成单个文件 / Regenerate a single file from templates
+///
+/// Reads `.axum-app-create.json` in `project_dir`, renders the project's
+/// templates with the recorded configuration and writes the file at `path`.
+///
+/// A file that was changed since generation (its checksum no longer matches
+/// the metadata) is a conflict and is only overwritten with `force`. With
+/// `merge`, it is instead three-way merged against the content it was
+/// generated with, if the metadata has a snapshot of it; a `Cargo.toml` is
+/// merged by key instead (see [`merge_manifest`]). With `backup`, the
+/// previous content of a file that changes is first copied to `<path>.bak`.
+///
+/// # Errors
+/// * `CliError::Config` if the project has no generation metadata
+/// * `CliError::ValidationError` if `path` is not produced by the templates
+/// * `CliError::Generation` if the metadata schema is incompatible, or the existing
+///   file was modified and can neither be overwritten nor merged
+pub fn regen_file(
+    project_dir: &Path,
+    path: &str,
+    force: bool,
+    merge: bool,
+    backup: bool,
+) -> Result<RegeneratedFile> {
+    let path = normalize_path(path);
+    let mut metadata = MetadataManager::read(project_dir)?;
+
+    let files = render_project_files(&metadata.config)?;
+    let Some((_, content)) = files.iter().find(|(p, _)| *p == path) else {
+        let mut known: Vec<&str> = files.iter().map(|(p, _)| p.as_str()).collect();
+        known.sort();
+        return Err(CliError::ValidationError(format!(
+            "❌ 模板中没有该文件 / No template generates '{}'\n\n\
+             💡 可重新生成的文件 / Files that can be regenerated:\n  - {}",
+            path,
+            known.join("\n  - ")
+        )));
+    };
+
+    let merged = plan_regenerated(project_dir, &metadata, &path, content, force, merge)?;
+    let file = write_regenerated(project_dir, &mut metadata, &path, content, merged, backup)?;
+    MetadataManager::update(
+        project_dir,
+        metadata.file_checksums,
+        metadata.file_snapshots,
+    )?;
+
+    Ok(file)
+}
+
+/// 仅重新生成清单 / Regenerate only the Cargo manifests from the recorded configuration
+///
+/// Rewrites `Cargo.toml` (and every workspace member's `Cargo.toml`) so the
+/// dependencies match the configuration in `.axum-app-create.json`, leaving all
+/// other files untouched. Returns the regenerated manifests and how each was
+/// written; `backup` copies changed manifests to `<path>.bak` first.
+///
+/// # Errors
+/// * `CliError::Config` if the project has no generation metadata
+/// * `CliError::Generation` if the metadata schema is incompatible, or a
+///   manifest was modified and can neither be overwritten nor merged (nothing
+///   is written then)
+pub fn regen_manifests(
+    project_dir: &Path,
+    force: bool,
+    merge: bool,
+    backup: bool,
+) -> Result<Vec<RegeneratedFile>> {
+    let mut metadata = MetadataManager::read(project_dir)?;
+
+    let files = render_project_files(&metadata.config)?;
+    let manifests: Vec<_> = files.iter().filter(|(path, _)| is_manifest(path)).collect();
+
+    // Plan every manifest before writing any, so a conflict leaves the project as it was
+    let plans = manifests
+        .iter()
+        .map(|(path, content)| {
+            plan_regenerated(project_dir, &metadata, path, content, force, merge)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut regenerated = Vec::new();
+    for ((path, content), merged) in manifests.into_iter().zip(plans) {
+        regenerated.push(write_regenerated(
+            project_dir,
+            &mut metadata,
+            path,
+            content,
+            merged,
+            backup,
+        )?);
+    }
+    MetadataManager::update(
+        project_dir,
+        metadata.file_checksums,
+        metadata.file_snapshots,
+    )?;
+
+    Ok(regenerated)
+}
+
+/// Decide how the file at `path` can be regenerated without losing local changes
+///
+/// Returns `None` if the generated `content` can simply be written, or the merged
+/// text and whether it has conflict markers if the file was modified and `merge`
+/// is set. Modified manifests that parse as TOML are merged by key, which never
+/// conflicts and needs no snapshot.
+///
+/// # Errors
+/// * `CliError::Generation` if the file was modified and neither `force` nor
+///   `merge` applies, or there is no snapshot to merge against
+fn plan_regenerated(
+    project_dir: &Path,
+    metadata: &GenerationMetadata,
+    path: &str,
+    content: &str,
+    force: bool,
+    merge: bool,
+) -> Result<Option<(String, bool)>> {
+    if force {
+        return Ok(None);
+    }
+    let Some(current) = modified_content(project_dir, metadata, path, content)? else {
+        return Ok(None);
+    };
+
+    // Manifests merge by TOML key, so added dependencies never conflict
+    if merge && is_manifest(path) {
+        let merged = String::from_utf8(current.clone())
+            .ok()
+            .and_then(|current| merge_manifest(&current, content));
+        if let Some(merged) = merged {
+            return Ok(Some((merged, false)));
+        }
+    }
+
+    let original = metadata.file_snapshots.get(path);
+    let (true, Some(original), Ok(current)) = (merge, original, String::from_utf8(current)) else {
+        let fix = if merge {
+            "没有可用于合并的原始内容，使用 --force 覆盖 / No original content was recorded to merge with; use --force to overwrite it"
+        } else {
+            "使用 --force 覆盖或 --merge 合并 / Use --force to overwrite it or --merge to merge your changes"
+        };
+        return Err(CliError::Generation(format!(
+            "❌ 文件已被修改 / '{}' has been modified since generation\n\n\
+             💡 修复建议 / Fix: {}",
+            path, fix
+        )));
+    };
+
+    let merged = MergeOptions::new()
+        .set_conflict_style(ConflictStyle::Merge)
+        .merge(original, &current, content);
+    Ok(Some(match merged {
+        Ok(merged) => (merged, false),
+        Err(merged) => (merged, true),
+    }))
+}
+
+/// Write a regenerated (or merged) file and record it in `metadata`
+///
+/// The checksum and snapshot always describe the generated `content`, so a
+/// merged file still counts as locally modified on the next regeneration.
+/// With `backup`, an existing file whose content changes is copied to
+/// `<path>.bak` before it is overwritten.
+fn write_regenerated(
+    project_dir: &Path,
+    metadata: &mut GenerationMetadata,
+    path: &str,
+    content: &str,
+    merged: Option<(String, bool)>,
+    backup: bool,
+) -> Result<RegeneratedFile> {
+    let (new_content, classification) = match &merged {
+        Some((merged, conflicts)) => (
+            merged.as_str(),
+            FileClassification::Merged {
+                conflicts: *conflicts,
+            },
+        ),
+        None => (content, FileClassification::Written),
+    };
+
+    let diff = std::fs::read_to_string(project_dir.join(path))
+        .ok()
+        .and_then(|current| render_diff(path, &current, new_content));
+    let backup = if backup {
+        backup_file(project_dir, path, new_content)?
+    } else {
+        None
+    };
+    write_file(project_dir, path, new_content)?;
+
+    metadata.file_checksums.insert(
+        path.to_string(),
+        ChecksumCalculator::calculate(content.as_bytes()),
+    );
+    metadata
+        .file_snapshots
+        .insert(path.to_string(), content.to_string());
+
+    Ok(RegeneratedFile {
+        path: path.to_string(),
+        classification,
+        backup,
+        diff,
+    })
+}
+
+/// Copy the file at `path` to `<path>.bak` if it exists and differs from `new_content`
+///
+/// Returns the backup path relative to the project root, or `None` if there
+/// was nothing to lose.
+fn backup_file(project_dir: &Path, path: &str, new_content: &str) -> Result<Option<String>> {
+    let target = project_dir.join(path);
+    if !target.is_file() || std::fs::read(&target)? == new_content.as_bytes() {
+        return Ok(None);
+    }
+
+    let backup = format!("{}.bak", path);
+    std::fs::copy(&target, project_dir.join(&backup))?;
+    Ok(Some(backup))
+}
+
+/// Current content of the file at `path` if it was modified since generation
+///
+/// A missing file, or one that already matches the generated `content`, is not
+/// considered modified.
+fn modified_content(
+    project_dir: &Path,
+    metadata: &GenerationMetadata,
+    path: &str,
+    content: &str,
+) -> Result<Option<Vec<u8>>> {
+    let target = project_dir.join(path);
+    if !target.is_file() {
+        return Ok(None);
+    }
+
+    let current = std::fs::read(&target)?;
+    let checksum = ChecksumCalculator::calculate(&current);
+    let modified = metadata
+        .file_checksums
+        .get(path)
+        .is_none_or(|recorded| *recorded != checksum);
+    if modified && checksum != ChecksumCalculator::calculate(content.as_bytes()) {
+        Ok(Some(current))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Whether `path` is a Cargo manifest (the root one or a workspace member's)
+fn is_manifest(path: &str) -> bool {
+    path == "Cargo.toml" || path.ends_with("/Cargo.toml")
+}
+
+/// Normalize a user-supplied path to a template key (`./src\main.rs` → `src/main.rs`)
+fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.trim_start_matches("./").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(normalize_path("./src/main.rs"), "src/main.rs");
+        assert_eq!(
+            normalize_path("src\\handlers\\health.rs"),
+            "src/handlers/health.rs"
+        );
+        assert_eq!(normalize_path("Cargo.toml"), "Cargo.toml");
+    }
+
+    #[test]
+    fn test_regen_report_json() {
+        let file = |path: &str, classification, backup: Option<&str>| RegeneratedFile {
+            path: path.to_string(),
+            classification,
+            backup: backup.map(str::to_string),
+            diff: None,
+        };
+        let report = RegenReport::new(vec![
+            file(
+                "Cargo.toml",
+                FileClassification::Written,
+                Some("Cargo.toml.bak"),
+            ),
+            file("api/Cargo.toml", FileClassification::Written, None),
+            file(
+                "domain/Cargo.toml",
+                FileClassification::Merged { conflicts: false },
+                None,
+            ),
+            file(
+                "common/Cargo.toml",
+                FileClassification::Merged { conflicts: true },
+                None,
+            ),
+        ]);
+
+        let json = report.to_json();
+        assert_eq!(json["files_written"].as_array().unwrap().len(), 2);
+        assert_eq!(json["files_merged"].as_array().unwrap().len(), 1);
+        assert_eq!(json["files_conflicted"].as_array().unwrap().len(), 1);
+        assert_eq!(json["files_conflicted"][0], "common/Cargo.toml");
+        assert_eq!(json["files_backed_up"][0], "Cargo.toml.bak");
+
+        let summary = report.summary(Locale::En);
+        assert_eq!(summary.lines().count(), 5);
+        assert!(summary.contains("Merged common/Cargo.toml with conflicts"));
+        assert!(!report.summary(Locale::Zh).contains("Merged"));
+    }
+}
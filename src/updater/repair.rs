@@ -0,0 +1,122 @@
+// Project repair
+//
+// This module re-syncs files a user may have damaged by hand with what the
+// templates expect, without touching anything else in the project.
+
+use crate::error::Result;
+use crate::generator::project::render_file;
+use crate::updater::metadata::MetadataManager;
+use std::path::Path;
+
+/// 同步 .gitignore / Re-add ignore patterns the templates expect to `.gitignore`
+///
+/// Renders the project's `.gitignore` template with the configuration recorded
+/// in its generation metadata and appends every pattern missing from the
+/// existing file (creating it if needed). Running it again adds nothing.
+///
+/// Returns the patterns that were added, in template order.
+///
+/// # Errors
+/// * `CliError::Config` if the project has no generation metadata
+/// * `CliError::Generation` if the metadata schema is incompatible
+pub fn repair_gitignore(project_dir: &Path) -> Result<Vec<String>> {
+    let metadata = MetadataManager::read(project_dir)?;
+    let Some(expected) = render_file(&metadata.config, ".gitignore", None)? else {
+        // `--bare` projects have no .gitignore of their own
+        return Ok(Vec::new());
+    };
+
+    let path = project_dir.join(".gitignore");
+    let current = if path.is_file() {
+        std::fs::read_to_string(&path)?
+    } else {
+        String::new()
+    };
+
+    let present: Vec<&str> = current.lines().map(str::trim).collect();
+    let missing: Vec<String> = expected
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter(|line| !present.contains(line))
+        .map(str::to_string)
+        .collect();
+
+    if missing.is_empty() {
+        return Ok(missing);
+    }
+
+    let mut content = current;
+    if !content.is_empty() {
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        if !content.ends_with("\n\n") {
+            content.push('\n');
+        }
+    }
+    content.push_str("# Restored by axum-app-create repair\n");
+    for pattern in &missing {
+        content.push_str(pattern);
+        content.push('\n');
+    }
+    std::fs::write(&path, content)?;
+
+    Ok(missing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ProjectConfig;
+    use crate::updater::metadata::METADATA_FILE;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn project_with_gitignore(gitignore: Option<&str>) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        MetadataManager::create(
+            temp_dir.path(),
+            &ProjectConfig::default(),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap();
+        if let Some(content) = gitignore {
+            std::fs::write(temp_dir.path().join(".gitignore"), content).unwrap();
+        }
+        temp_dir
+    }
+
+    #[test]
+    fn test_repair_gitignore_readds_metadata_line_once() {
+        let config = ProjectConfig::default();
+        let expected = render_file(&config, ".gitignore", None).unwrap().unwrap();
+        let damaged = expected.replace(&format!("{}\n", METADATA_FILE), "");
+        let temp_dir = project_with_gitignore(Some(&damaged));
+
+        let added = repair_gitignore(temp_dir.path()).unwrap();
+        assert_eq!(added, vec![METADATA_FILE.to_string()]);
+        assert!(repair_gitignore(temp_dir.path()).unwrap().is_empty());
+
+        let repaired = std::fs::read_to_string(temp_dir.path().join(".gitignore")).unwrap();
+        assert_eq!(
+            repaired
+                .lines()
+                .filter(|line| *line == METADATA_FILE)
+                .count(),
+            1
+        );
+        assert!(repaired.starts_with(&damaged));
+    }
+
+    #[test]
+    fn test_repair_gitignore_creates_missing_file() {
+        let temp_dir = project_with_gitignore(None);
+
+        let added = repair_gitignore(temp_dir.path()).unwrap();
+        assert!(added.contains(&"/target/".to_string()));
+        assert!(added.contains(&METADATA_FILE.to_string()));
+        assert!(temp_dir.path().join(".gitignore").is_file());
+    }
+}
@@ -0,0 +1,132 @@
+// Casing utilities
+//
+// This module converts project and entity names into snake_case and PascalCase
+// identifiers. Both conversions share one word-splitting rule so that they
+// always agree on where words begin.
+
+/// Split `name` into words
+///
+/// Words are separated by `-`, `_` or whitespace, and by case changes inside a
+/// segment: a lowercase letter or digit followed by an uppercase letter starts
+/// a new word (`blogPost` → `blog`, `Post`), and an acronym ends before its last
+/// uppercase letter when a lowercase letter follows (`HTTPServer` → `HTTP`,
+/// `Server`). Digits stay attached to the word they follow (`http2`).
+fn words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+
+    for segment in name.split(['-', '_', ' ', '\t']) {
+        let chars: Vec<(usize, char)> = segment.char_indices().collect();
+        let mut start = 0;
+
+        for i in 1..chars.len() {
+            let (index, c) = chars[i];
+            let prev = chars[i - 1].1;
+            let next_is_lower = chars.get(i + 1).is_some_and(|(_, n)| n.is_lowercase());
+
+            let boundary = c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_is_lower));
+            if boundary {
+                words.push(&segment[start..index]);
+                start = index;
+            }
+        }
+
+        if start < segment.len() {
+            words.push(&segment[start..]);
+        }
+    }
+
+    words
+}
+
+/// Convert a name to snake_case (e.g. `my-API-app` → `my_api_app`)
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::casing::to_snake_case;
+///
+/// assert_eq!(to_snake_case("my-app"), "my_app");
+/// assert_eq!(to_snake_case("BlogPost"), "blog_post");
+/// assert_eq!(to_snake_case("my-API-app"), "my_api_app");
+/// ```
+pub fn to_snake_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Convert a name to PascalCase (e.g. `my-API-app` → `MyApiApp`)
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::casing::to_pascal_case;
+///
+/// assert_eq!(to_pascal_case("my-app"), "MyApp");
+/// assert_eq!(to_pascal_case("my-API-app"), "MyApiApp");
+/// ```
+pub fn to_pascal_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+/// Convert a package name to the crate name Cargo derives from it
+///
+/// Cargo only replaces hyphens, so the case of the package name is kept
+/// (`my-API-app` → `my_API_app`). Use this for `use` paths and log targets.
+pub fn to_crate_name(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("my-app"), "my_app");
+        assert_eq!(to_snake_case("my_axum_app"), "my_axum_app");
+        assert_eq!(to_snake_case("myapp"), "myapp");
+        assert_eq!(to_snake_case("my-API-app"), "my_api_app");
+        assert_eq!(to_snake_case("get-http2-client"), "get_http2_client");
+        assert_eq!(to_snake_case("a-1-b"), "a_1_b");
+    }
+
+    #[test]
+    fn test_to_snake_case_from_pascal() {
+        assert_eq!(to_snake_case("User"), "user");
+        assert_eq!(to_snake_case("BlogPost"), "blog_post");
+        assert_eq!(to_snake_case("OrderItem2"), "order_item2");
+        assert_eq!(to_snake_case("HTTPServer"), "http_server");
+    }
+
+    #[test]
+    fn test_to_pascal_case() {
+        assert_eq!(to_pascal_case("my-app"), "MyApp");
+        assert_eq!(to_pascal_case("my-axum-app"), "MyAxumApp");
+        assert_eq!(to_pascal_case("myapp"), "Myapp");
+        assert_eq!(to_pascal_case("my-API-app"), "MyApiApp");
+        assert_eq!(to_pascal_case("get-http2-client"), "GetHttp2Client");
+        assert_eq!(to_pascal_case("a-1-b"), "A1B");
+    }
+
+    #[test]
+    fn test_to_crate_name() {
+        assert_eq!(to_crate_name("my-app"), "my_app");
+        assert_eq!(to_crate_name("my-API-app"), "my_API_app");
+    }
+}
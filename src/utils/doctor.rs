@@ -0,0 +1,150 @@
+// Environment diagnostics
+//
+// This module backs the `doctor` subcommand: it checks the tools generation
+// depends on and reports each one as a pass/fail item.
+
+use crate::config::user_config_path;
+use crate::utils::rust_toolchain::{check_rust_toolchain, get_cargo_version, get_rust_version};
+use std::path::Path;
+use std::process::Command;
+
+/// 检查结果 / One item of the doctor checklist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorCheck {
+    /// 检查项 / What was checked (e.g. "rustc")
+    pub name: &'static str,
+    /// 是否通过 / Whether the check passed
+    pub passed: bool,
+    /// 是否必需 / A failed required check makes `doctor` exit non-zero
+    pub required: bool,
+    /// 详情 / Version string, path or the reason it failed
+    pub detail: String,
+}
+
+impl DoctorCheck {
+    /// Format the check as a checklist line (e.g. `✓ rustc: rustc 1.85.0`)
+    pub fn line(&self) -> String {
+        let mark = match (self.passed, self.required) {
+            (true, _) => "✓",
+            (false, true) => "✗",
+            (false, false) => "⚠",
+        };
+        format!("{} {}: {}", mark, self.name, self.detail)
+    }
+}
+
+/// Run every check, in checklist order
+///
+/// # Examples
+/// ```
+/// use axum_app_create::utils::doctor::run_checks;
+///
+/// let checks = run_checks();
+/// assert!(checks.iter().any(|check| check.name == "rustc"));
+/// ```
+pub fn run_checks() -> Vec<DoctorCheck> {
+    let first_line = |output: String| output.lines().next().unwrap_or_default().to_string();
+
+    let toolchain = check_rust_toolchain();
+    let rustc = match (get_rust_version(), &toolchain) {
+        (Ok(version), Ok(())) => (true, first_line(version)),
+        (Ok(version), Err(_)) => (false, format!("{} (too old)", first_line(version))),
+        (Err(e), _) => (false, first_line(e.to_string())),
+    };
+    let cargo = match get_cargo_version() {
+        Ok(version) => (true, first_line(version)),
+        Err(e) => (false, first_line(e.to_string())),
+    };
+    let git = match Command::new("git").arg("--version").output() {
+        Ok(output) if output.status.success() => (
+            true,
+            first_line(String::from_utf8_lossy(&output.stdout).into()),
+        ),
+        _ => (
+            false,
+            "not found on PATH (use --no-git to skip repository init)".to_string(),
+        ),
+    };
+    let user_config = check_user_config(user_config_path().as_deref());
+
+    vec![
+        DoctorCheck {
+            name: "rustc",
+            passed: rustc.0,
+            required: true,
+            detail: rustc.1,
+        },
+        DoctorCheck {
+            name: "cargo",
+            passed: cargo.0,
+            required: true,
+            detail: cargo.1,
+        },
+        DoctorCheck {
+            name: "git",
+            passed: git.0,
+            required: false,
+            detail: git.1,
+        },
+        DoctorCheck {
+            name: "user config",
+            passed: user_config.0,
+            required: false,
+            detail: user_config.1,
+        },
+    ]
+}
+
+/// A missing user config passes (it is optional); one that exists must parse as TOML
+fn check_user_config(path: Option<&Path>) -> (bool, String) {
+    let Some(path) = path else {
+        return (true, "no home directory, skipped".to_string());
+    };
+    if !path.exists() {
+        return (true, format!("{} not found (optional)", path.display()));
+    }
+
+    match std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| {
+            toml::from_str::<toml::Table>(&content).map_err(|e| e.message().to_string())
+        }) {
+        Ok(_) => (true, format!("{} parses", path.display())),
+        Err(e) => (false, format!("{} is invalid: {}", path.display(), e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::USER_CONFIG_FILE;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_check_user_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(USER_CONFIG_FILE);
+
+        assert!(check_user_config(Some(&path)).0);
+        assert!(check_user_config(None).0);
+
+        std::fs::write(&path, "mode = \"workspace\"\n").unwrap();
+        assert!(check_user_config(Some(&path)).0);
+
+        std::fs::write(&path, "mode = \n").unwrap();
+        let (passed, detail) = check_user_config(Some(&path));
+        assert!(!passed);
+        assert!(detail.contains("invalid"));
+    }
+
+    #[test]
+    fn test_doctor_check_line() {
+        let check = DoctorCheck {
+            name: "git",
+            passed: false,
+            required: false,
+            detail: "not found".to_string(),
+        };
+        assert_eq!(check.line(), "⚠ git: not found");
+    }
+}
@@ -2,5 +2,8 @@
 //
 // This module contains utility functions.
 
+pub mod casing;
+pub mod doctor;
+pub mod naming;
 pub mod rust_toolchain;
 pub mod validator;
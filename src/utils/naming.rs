@@ -0,0 +1,68 @@
+// Naming utilities
+//
+// This module converts entity names into the identifiers used by generated code.
+
+/// Irregular English plurals commonly used as entity names
+const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    ("child", "children"),
+    ("person", "people"),
+    ("man", "men"),
+    ("woman", "women"),
+];
+
+/// Pluralize a lowercase English noun (e.g. `post` → `posts`, `category` → `categories`)
+///
+/// For snake_case input only the last word is pluralized (`blog_post` → `blog_posts`).
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::naming::pluralize;
+///
+/// assert_eq!(pluralize("user"), "users");
+/// assert_eq!(pluralize("address"), "addresses");
+/// assert_eq!(pluralize("category"), "categories");
+/// assert_eq!(pluralize("blog_post"), "blog_posts");
+/// ```
+pub fn pluralize(word: &str) -> String {
+    let (prefix, last) = match word.rfind('_') {
+        Some(index) => word.split_at(index + 1),
+        None => ("", word),
+    };
+
+    if let Some((_, plural)) = IRREGULAR_PLURALS.iter().find(|(s, _)| *s == last) {
+        return format!("{}{}", prefix, plural);
+    }
+
+    let ends_with_consonant_y = last.ends_with('y')
+        && !last[..last.len() - 1].ends_with(['a', 'e', 'i', 'o', 'u'])
+        && last.len() > 1;
+
+    if ends_with_consonant_y {
+        format!("{}{}ies", prefix, &last[..last.len() - 1])
+    } else if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|s| last.ends_with(s))
+    {
+        format!("{}{}es", prefix, last)
+    } else {
+        format!("{}{}s", prefix, last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("post"), "posts");
+        assert_eq!(pluralize("comment"), "comments");
+        assert_eq!(pluralize("box"), "boxes");
+        assert_eq!(pluralize("match"), "matches");
+        assert_eq!(pluralize("category"), "categories");
+        assert_eq!(pluralize("day"), "days");
+        assert_eq!(pluralize("person"), "people");
+        assert_eq!(pluralize("order_item"), "order_items");
+        assert_eq!(pluralize("sales_person"), "sales_people");
+    }
+}
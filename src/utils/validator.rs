@@ -2,6 +2,8 @@
 //
 // This module contains validation logic for project names and inputs.
 
+use crate::config::{DatabaseOption, Edition, FeatureSet, ProjectMode};
+use crate::utils::casing::{to_crate_name, to_snake_case};
 use std::collections::HashSet;
 
 /// Reserved Cargo keywords that cannot be used as project names
@@ -13,6 +15,49 @@ const RESERVED_KEYWORDS: &[&str] = &[
     "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
 ];
 
+/// Dependencies of generated projects, as crate names (`-` replaced by `_`)
+///
+/// A project whose crate name matches one of these shadows the dependency,
+/// so `use axum::...` inside the generated code would resolve to the project itself.
+const GENERATED_DEPENDENCIES: &[&str] = &[
+    "anyhow",
+    "axum",
+    "bcrypt",
+    "biz_error",
+    "jsonwebtoken",
+    "serde",
+    "serde_json",
+    "serde_yaml",
+    "sqlx",
+    "testcontainers_modules",
+    "thiserror",
+    "tokio",
+    "tokio_test",
+    "tower",
+    "tower_http",
+    "tracing",
+    "tracing_subscriber",
+];
+
+/// Minimum edition required by the code generated for each feature
+///
+/// The base server and every feature's handlers are `async fn`s, which need
+/// edition 2018.
+const FEATURE_MIN_EDITIONS: &[(&str, Edition)] = &[
+    ("server", Edition::E2018),
+    ("database", Edition::E2018),
+    ("auth", Edition::E2018),
+    ("biz-error", Edition::E2018),
+    ("openapi", Edition::E2018),
+    ("sse", Edition::E2018),
+    ("sessions", Edition::E2018),
+    ("multipart", Edition::E2018),
+    ("cache", Edition::E2018),
+    ("cors", Edition::E2018),
+    ("compression", Edition::E2018),
+    ("metrics", Edition::E2018),
+];
+
 /// Validate project name according to Cargo naming conventions
 ///
 /// # 命名规则 / Naming Rules
@@ -22,6 +67,7 @@ const RESERVED_KEYWORDS: &[&str] = &[
 /// - 不能是Rust保留关键字 / Cannot be a Rust reserved keyword
 /// - 只能包含字母数字、连字符和下划线 / Can only contain alphanumeric characters, hyphens, and underscores
 /// - 不能以连字符或下划线开头 / Cannot start with a hyphen or underscore
+/// - 不能与生成项目的依赖同名 / Cannot shadow a dependency of the generated project
 ///
 /// # 参数 / Arguments
 /// * `name` - 要验证的项目名称 / The project name to validate
@@ -38,6 +84,7 @@ const RESERVED_KEYWORDS: &[&str] = &[
 /// assert!(validate_project_name("my_app").is_ok());
 /// assert!(validate_project_name("123invalid").is_err());
 /// assert!(validate_project_name("fn").is_err()); // Reserved keyword
+/// assert!(validate_project_name("tokio").is_err()); // Shadows a dependency
 /// ```
 pub fn validate_project_name(name: &str) -> Result<(), String> {
     // Check if empty
@@ -133,9 +180,388 @@ pub fn validate_project_name(name: &str) -> Result<(), String> {
         );
     }
 
+    // Check if the crate name would shadow a generated dependency
+    let crate_name = to_crate_name(name);
+    if GENERATED_DEPENDENCIES.contains(&crate_name.as_str()) {
+        return Err(format!(
+            "❌ 项目名称与依赖同名 / Project name shadows a dependency: '{}'\n\n\
+             💡 修复建议 / Fix: 生成的代码依赖 '{}'，同名项目会导致 `use` 语句失效 / Generated code depends on '{}'; a project with the same name breaks its `use` statements\n\
+             ✅ 好的示例 / Good examples: 'my-{}', '{}-app'\n\n\
+             📖 查看帮助 / View help: axum-app-create --help",
+            name, crate_name, crate_name, name, name
+        ));
+    }
+
+    Ok(())
+}
+
+/// 建议有效名称 / Turn `raw` into a project name that passes [`validate_project_name`]
+///
+/// The name is lowercased, characters other than letters, digits, `-` and `_`
+/// (including spaces) become `-`, runs of separators collapse into one, and
+/// separators are trimmed from both ends. A name starting with a digit gets an
+/// `app-` prefix, a keyword or dependency name an `-app` suffix, and the
+/// result is cut to 100 characters.
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::validator::suggest_valid_name;
+///
+/// assert_eq!(suggest_valid_name("My App!"), "my-app");
+/// assert_eq!(suggest_valid_name("123svc"), "app-123svc");
+/// assert_eq!(suggest_valid_name("fn"), "fn-app");
+/// ```
+pub fn suggest_valid_name(raw: &str) -> String {
+    let mut name = String::new();
+    for c in raw.to_lowercase().chars() {
+        let c = if c.is_alphanumeric() || c == '_' {
+            c
+        } else {
+            '-'
+        };
+        let is_separator = c == '-' || c == '_';
+        if is_separator && (name.is_empty() || name.ends_with(['-', '_'])) {
+            continue;
+        }
+        name.push(c);
+    }
+    let mut name = name.trim_end_matches(['-', '_']).to_string();
+
+    if name.is_empty() {
+        name = "app".to_string();
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name = format!("app-{}", name);
+    }
+    if RESERVED_KEYWORDS.contains(&name.as_str())
+        || GENERATED_DEPENDENCIES.contains(&to_crate_name(&name).as_str())
+    {
+        name = format!("{}-app", name);
+    }
+
+    // Cut on a character boundary, then drop a separator left at the end
+    while name.len() > 100 {
+        name.pop();
+    }
+    name.trim_end_matches(['-', '_']).to_string()
+}
+
+/// Validate an entity name passed to `--entities`
+///
+/// Entity names become Rust struct names, so they must be PascalCase
+/// identifiers: an ASCII uppercase letter followed by ASCII letters or digits.
+/// Their snake_case form names a module, so it cannot be a Rust keyword.
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::validator::validate_entity_name;
+///
+/// assert!(validate_entity_name("BlogPost").is_ok());
+/// assert!(validate_entity_name("blog_post").is_err());
+/// ```
+pub fn validate_entity_name(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_uppercase())
+        && chars.all(|c| c.is_ascii_alphanumeric());
+
+    if valid && RESERVED_KEYWORDS.contains(&to_snake_case(name).as_str()) {
+        return Err(format!(
+            "❌ 实体名称的模块名是Rust关键字 / Entity module name is a Rust keyword: '{}'\n\n\
+             💡 修复建议 / Fix: 使用更具体的名称 / Use a more specific name (e.g. '{}Record')",
+            to_snake_case(name),
+            name
+        ));
+    }
+
+    if !valid {
+        return Err(format!(
+            "❌ 无效的实体名称 / Invalid entity name: '{}'\n\n\
+             💡 修复建议 / Fix: 使用 PascalCase 名称 / Use a PascalCase name\n\
+             ✅ 好的示例 / Good examples: User, BlogPost, OrderItem",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate a handler name passed to `new-handler`
+///
+/// Handler names become both a module and a route segment, so they must be
+/// snake_case identifiers: an ASCII lowercase letter followed by lowercase
+/// letters, digits or underscores, and not a Rust keyword.
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::validator::validate_handler_name;
+///
+/// assert!(validate_handler_name("orders").is_ok());
+/// assert!(validate_handler_name("Orders").is_err());
+/// ```
+pub fn validate_handler_name(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if valid && RESERVED_KEYWORDS.contains(&name) {
+        return Err(format!(
+            "❌ 处理器名称是Rust关键字 / Handler name is a Rust keyword: '{}'\n\n\
+             💡 修复建议 / Fix: 使用更具体的名称 / Use a more specific name (e.g. '{}_handler')",
+            name, name
+        ));
+    }
+
+    if !valid {
+        return Err(format!(
+            "❌ 无效的处理器名称 / Invalid handler name: '{}'\n\n\
+             💡 修复建议 / Fix: 使用 snake_case 名称 / Use a snake_case name\n\
+             ✅ 好的示例 / Good examples: orders, user_profile, reports2",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate an API version passed to `--api-versions`
+///
+/// Versions name both a route segment and a module, so they must be a
+/// lowercase `v` followed by digits.
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::validator::validate_api_version;
+///
+/// assert!(validate_api_version("v1").is_ok());
+/// assert!(validate_api_version("1").is_err());
+/// ```
+pub fn validate_api_version(version: &str) -> Result<(), String> {
+    let valid = version
+        .strip_prefix('v')
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+
+    if !valid {
+        return Err(format!(
+            "❌ 无效的 API 版本 / Invalid API version: '{}'\n\n\
+             💡 修复建议 / Fix: 使用 'v' 加数字 / Use 'v' followed by digits\n\
+             ✅ 好的示例 / Good examples: v1, v2, v10",
+            version
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate the server host passed to `--host`
+///
+/// The host is written into a Rust string literal and `.env`, so only IP
+/// addresses and DNS hostnames are accepted.
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::validator::validate_host;
+///
+/// assert!(validate_host("0.0.0.0").is_ok());
+/// assert!(validate_host("api.internal").is_ok());
+/// assert!(validate_host("a\"b").is_err());
+/// ```
+pub fn validate_host(host: &str) -> Result<(), String> {
+    let is_hostname = !host.is_empty()
+        && host.len() <= 253
+        && host.split('.').all(|label| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+
+    if !is_hostname && host.parse::<std::net::IpAddr>().is_err() {
+        return Err(format!(
+            "❌ 无效的主机地址 / Invalid host: '{}'\n\n\
+             💡 修复建议 / Fix: 使用 IP 地址或主机名 / Use an IP address or hostname\n\
+             ✅ 好的示例 / Good examples: 127.0.0.1, 0.0.0.0, ::1, localhost",
+            host
+        ));
+    }
+
+    Ok(())
+}
+
+/// JWT 签名算法 / Signing algorithms accepted by `--jwt-algorithm`
+///
+/// Only the HMAC algorithms: they sign with the single `JWT_SECRET` the
+/// generated project is configured with.
+pub const JWT_ALGORITHMS: &[&str] = &["HS256", "HS384", "HS512"];
+
+/// Validate a JWT signing algorithm passed to `--jwt-algorithm`
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::validator::validate_jwt_algorithm;
+///
+/// assert!(validate_jwt_algorithm("HS512").is_ok());
+/// assert!(validate_jwt_algorithm("RS256").is_err());
+/// ```
+pub fn validate_jwt_algorithm(algorithm: &str) -> Result<(), String> {
+    if !JWT_ALGORITHMS.contains(&algorithm) {
+        return Err(format!(
+            "❌ 不支持的 JWT 算法 / Unsupported JWT algorithm: '{}'\n\n\
+             💡 修复建议 / Fix: 使用以下算法之一 / Use one of: {}\n\
+             ✅ 好的示例 / Good example: --jwt-algorithm HS512",
+            algorithm,
+            JWT_ALGORITHMS.join(", ")
+        ));
+    }
+
     Ok(())
 }
 
+/// 许可证 / SPDX license identifiers accepted by `--license`
+///
+/// Each one has its license text embedded; `MIT OR Apache-2.0` writes both.
+pub const LICENSES: &[&str] = &[
+    "MIT",
+    "Apache-2.0",
+    "MIT OR Apache-2.0",
+    "BSD-3-Clause",
+    "ISC",
+];
+
+/// Validate an SPDX license identifier passed to `--license`
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::validator::validate_license;
+///
+/// assert!(validate_license("MIT OR Apache-2.0").is_ok());
+/// assert!(validate_license("mit").is_err());
+/// ```
+pub fn validate_license(license: &str) -> Result<(), String> {
+    if !LICENSES.contains(&license) {
+        return Err(format!(
+            "❌ 不支持的许可证 / Unsupported license: '{}'\n\n\
+             💡 修复建议 / Fix: 使用以下 SPDX 标识之一 / Use one of these SPDX identifiers: {}\n\
+             ✅ 好的示例 / Good example: --license \"MIT OR Apache-2.0\"",
+            license,
+            LICENSES.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate the package names derived for workspace member crates
+///
+/// Each member is named `<project>-<crate>`, so a project name that is valid
+/// on its own can still yield a member name that is too long or shadows a
+/// dependency. Member names must also be distinct from each other.
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::utils::validator::validate_workspace_packages;
+///
+/// assert!(validate_workspace_packages(&["shop-api", "shop-core"]).is_ok());
+/// assert!(validate_workspace_packages(&["shop-api", "shop-api"]).is_err());
+/// ```
+pub fn validate_workspace_packages(package_names: &[&str]) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for name in package_names {
+        if let Err(e) = validate_project_name(name) {
+            return Err(format!(
+                "❌ 工作区成员包名无效 / Invalid workspace member package name: '{}'\n\n\
+                 💡 修复建议 / Fix: 成员包名由项目名加 crate 后缀（如 -api）组成，请缩短或修改项目名 / \
+                 Member package names are the project name plus a crate suffix (e.g. -api); shorten or change the project name\n\n\
+                 {}",
+                name, e
+            ));
+        }
+        if !seen.insert(to_crate_name(name)) {
+            return Err(format!(
+                "❌ 工作区成员包名冲突 / Workspace member package names collide: '{}'\n\n\
+                 💡 修复建议 / Fix: 修改项目名，使每个成员 crate 名称唯一 / Change the project name so every member crate name is unique",
+                name
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate that `--testcontainers` is used with a project that can run it
+///
+/// The generated test starts a PostgreSQL container against the single-mode
+/// repository, so SQLite, Both and workspace projects are rejected rather
+/// than silently generated without it.
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::config::{DatabaseOption, ProjectMode};
+/// use axum_app_create::utils::validator::validate_testcontainers;
+///
+/// assert!(validate_testcontainers(ProjectMode::Single, DatabaseOption::PostgreSQL).is_ok());
+/// assert!(validate_testcontainers(ProjectMode::Single, DatabaseOption::SQLite).is_err());
+/// assert!(validate_testcontainers(ProjectMode::Workspace, DatabaseOption::PostgreSQL).is_err());
+/// ```
+pub fn validate_testcontainers(mode: ProjectMode, database: DatabaseOption) -> Result<(), String> {
+    if mode != ProjectMode::Single || database != DatabaseOption::PostgreSQL {
+        return Err(format!(
+            "❌ --testcontainers 不支持此组合 / --testcontainers is not supported for: {} mode, database {}\n\n\
+             💡 修复建议 / Fix: testcontainers 测试仅支持单包模式的 PostgreSQL / \
+             The testcontainers test is only generated for single mode with PostgreSQL\n\
+             ✅ 好的示例 / Good example: --mode single --database postgresql --testcontainers",
+            mode, database
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validate that `edition` is new enough for the enabled features
+///
+/// # 示例 / Examples
+/// ```
+/// use axum_app_create::config::{Edition, FeatureSet};
+/// use axum_app_create::utils::validator::validate_edition;
+///
+/// let features = FeatureSet::default();
+/// assert!(validate_edition(Edition::E2021, &features).is_ok());
+/// assert!(validate_edition(Edition::E2015, &features).is_err());
+/// ```
+pub fn validate_edition(edition: Edition, features: &FeatureSet) -> Result<(), String> {
+    let enabled = |feature: &str| match feature {
+        "database" => features.database.is_enabled(),
+        "auth" => features.authentication,
+        "biz-error" => features.biz_error,
+        "openapi" => features.openapi,
+        "sse" => features.sse,
+        "sessions" => features.sessions,
+        "multipart" => features.multipart,
+        "cache" => features.cache,
+        "cors" => features.cors,
+        "compression" => features.compression,
+        "metrics" => features.metrics,
+        _ => true,
+    };
+    let too_old: Vec<_> = FEATURE_MIN_EDITIONS
+        .iter()
+        .filter(|(feature, min)| enabled(feature) && edition < *min)
+        .collect();
+
+    let Some(required) = too_old.iter().map(|(_, min)| *min).max() else {
+        return Ok(());
+    };
+    let names: Vec<_> = too_old.iter().map(|(feature, _)| *feature).collect();
+    Err(format!(
+        "❌ Rust 版本过旧 / Edition {} is too old for: {}\n\n\
+         💡 修复建议 / Fix: 所选功能至少需要 {} 版本 / The selected features require minimum edition {}\n\
+         ✅ 好的示例 / Good example: --edition {}",
+        edition,
+        names.join(", "),
+        required,
+        required,
+        Edition::default()
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,6 +615,145 @@ mod tests {
         assert!(validate_project_name("my$app").is_err()); // $
     }
 
+    #[test]
+    fn test_suggest_valid_name() {
+        assert_eq!(suggest_valid_name("My App!"), "my-app");
+        assert_eq!(suggest_valid_name("123svc"), "app-123svc");
+        assert_eq!(suggest_valid_name("fn"), "fn-app");
+        assert_eq!(suggest_valid_name("tokio"), "tokio-app");
+        assert_eq!(suggest_valid_name("  --my..project__ "), "my-project");
+        assert_eq!(suggest_valid_name("@#!"), "app");
+        assert_eq!(suggest_valid_name(&"a".repeat(150)).len(), 100);
+
+        for raw in [
+            "My App!",
+            "123svc",
+            "fn",
+            "_x",
+            "a b-c_d",
+            &"x-".repeat(60),
+            "Ünïcode App",
+        ] {
+            let name = suggest_valid_name(raw);
+            assert!(
+                validate_project_name(&name).is_ok(),
+                "{:?} -> {:?}",
+                raw,
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_workspace_packages() {
+        assert!(validate_workspace_packages(&["shop-api", "shop-domain"]).is_ok());
+
+        let long = format!("{}-infrastructure", "a".repeat(90));
+        let error = validate_workspace_packages(&["a-api", &long]).unwrap_err();
+        assert!(error.contains("Invalid workspace member package name"));
+        assert!(error.contains("too long"));
+
+        // `-` and `_` map to the same crate name
+        let error = validate_workspace_packages(&["shop-api", "shop_api"]).unwrap_err();
+        assert!(error.contains("collide: 'shop_api'"));
+    }
+
+    #[test]
+    fn test_validate_testcontainers() {
+        assert!(validate_testcontainers(ProjectMode::Single, DatabaseOption::PostgreSQL).is_ok());
+
+        for (mode, database) in [
+            (ProjectMode::Single, DatabaseOption::SQLite),
+            (ProjectMode::Single, DatabaseOption::Both),
+            (ProjectMode::Single, DatabaseOption::None),
+            (ProjectMode::Workspace, DatabaseOption::PostgreSQL),
+        ] {
+            let error = validate_testcontainers(mode, database).unwrap_err();
+            assert!(error.contains("--testcontainers is not supported"));
+        }
+    }
+
+    #[test]
+    fn test_shadows_dependency() {
+        let result = validate_project_name("tokio");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("shadows a dependency"));
+        assert!(validate_project_name("axum").is_err());
+        assert!(validate_project_name("tower-http").is_err());
+        assert!(validate_project_name("serde_json").is_err());
+        assert!(validate_project_name("my-tokio").is_ok());
+    }
+
+    #[test]
+    fn test_api_versions() {
+        assert!(validate_api_version("v1").is_ok());
+        assert!(validate_api_version("v10").is_ok());
+        assert!(validate_api_version("v").is_err());
+        assert!(validate_api_version("V1").is_err());
+        assert!(validate_api_version("v1.0").is_err());
+    }
+
+    #[test]
+    fn test_hosts() {
+        for host in [
+            "127.0.0.1",
+            "0.0.0.0",
+            "::",
+            "::1",
+            "localhost",
+            "api-1.example.com",
+        ] {
+            assert!(validate_host(host).is_ok(), "{}", host);
+        }
+        for host in [
+            "",
+            "a\"b",
+            "a\\b",
+            "my host",
+            "-api",
+            "api..local",
+            "{{host}}",
+        ] {
+            assert!(validate_host(host).is_err(), "{}", host);
+        }
+    }
+
+    #[test]
+    fn test_handler_names() {
+        assert!(validate_handler_name("orders").is_ok());
+        assert!(validate_handler_name("user_profile2").is_ok());
+        assert!(validate_handler_name("").is_err());
+        assert!(validate_handler_name("Orders").is_err());
+        assert!(validate_handler_name("user-profile").is_err());
+        assert!(validate_handler_name("2orders").is_err());
+        assert!(validate_handler_name("type").is_err());
+    }
+
+    #[test]
+    fn test_entity_names() {
+        assert!(validate_entity_name("User").is_ok());
+        assert!(validate_entity_name("OrderItem2").is_ok());
+        assert!(validate_entity_name("").is_err());
+        assert!(validate_entity_name("user").is_err());
+        assert!(validate_entity_name("Blog_Post").is_err());
+        assert!(validate_entity_name("2User").is_err());
+        assert!(validate_entity_name("Type").is_err()); // module `type`
+    }
+
+    #[test]
+    fn test_edition_too_old_for_auth() {
+        let features = FeatureSet {
+            authentication: true,
+            ..Default::default()
+        };
+        let error = validate_edition(Edition::E2015, &features).unwrap_err();
+        assert!(error.contains("Edition 2015 is too old for: server, auth"));
+        assert!(error.contains("minimum edition 2018"));
+
+        assert!(validate_edition(Edition::E2018, &features).is_ok());
+        assert!(validate_edition(Edition::E2024, &features).is_ok());
+    }
+
     #[test]
     fn test_starts_with_separator() {
         assert!(validate_project_name("-myapp").is_err());
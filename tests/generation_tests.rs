@@ -18,7 +18,7 @@ fn test_generate_basic_project() {
         ..Default::default()
     };
 
-    let result = generate_project(&project_dir, &config, false, false);
+    let result = generate_project(&project_dir, &config, false, false, false);
     assert!(
         result.is_ok(),
         "Project generation failed: {:?}",
@@ -64,7 +64,7 @@ fn test_generated_project_compiles() {
     };
 
     // Generate project
-    let result = generate_project(&project_dir, &config, false, false);
+    let result = generate_project(&project_dir, &config, false, false, false);
     assert!(
         result.is_ok(),
         "Project generation failed: {:?}",
@@ -118,7 +118,7 @@ fn test_health_endpoint_exists() {
     };
 
     // Generate project
-    let result = generate_project(&project_dir, &config, false, false);
+    let result = generate_project(&project_dir, &config, false, false, false);
     assert!(
         result.is_ok(),
         "Project generation failed: {:?}",
@@ -142,6 +142,53 @@ fn test_health_endpoint_exists() {
     assert!(main_content.contains("health::router()"));
 }
 
+/// Test that the health response reports uptime from a start instant held in router state
+#[test]
+fn test_health_uptime_compiles() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, health_path) in [
+        (ProjectMode::Single, "src/handlers/health.rs"),
+        (ProjectMode::Workspace, "api/src/handlers/health.rs"),
+    ] {
+        let name = format!("uptime-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            features: FeatureSet {
+                openapi: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let health_rs = std::fs::read_to_string(project_dir.join(health_path)).unwrap();
+        assert!(health_rs.contains("pub uptime: u64"));
+        assert!(health_rs.contains("pub started_at: Instant"));
+        assert!(health_rs.contains("state.started_at.elapsed().as_secs()"));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        assert!(
+            output.status.success(),
+            "{} project with health uptime failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
 /// Additional test: Verify .gitignore contains essential patterns
 #[test]
 fn test_gitignore_patterns() {
@@ -153,7 +200,7 @@ fn test_gitignore_patterns() {
         ..Default::default()
     };
 
-    generate_project(&project_dir, &config, false, false).unwrap();
+    generate_project(&project_dir, &config, false, false, false).unwrap();
 
     let gitignore = project_dir.join(".gitignore");
     let content = std::fs::read_to_string(&gitignore).unwrap();
@@ -175,7 +222,7 @@ fn test_readme_bilingual() {
         ..Default::default()
     };
 
-    generate_project(&project_dir, &config, false, false).unwrap();
+    generate_project(&project_dir, &config, false, false, false).unwrap();
 
     let readme = project_dir.join("README.md");
     let content = std::fs::read_to_string(&readme).unwrap();
@@ -202,7 +249,7 @@ fn test_database_feature() {
         ..Default::default()
     };
 
-    generate_project(&project_dir, &config, false, false).unwrap();
+    generate_project(&project_dir, &config, false, false, false).unwrap();
 
     // Verify db.rs exists
     assert!(project_dir.join("src/db.rs").exists());
@@ -219,731 +266,4378 @@ fn test_database_feature() {
     assert!(env_example.contains("DATABASE_URL"));
 }
 
-/// T061: Integration test - generate project with authentication feature
+/// Integration test - generate project with Redis cache feature
 #[test]
-fn test_auth_feature() {
+fn test_cache_feature() {
     use axum_app_create::config::FeatureSet;
 
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("auth-test-app");
 
-    let config = ProjectConfig {
-        project_name: "auth-test-app".to_string(),
-        features: FeatureSet {
-            authentication: true,
+    for (mode, cargo_path, cache_path) in [
+        (ProjectMode::Single, "Cargo.toml", "src/cache.rs"),
+        (
+            ProjectMode::Workspace,
+            "infrastructure/Cargo.toml",
+            "infrastructure/src/cache.rs",
+        ),
+    ] {
+        let name = format!("cache-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            features: FeatureSet {
+                cache: true,
+                ..Default::default()
+            },
             ..Default::default()
-        },
-        ..Default::default()
-    };
+        };
 
-    generate_project(&project_dir, &config, false, false).unwrap();
+        generate_project(&project_dir, &config, false, false, false).unwrap();
 
-    // Verify auth handler exists
-    assert!(project_dir.join("src/handlers/auth.rs").exists());
+        // Verify cache.rs builds a connection manager
+        let cache_rs = std::fs::read_to_string(project_dir.join(cache_path)).unwrap();
+        assert!(cache_rs.contains("ConnectionManager::new(client)"));
 
-    // Verify Cargo.toml contains auth dependencies
-    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
-    assert!(cargo_toml.contains("jsonwebtoken"));
-    assert!(cargo_toml.contains("bcrypt"));
+        // Verify Cargo.toml contains redis dependency
+        let cargo_toml = std::fs::read_to_string(project_dir.join(cargo_path)).unwrap();
+        assert!(cargo_toml.contains("redis = "));
 
-    // Verify .env.example contains JWT_SECRET
-    let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
-    assert!(env_example.contains("JWT_SECRET"));
-}
+        // Verify .env.example contains REDIS_URL
+        let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
+        assert!(env_example.contains("REDIS_URL"));
 
-/// T062: Integration test - generate project with biz-error feature
-#[test]
-fn test_biz_error_feature() {
-    use axum_app_create::config::FeatureSet;
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
 
-    let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("bizerror-test-app");
+        assert!(
+            output.status.success(),
+            "{} project with cache failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
 
+    // Without the feature, no cache module or REDIS_URL is generated
+    let project_dir = temp_dir.path().join("no-cache");
     let config = ProjectConfig {
-        project_name: "bizerror-test-app".to_string(),
-        features: FeatureSet {
-            biz_error: true,
-            ..Default::default()
-        },
+        project_name: "no-cache".to_string(),
         ..Default::default()
     };
-
-    generate_project(&project_dir, &config, false, false).unwrap();
-
-    // Verify biz_errors.yaml exists
-    assert!(project_dir.join("biz_errors.yaml").exists());
-
-    // Verify it contains error definitions in both English and Chinese
-    let biz_errors = std::fs::read_to_string(project_dir.join("biz_errors.yaml")).unwrap();
-    assert!(biz_errors.contains("en:"));
-    assert!(biz_errors.contains("zh:"));
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert!(!project_dir.join("src/cache.rs").exists());
+    let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
+    assert!(!env_example.contains("REDIS_URL"));
 }
 
-/// T063: Integration test - generate project with multiple features
+/// Test that --api-versions nests each version and scaffolds its module
 #[test]
-fn test_multiple_features() {
-    use axum_app_create::config::{DatabaseOption, FeatureSet};
-
+fn test_api_versions() {
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("multi-test-app");
 
-    let config = ProjectConfig {
-        project_name: "multi-test-app".to_string(),
-        features: FeatureSet {
-            database: DatabaseOption::Both,
-            authentication: true,
-            logging: true,
-            biz_error: true,
-        },
-        ..Default::default()
-    };
+    for (mode, main_path, versions_dir) in [
+        (ProjectMode::Single, "src/main.rs", "src/versions"),
+        (
+            ProjectMode::Workspace,
+            "api/src/main.rs",
+            "api/src/versions",
+        ),
+    ] {
+        let name = format!("versions-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            api_versions: vec!["v1".to_string(), "v2".to_string()],
+            ..Default::default()
+        };
 
-    generate_project(&project_dir, &config, false, false).unwrap();
+        generate_project(&project_dir, &config, false, false, false).unwrap();
 
-    // Verify all feature files exist
-    assert!(project_dir.join("src/db.rs").exists());
-    assert!(project_dir.join("src/handlers/auth.rs").exists());
-    assert!(project_dir.join("migrations/001_initial.sql").exists());
-    assert!(project_dir.join("biz_errors.yaml").exists());
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains(".merge(versions::router())"));
 
-    // Verify Cargo.toml contains all dependencies
-    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
-    assert!(cargo_toml.contains("sqlx"));
-    assert!(cargo_toml.contains("jsonwebtoken"));
-    assert!(cargo_toml.contains("tracing"));
+        let versions_mod =
+            std::fs::read_to_string(project_dir.join(versions_dir).join("mod.rs")).unwrap();
+        assert!(versions_mod.contains(r#".nest("/api/v1", v1::router())"#));
+        assert!(versions_mod.contains(r#".nest("/api/v2", v2::router())"#));
+        assert!(project_dir.join(versions_dir).join("v1.rs").exists());
+        assert!(project_dir.join(versions_dir).join("v2.rs").exists());
 
-    // Verify .env.example contains all config variables
-    let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
-    assert!(env_example.contains("DATABASE_URL"));
-    assert!(env_example.contains("JWT_SECRET"));
-    assert!(env_example.contains("LOG_LEVEL"));
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        assert!(
+            output.status.success(),
+            "{} project with API versions failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
 }
 
-/// Test: auth-only project (no database) compiles with `cargo check`
-/// This verifies the auth.rs.hbs template generates valid code without database imports
+/// Test that OpenAPI with auth declares a bearer JWT security scheme
 #[test]
-fn test_auth_only_project_compiles() {
+fn test_openapi_auth_security_scheme() {
     use axum_app_create::config::FeatureSet;
 
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("auth-only-app");
 
-    let config = ProjectConfig {
-        project_name: "auth-only-app".to_string(),
-        features: FeatureSet {
-            authentication: true,
+    for (mode, main_path) in [
+        (ProjectMode::Single, "src/main.rs"),
+        (ProjectMode::Workspace, "api/src/main.rs"),
+    ] {
+        let name = format!("openapi-auth-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            features: FeatureSet {
+                authentication: true,
+                openapi: true,
+                ..Default::default()
+            },
             ..Default::default()
-        },
-        ..Default::default()
-    };
-
-    generate_project(&project_dir, &config, false, false).unwrap();
-
-    // Run cargo check to verify it compiles
-    let output = Command::new("cargo")
-        .arg("check")
-        .arg("--manifest-path")
-        .arg(project_dir.join("Cargo.toml"))
-        .output()
-        .expect("Failed to run cargo check");
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains("modifiers(&SecurityAddon)"));
+        assert!(main_rs.contains(
+            r#"add_security_scheme(
+            "bearer_auth","#
+        ));
+        assert!(main_rs.contains(".scheme(HttpAuthScheme::Bearer)"));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
 
-    if !output.status.success() {
-        eprintln!(
-            "cargo check stderr: {}",
+        assert!(
+            output.status.success(),
+            "{} project with OpenAPI and auth failed to compile:\n{}",
+            mode,
             String::from_utf8_lossy(&output.stderr)
         );
     }
-
-    assert!(
-        output.status.success(),
-        "Auth-only generated project failed to compile"
-    );
 }
 
-/// Test: --force flag overwrites existing directory
+/// Integration test - --with-env writes a runnable .env next to .env.example, never committed
 #[test]
-fn test_force_overwrite() {
+fn test_env_file_generated() {
+    use axum_app_create::config::OverwritePolicy;
+
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("force-test-app");
 
-    let config = ProjectConfig {
-        project_name: "force-test-app".to_string(),
+    // Without --with-env only .env.example is written
+    let plain_dir = temp_dir.path().join("plain-app");
+    let plain = ProjectConfig {
+        project_name: "plain-app".to_string(),
+        no_update: true,
+        ..Default::default()
+    };
+    generate_project(&plain_dir, &plain, false, false, false).unwrap();
+    assert!(plain_dir.join(".env.example").exists());
+    assert!(!plain_dir.join(".env").exists());
+
+    let project_dir = temp_dir.path().join("env-app");
+    let mut config = ProjectConfig {
+        project_name: "env-app".to_string(),
+        no_update: true,
+        with_env: true,
         ..Default::default()
     };
 
-    // Generate first time
-    generate_project(&project_dir, &config, false, false).unwrap();
-    assert!(project_dir.exists());
+    generate_project(&project_dir, &config, false, false, false).unwrap();
 
-    // Generate again with force=true should succeed
-    let result = generate_project(&project_dir, &config, false, true);
-    assert!(result.is_ok(), "Force overwrite failed: {:?}", result.err());
-    assert!(project_dir.join("Cargo.toml").exists());
+    let env = std::fs::read_to_string(project_dir.join(".env")).unwrap();
+    assert!(env.contains("PORT=8080"));
+    assert!(project_dir.join(".env.example").exists());
+    let gitignore = std::fs::read_to_string(project_dir.join(".gitignore")).unwrap();
+    assert!(gitignore.lines().any(|line| line.trim() == ".env"));
+
+    // Regenerating into the directory keeps a user's edited .env
+    std::fs::write(project_dir.join(".env"), "PORT=9000\n").unwrap();
+    config.overwrite_policy = Some(OverwritePolicy::Merge);
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(project_dir.join(".env")).unwrap(),
+        "PORT=9000\n"
+    );
 }
 
-/// Test: existing directory without force in non-interactive mode should fail
+/// Test that the generated .env points DATABASE_URL at the selected database
 #[test]
-fn test_existing_dir_no_force_fails() {
+fn test_env_database_url_per_database() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("noforce-test-app");
 
-    let config = ProjectConfig {
-        project_name: "noforce-test-app".to_string(),
-        ..Default::default()
-    };
+    for mode in [ProjectMode::Single, ProjectMode::Workspace] {
+        for (suffix, database, expected) in [
+            ("pg", DatabaseOption::PostgreSQL, "postgresql://"),
+            ("sqlite", DatabaseOption::SQLite, "sqlite://"),
+            ("both", DatabaseOption::Both, "postgresql://"),
+        ] {
+            let name = format!("url-{}-{}", suffix, mode);
+            let project_dir = temp_dir.path().join(&name);
+            let config = ProjectConfig {
+                project_name: name.clone(),
+                mode,
+                features: FeatureSet {
+                    database,
+                    ..Default::default()
+                },
+                with_env: true,
+                ..Default::default()
+            };
+
+            generate_project(&project_dir, &config, false, false, false).unwrap();
+
+            let env = std::fs::read_to_string(project_dir.join(".env")).unwrap();
+            let urls: Vec<&str> = env
+                .lines()
+                .filter_map(|line| line.strip_prefix("DATABASE_URL="))
+                .collect();
+            assert_eq!(urls.len(), 1, "{}:\n{}", name, env);
+            assert!(urls[0].starts_with(expected), "{}:\n{}", name, env);
+        }
+    }
+}
 
-    // Generate first time
-    generate_project(&project_dir, &config, false, false).unwrap();
+/// Test that .env.example only has section headers for enabled features
+#[test]
+fn test_env_example_sections() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
 
-    // Generate again without force should fail
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(
-        result.is_err(),
-        "Should fail when directory exists without --force"
-    );
+    let temp_dir = TempDir::new().unwrap();
+
+    for mode in [ProjectMode::Single, ProjectMode::Workspace] {
+        for (suffix, database, authentication) in [
+            ("plain", DatabaseOption::None, false),
+            ("db", DatabaseOption::PostgreSQL, false),
+            ("auth", DatabaseOption::None, true),
+        ] {
+            let name = format!("env-{}-{}", suffix, mode);
+            let project_dir = temp_dir.path().join(&name);
+            let config = ProjectConfig {
+                project_name: name.clone(),
+                mode,
+                features: FeatureSet {
+                    database,
+                    authentication,
+                    logging: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            generate_project(&project_dir, &config, false, false, false).unwrap();
+
+            let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
+            let headers: Vec<&str> = env_example
+                .lines()
+                .filter(|line| matches!(*line, "# Server" | "# Database" | "# Auth" | "# Logging"))
+                .collect();
+
+            let mut expected = vec!["# Server"];
+            if suffix == "db" {
+                expected.push("# Database");
+            }
+            if suffix == "auth" {
+                expected.push("# Auth");
+            }
+            expected.push("# Logging");
+            assert_eq!(headers, expected, "{}:\n{}", name, env_example);
+        }
+    }
 }
 
-/// Test: project with ALL features enabled compiles with `cargo check`
-/// This is the critical integration test that catches template type mismatches
+/// Test that parallel writing creates every rendered file with its content
 #[test]
-fn test_all_features_project_compiles() {
+fn test_parallel_generation_writes_all_files() {
     use axum_app_create::config::{DatabaseOption, FeatureSet};
+    use axum_app_create::updater::metadata::MetadataManager;
 
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("all-features-app");
-
+    let project_dir = temp_dir.path().join("parallel-app");
     let config = ProjectConfig {
-        project_name: "all-features-app".to_string(),
+        project_name: "parallel-app".to_string(),
+        mode: ProjectMode::Workspace,
         features: FeatureSet {
-            database: DatabaseOption::Both,
+            database: DatabaseOption::PostgreSQL,
             authentication: true,
             logging: true,
-            biz_error: true,
+            openapi: true,
+            ..Default::default()
         },
+        entities: vec!["User".to_string(), "BlogPost".to_string()],
         ..Default::default()
     };
 
-    generate_project(&project_dir, &config, false, false).unwrap();
+    let report = generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    // Every rendered file is on disk with the content the dry run reports
+    let dry_run = generate_project(
+        &temp_dir.path().join("dry-run"),
+        &config,
+        false,
+        false,
+        true,
+    )
+    .unwrap();
+    assert_eq!(report.files_created, dry_run.files_created);
+    for path in &report.files_created {
+        let content = std::fs::read_to_string(project_dir.join(path))
+            .unwrap_or_else(|e| panic!("{} was not written: {}", path, e));
+        assert!(!content.trim().is_empty(), "{} is empty", path);
+    }
+    for path in [
+        "Cargo.toml",
+        "api/src/main.rs",
+        "domain/src/entities/user.rs",
+        "domain/src/entities/blog_post.rs",
+        "infrastructure/Cargo.toml",
+    ] {
+        assert!(report.files_created.iter().any(|p| p == path), "{}", path);
+    }
 
-    // Verify biz-error dependency and build.rs in Cargo.toml
-    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
-    assert!(
-        cargo_toml.contains("biz-error"),
-        "Cargo.toml should contain biz-error dependency"
-    );
-    assert!(
-        cargo_toml.contains("[build-dependencies]"),
-        "Cargo.toml should have [build-dependencies] section"
-    );
+    // The created-file report is sorted regardless of write order
+    let mut sorted = report.files_created.clone();
+    sorted.sort();
+    assert_eq!(report.files_created, sorted);
 
-    // Verify build.rs exists
-    assert!(
-        project_dir.join("build.rs").exists(),
-        "build.rs should be generated for biz-error codegen"
-    );
+    // Metadata has a checksum for every written file
+    let metadata = MetadataManager::read(&project_dir).unwrap();
+    assert_eq!(metadata.file_checksums.len(), report.files_created.len());
+}
 
-    // Run cargo check to verify it compiles
-    let output = Command::new("cargo")
-        .arg("check")
-        .arg("--manifest-path")
-        .arg(project_dir.join("Cargo.toml"))
-        .output()
-        .expect("Failed to run cargo check");
+/// Test that two runs print the same "Created" list, in path order
+#[test]
+fn test_created_list_is_deterministic() {
+    let temp_dir = TempDir::new().unwrap();
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        eprintln!("cargo check stderr:\n{}", stderr);
-    }
+    let created = |name: &str| -> Vec<String> {
+        let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+            .args([name, "--mode", "workspace", "--preset", "fullstack"])
+            .args(["--entities", "User,BlogPost", "--api-versions", "v1,v2"])
+            .args(["--ci", "--non-interactive", "--no-git", "--no-update"])
+            .args(["--locale", "en"])
+            .current_dir(temp_dir.path())
+            .output()
+            .expect("Failed to run axum-app-create");
+        assert!(
+            output.status.success(),
+            "generation failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
 
-    assert!(
-        output.status.success(),
-        "All-features generated project failed to compile"
-    );
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("✓ Created "))
+            .map(str::to_string)
+            .collect()
+    };
+
+    let first = created("order-app");
+    std::fs::remove_dir_all(temp_dir.path().join("order-app")).unwrap();
+    let second = created("order-app");
+
+    assert!(first.len() > 20, "{:?}", first);
+    assert_eq!(first, second);
+    let mut sorted = first.clone();
+    sorted.sort();
+    assert_eq!(first, sorted);
 }
 
-/// Test: project with database + auth compiles (the specific combo that was broken)
+/// Test that templates marked executable are written with the execute bit
+#[cfg(unix)]
 #[test]
-fn test_database_auth_project_compiles() {
+fn test_executable_scripts() {
     use axum_app_create::config::{DatabaseOption, FeatureSet};
+    use std::os::unix::fs::PermissionsExt;
 
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("db-auth-app");
-
+    let project_dir = temp_dir.path().join("exec-app");
     let config = ProjectConfig {
-        project_name: "db-auth-app".to_string(),
+        project_name: "exec-app".to_string(),
         features: FeatureSet {
             database: DatabaseOption::PostgreSQL,
-            authentication: true,
-            logging: true,
             ..Default::default()
         },
         ..Default::default()
     };
 
-    generate_project(&project_dir, &config, false, false).unwrap();
-
-    // Run cargo check
-    let output = Command::new("cargo")
-        .arg("check")
-        .arg("--manifest-path")
-        .arg(project_dir.join("Cargo.toml"))
-        .output()
-        .expect("Failed to run cargo check");
+    generate_project(&project_dir, &config, false, false, false).unwrap();
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        eprintln!("cargo check stderr:\n{}", stderr);
-    }
+    let mode = |path: &str| {
+        std::fs::metadata(project_dir.join(path))
+            .unwrap()
+            .permissions()
+            .mode()
+    };
+    assert_eq!(mode("scripts/dev.sh") & 0o111, 0o111);
+    assert_eq!(mode("docker-entrypoint.sh") & 0o111, 0o111);
+    assert_eq!(mode("Cargo.toml") & 0o111, 0);
 
-    assert!(
-        output.status.success(),
-        "Database+Auth generated project failed to compile"
-    );
+    let dev_sh = std::fs::read_to_string(project_dir.join("scripts/dev.sh")).unwrap();
+    assert!(dev_sh.starts_with("#!/bin/sh"));
+    assert!(dev_sh.contains("cargo run --bin exec-app"));
 }
 
-// ============================================================
-// v0.2.0 Integration Tests
-// ============================================================
-
-/// Test workspace mode generates correct structure
+/// Integration test - generate project with a CORS layer
 #[test]
-fn test_workspace_mode_basic_structure() {
+fn test_cors_feature() {
+    use axum_app_create::config::FeatureSet;
+
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("ws-app");
 
-    let config = ProjectConfig {
-        project_name: "ws-app".to_string(),
-        mode: ProjectMode::Workspace,
-        ..Default::default()
-    };
+    for (mode, cargo_path, main_path, normalize_path) in [
+        (ProjectMode::Single, "Cargo.toml", "src/main.rs", false),
+        (
+            ProjectMode::Workspace,
+            "api/Cargo.toml",
+            "api/src/main.rs",
+            true,
+        ),
+    ] {
+        let name = format!("cors-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            features: FeatureSet {
+                cors: true,
+                ..Default::default()
+            },
+            normalize_path,
+            ..Default::default()
+        };
 
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(
-        result.is_ok(),
-        "Workspace generation failed: {:?}",
-        result.err()
-    );
+        generate_project(&project_dir, &config, false, false, false).unwrap();
 
-    // Verify root Cargo.toml has [workspace]
-    let cargo_content = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
-    assert!(
-        cargo_content.contains("[workspace]"),
-        "Root Cargo.toml missing [workspace]"
-    );
-    assert!(
-        cargo_content.contains("members"),
-        "Root Cargo.toml missing members"
-    );
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains("CorsLayer"));
+        assert!(main_rs.contains("let app = app.layer(cors_layer());"));
+        assert!(main_rs.contains("CORS_ALLOWED_ORIGINS"));
+
+        let cargo_toml = std::fs::read_to_string(project_dir.join(cargo_path)).unwrap();
+        assert!(cargo_toml.contains(r#""cors""#));
+        assert_eq!(cargo_toml.contains(r#""normalize-path""#), normalize_path);
+
+        let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
+        assert!(env_example.contains("CORS_ALLOWED_ORIGINS"));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
 
-    // Verify all 4 sub-crate directories exist
-    for crate_name in &["api", "domain", "infrastructure", "common"] {
         assert!(
-            project_dir.join(crate_name).exists(),
-            "Missing crate dir: {}",
-            crate_name
+            output.status.success(),
+            "{} project with CORS failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
         );
+    }
+}
+
+/// Integration test - generate project with response compression
+#[test]
+fn test_compression_feature() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, cargo_path, main_path) in [
+        (ProjectMode::Single, "Cargo.toml", "src/main.rs"),
+        (ProjectMode::Workspace, "api/Cargo.toml", "api/src/main.rs"),
+    ] {
+        let name = format!("compression-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            features: FeatureSet {
+                compression: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains("use tower_http::compression::CompressionLayer;"));
+        assert!(main_rs.contains("let app = app.layer(CompressionLayer::new());"));
+
+        let cargo_toml = std::fs::read_to_string(project_dir.join(cargo_path)).unwrap();
+        assert!(cargo_toml.contains(r#""compression-gzip", "compression-br""#));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
         assert!(
-            project_dir
-                .join(format!("{}/Cargo.toml", crate_name))
-                .exists(),
-            "Missing {}/Cargo.toml",
-            crate_name
+            output.status.success(),
+            "{} project with compression failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
         );
+    }
+}
+
+/// Integration test - generate project with a Prometheus /metrics endpoint
+#[test]
+fn test_metrics_feature() {
+    use axum_app_create::config::{FeatureSet, Preset};
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, cargo_path, main_path, base) in [
+        (
+            ProjectMode::Single,
+            "Cargo.toml",
+            "src/main.rs",
+            FeatureSet::default(),
+        ),
+        (
+            ProjectMode::Workspace,
+            "api/Cargo.toml",
+            "api/src/main.rs",
+            Preset::Fullstack.to_feature_set(),
+        ),
+    ] {
+        let name = format!("metrics-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            features: FeatureSet {
+                metrics: true,
+                ..base
+            },
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains("PrometheusMetricLayer::pair()"));
+        assert!(main_rs.contains(r#".route("/metrics", get("#));
+        assert!(main_rs.contains(".layer(metrics_layer)"));
+
+        let cargo_toml = std::fs::read_to_string(project_dir.join(cargo_path)).unwrap();
+        assert!(cargo_toml.contains("axum-prometheus = "));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
         assert!(
-            project_dir
-                .join(format!("{}/src/lib.rs", crate_name))
-                .exists(),
-            "Missing {}/src/lib.rs",
-            crate_name
+            output.status.success(),
+            "{} project with metrics failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
         );
     }
+}
 
-    // Verify api crate has main.rs (binary crate)
-    assert!(
-        project_dir.join("api/src/main.rs").exists(),
-        "Missing api/src/main.rs"
-    );
+/// Integration test - logging adds request ID and trace layers to the router
+#[test]
+fn test_request_id_layers() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, cargo_path, main_path) in [
+        (ProjectMode::Single, "Cargo.toml", "src/main.rs"),
+        (ProjectMode::Workspace, "api/Cargo.toml", "api/src/main.rs"),
+    ] {
+        for logging in [true, false] {
+            let name = format!("request-id-{}-{}", mode, logging);
+            let project_dir = temp_dir.path().join(&name);
+            let config = ProjectConfig {
+                project_name: name.clone(),
+                mode,
+                features: FeatureSet {
+                    logging,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            generate_project(&project_dir, &config, false, false, false).unwrap();
+
+            let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+            let cargo_toml = std::fs::read_to_string(project_dir.join(cargo_path)).unwrap();
+            assert_eq!(main_rs.contains("SetRequestIdLayer::new"), logging);
+            assert_eq!(main_rs.contains("PropagateRequestIdLayer::new"), logging);
+            assert_eq!(
+                main_rs.contains("make_span_with(make_request_span)"),
+                logging
+            );
+            assert_eq!(cargo_toml.contains(r#""request-id", "trace""#), logging);
+        }
+    }
 }
 
-/// Test workspace mode + database feature
+/// Integration test - --log-format selects the tracing formatter
 #[test]
-fn test_workspace_mode_with_database() {
+fn test_log_format_json() {
+    use axum_app_create::config::{FeatureSet, LoggingConfig};
+
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("ws-db-app");
+
+    for (mode, cargo_path, main_path) in [
+        (ProjectMode::Single, "Cargo.toml", "src/main.rs"),
+        (ProjectMode::Workspace, "api/Cargo.toml", "api/src/main.rs"),
+    ] {
+        let name = format!("json-log-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            features: FeatureSet {
+                logging: true,
+                ..Default::default()
+            },
+            logging: Some(LoggingConfig {
+                format: "json".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains(".json()"));
+        assert!(!main_rs.contains(".pretty()"));
+        let cargo_toml = std::fs::read_to_string(project_dir.join(cargo_path)).unwrap();
+        assert!(cargo_toml.contains(r#"features = ["env-filter", "json"]"#));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        assert!(
+            output.status.success(),
+            "{} project with JSON logs failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    // `pretty` needs no extra feature, `compact` is the plain formatter
+    for (format, expected) in [("pretty", Some(".pretty()")), ("compact", None)] {
+        let name = format!("{}-log", format);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name,
+            features: FeatureSet {
+                logging: true,
+                ..Default::default()
+            },
+            logging: Some(LoggingConfig {
+                format: format.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let main_rs = std::fs::read_to_string(project_dir.join("src/main.rs")).unwrap();
+        assert_eq!(main_rs.contains(".pretty()"), expected.is_some());
+        assert!(!main_rs.contains(".json()"));
+        let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+        assert!(cargo_toml.contains(r#"features = ["env-filter"]"#));
+    }
+}
+
+/// T061: Integration test - generate project with authentication feature
+#[test]
+fn test_auth_feature() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("auth-test-app");
 
     let config = ProjectConfig {
-        project_name: "ws-db-app".to_string(),
-        mode: ProjectMode::Workspace,
-        features: axum_app_create::config::FeatureSet {
-            database: axum_app_create::config::DatabaseOption::PostgreSQL,
+        project_name: "auth-test-app".to_string(),
+        features: FeatureSet {
+            authentication: true,
             ..Default::default()
         },
-        database: Some(axum_app_create::config::DatabaseConfig::default()),
         ..Default::default()
     };
 
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+    generate_project(&project_dir, &config, false, false, false).unwrap();
 
-    assert!(
-        project_dir.join("infrastructure/src/db.rs").exists(),
-        "Missing infrastructure/src/db.rs"
-    );
+    // Verify auth handler exists
+    assert!(project_dir.join("src/handlers/auth.rs").exists());
+
+    // Verify Cargo.toml contains auth dependencies
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("jsonwebtoken"));
+    assert!(cargo_toml.contains("bcrypt"));
+
+    // Verify .env.example contains JWT_SECRET
+    let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
+    assert!(env_example.contains("JWT_SECRET"));
 }
 
-/// Test workspace mode + auth feature
+/// Test: --api-routes nests auth routes under the API prefix, health stays at root
 #[test]
-fn test_workspace_mode_with_auth() {
+fn test_api_routes_nest_auth_under_prefix() {
+    use axum_app_create::config::FeatureSet;
+
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("ws-auth-app");
+    let project_dir = temp_dir.path().join("api-routes-app");
 
     let config = ProjectConfig {
-        project_name: "ws-auth-app".to_string(),
-        mode: ProjectMode::Workspace,
-        features: axum_app_create::config::FeatureSet {
+        project_name: "api-routes-app".to_string(),
+        features: FeatureSet {
             authentication: true,
+            logging: true,
             ..Default::default()
         },
-        authentication: Some(axum_app_create::config::AuthConfig::default()),
+        api_prefix: "/api".to_string(),
+        api_routes: vec!["auth".to_string()],
         ..Default::default()
     };
 
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let main_rs = std::fs::read_to_string(project_dir.join("src/main.rs")).unwrap();
+    assert!(main_rs.contains(".nest(\"/api\", auth::router())"));
+    assert!(main_rs.contains(".merge(health::router())"));
+    assert!(!main_rs.contains(".merge(auth::router())"));
+
+    let readme = std::fs::read_to_string(project_dir.join("README.md")).unwrap();
+    assert!(readme.contains("8080/api/auth/login"));
+
+    let output = Command::new("cargo")
+        .arg("check")
+        .arg("--manifest-path")
+        .arg(project_dir.join("Cargo.toml"))
+        .output()
+        .expect("Failed to run cargo check");
+
+    if !output.status.success() {
+        eprintln!(
+            "cargo check stderr:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
 
     assert!(
-        project_dir.join("api/src/handlers/auth.rs").exists(),
-        "Missing api/src/handlers/auth.rs"
-    );
-    assert!(
-        project_dir.join("api/src/middleware/mod.rs").exists(),
-        "Missing api/src/middleware/mod.rs"
+        output.status.success(),
+        "Project with nested API routes failed to compile"
     );
 }
 
-/// Test workspace mode + biz-error feature
+/// Test: db+auth config loader validates DATABASE_URL and JWT_SECRET together
 #[test]
-fn test_workspace_mode_with_biz_error() {
+fn test_config_reports_all_missing_env_vars() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("ws-biz-app");
+    let project_dir = temp_dir.path().join("env-check-app");
 
     let config = ProjectConfig {
-        project_name: "ws-biz-app".to_string(),
-        mode: ProjectMode::Workspace,
-        features: axum_app_create::config::FeatureSet {
-            biz_error: true,
+        project_name: "env-check-app".to_string(),
+        features: FeatureSet {
+            database: DatabaseOption::PostgreSQL,
+            authentication: true,
+            logging: true,
             ..Default::default()
         },
-        biz_error: Some(axum_app_create::config::BizErrorConfig::default()),
         ..Default::default()
     };
 
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let config_rs = std::fs::read_to_string(project_dir.join("src/config.rs")).unwrap();
+    let required = config_rs
+        .split("const REQUIRED_ENV_VARS")
+        .nth(1)
+        .and_then(|rest| rest.split("];").next())
+        .expect("config.rs should define REQUIRED_ENV_VARS");
+    assert!(required.contains("\"DATABASE_URL\""));
+    assert!(required.contains("\"JWT_SECRET\""));
+    assert!(config_rs.contains("check_required_env()?"));
+
+    let output = Command::new("cargo")
+        .arg("check")
+        .arg("--manifest-path")
+        .arg(project_dir.join("Cargo.toml"))
+        .output()
+        .expect("Failed to run cargo check");
+
+    if !output.status.success() {
+        eprintln!(
+            "cargo check stderr:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
 
     assert!(
-        project_dir.join("common/src/error.rs").exists(),
-        "Missing common/src/error.rs"
+        output.status.success(),
+        "Project with env validation failed to compile"
     );
 }
 
-/// Test preset minimal generates no optional features
+/// Test: --normalize-path wraps the router in NormalizePathLayer and compiles
 #[test]
-fn test_preset_minimal() {
+fn test_normalize_path_layer_compiles() {
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("minimal-app");
 
-    let features = Preset::Minimal.to_feature_set();
+    for (mode, main_path) in [
+        (ProjectMode::Single, "src/main.rs"),
+        (ProjectMode::Workspace, "api/src/main.rs"),
+    ] {
+        let project_dir = temp_dir.path().join(format!("normalize-{}", mode));
+
+        let config = ProjectConfig {
+            project_name: format!("normalize-{}", mode),
+            mode,
+            normalize_path: true,
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains("NormalizePathLayer::trim_trailing_slash()"));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        if !output.status.success() {
+            eprintln!(
+                "cargo check stderr:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        assert!(
+            output.status.success(),
+            "{} project with NormalizePathLayer failed to compile",
+            mode
+        );
+    }
+}
+
+/// Test: --startup-banner prints name, version and port and compiles in both modes
+#[test]
+fn test_startup_banner_compiles() {
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, main_path) in [
+        (ProjectMode::Single, "src/main.rs"),
+        (ProjectMode::Workspace, "api/src/main.rs"),
+    ] {
+        let project_dir = temp_dir.path().join(format!("banner-{}", mode));
+
+        let config = ProjectConfig {
+            project_name: format!("banner-{}", mode),
+            mode,
+            startup_banner: true,
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains("print_banner(&config);"));
+        assert!(main_rs.contains(&format!("\"banner-{} v{{}}\"", mode)));
+        assert!(main_rs.contains("config.port"));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        if !output.status.success() {
+            eprintln!(
+                "cargo check stderr:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        assert!(
+            output.status.success(),
+            "{} project with startup banner failed to compile",
+            mode
+        );
+    }
+}
+
+/// Test: sonic-rs is only added for --json-backend simd; the default serde_json path compiles
+#[test]
+fn test_json_backend_dependency() {
+    use axum_app_create::config::JsonBackend;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, crate_dir) in [(ProjectMode::Single, ""), (ProjectMode::Workspace, "api")] {
+        let generate = |backend: JsonBackend| {
+            let name = format!("json-{}-{}", backend, mode);
+            let project_dir = temp_dir.path().join(&name);
+            let config = ProjectConfig {
+                project_name: name,
+                mode,
+                json_backend: backend,
+                ..Default::default()
+            };
+            generate_project(&project_dir, &config, false, false, false).unwrap();
+            project_dir
+        };
+
+        let default_dir = generate(JsonBackend::Serde);
+        let cargo =
+            std::fs::read_to_string(default_dir.join(crate_dir).join("Cargo.toml")).unwrap();
+        assert!(!cargo.contains("sonic-rs"));
+
+        let simd_dir = generate(JsonBackend::Simd);
+        let cargo = std::fs::read_to_string(simd_dir.join(crate_dir).join("Cargo.toml")).unwrap();
+        assert!(cargo.contains("sonic-rs"));
+        let health =
+            std::fs::read_to_string(simd_dir.join(crate_dir).join("src/handlers/health.rs"))
+                .unwrap();
+        assert!(health.contains("sonic_rs::to_vec"));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(default_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        if !output.status.success() {
+            eprintln!(
+                "cargo check stderr:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        assert!(
+            output.status.success(),
+            "{} project with default JSON backend failed to compile",
+            mode
+        );
+    }
+}
+
+/// Test: with a database the health handler pings the pool and reports degraded on failure
+#[test]
+fn test_health_database_probe() {
+    use axum_app_create::config::{DatabaseConfig, DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("health-db-app");
     let config = ProjectConfig {
-        project_name: "minimal-app".to_string(),
-        features,
-        preset: Some(Preset::Minimal),
+        project_name: "health-db-app".to_string(),
+        features: FeatureSet {
+            database: DatabaseOption::PostgreSQL,
+            ..Default::default()
+        },
+        database: Some(DatabaseConfig {
+            option: DatabaseOption::PostgreSQL,
+            ..Default::default()
+        }),
         ..Default::default()
     };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let health = std::fs::read_to_string(project_dir.join("src/handlers/health.rs")).unwrap();
+    assert!(health.contains("pub db: Option<crate::db::DbPool>"));
+    assert!(health.contains("crate::db::ping(db).await"));
+    assert!(health.contains("StatusCode::SERVICE_UNAVAILABLE, \"degraded\""));
+    assert!(health.contains("pub fn router_with_database(db: crate::db::DbPool)"));
+    let db = std::fs::read_to_string(project_dir.join("src/db.rs")).unwrap();
+    assert!(db.contains("sqlx::query(\"SELECT 1\")"));
+
+    // Without a database the handler stays a static response
+    let plain_dir = temp_dir.path().join("health-app");
+    let plain = ProjectConfig {
+        project_name: "health-app".to_string(),
+        ..Default::default()
+    };
+    generate_project(&plain_dir, &plain, false, false, false).unwrap();
+    let health = std::fs::read_to_string(plain_dir.join("src/handlers/health.rs")).unwrap();
+    assert!(!health.contains("database"));
+    assert!(health.contains("-> ResponseJson<HealthResponse>"));
+}
 
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+/// Test: the post-generation hook runs in the project directory; a failing hook is an error
+#[cfg(unix)]
+#[test]
+fn test_post_generate_hook() {
+    use axum_app_create::error::CliError;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("hook-app");
+    let mut config = ProjectConfig {
+        project_name: "hook-app".to_string(),
+        no_update: true,
+        post_generate_hook: Some("touch HOOK_RAN".to_string()),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert!(project_dir.join("HOOK_RAN").exists());
+
+    // The generated project is kept so the hook can be re-run by hand
+    let failing_dir = temp_dir.path().join("failing-hook-app");
+    config.post_generate_hook = Some("exit 3".to_string());
+    let result = generate_project(&failing_dir, &config, false, false, false);
+    assert!(matches!(result, Err(CliError::Generation(_))));
+    assert!(failing_dir.join("Cargo.toml").exists());
+}
+
+/// Test: --jwt-algorithm and --jwt-expiration flow into the env files and config defaults
+#[test]
+fn test_jwt_algorithm_and_expiration() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args(["jwt-app", "--auth", "--jwt-algorithm", "HS512"])
+        .args(["--jwt-expiration", "3600", "--non-interactive", "--no-git"])
+        .args(["--no-update", "--with-env", "--quiet"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(
+        output.status.success(),
+        "generation failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let project_dir = temp_dir.path().join("jwt-app");
+    for env_file in [".env", ".env.example"] {
+        let env = std::fs::read_to_string(project_dir.join(env_file)).unwrap();
+        assert!(env.contains("JWT_ALGORITHM=HS512\n"), "{}", env_file);
+        assert!(
+            env.contains("JWT_EXPIRATION_SECONDS=3600\n"),
+            "{}",
+            env_file
+        );
+    }
+    let config = std::fs::read_to_string(project_dir.join("src/config.rs")).unwrap();
+    assert!(config.contains(r#".unwrap_or_else(|_| "HS512".to_string())"#));
+
+    // Unknown algorithms are rejected before anything is written
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args([
+            "rsa-app",
+            "--auth",
+            "--jwt-algorithm",
+            "RS256",
+            "--non-interactive",
+        ])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unsupported JWT algorithm"));
+    assert!(!temp_dir.path().join("rsa-app").exists());
+}
+
+/// Test: --from-file with auth builds the JWT config, applies other flags and compiles
+#[test]
+fn test_from_file_auth_manifest_compiles() {
+    let temp_dir = TempDir::new().unwrap();
+    let manifest = temp_dir.path().join("axum-app.toml");
+    std::fs::write(
+        &manifest,
+        r#"project_name = "manifest-app"
+
+[features]
+database = "None"
+authentication = true
+logging = true
+biz_error = false
+"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .arg("--from-file")
+        .arg(&manifest)
+        .args(["--jwt-algorithm", "HS512", "--cors", "--port", "3000"])
+        .args(["--non-interactive", "--no-git", "--no-update", "--quiet"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(
+        output.status.success(),
+        "generation failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let project_dir = temp_dir.path().join("manifest-app");
+    let config = std::fs::read_to_string(project_dir.join("src/config.rs")).unwrap();
+    assert!(config.contains(r#".unwrap_or_else(|_| "HS512".to_string())"#));
+    let metadata = std::fs::read_to_string(project_dir.join(".axum-app-create.json")).unwrap();
+    assert!(
+        metadata.contains("\"algorithm\": \"HS512\""),
+        "{}",
+        metadata
+    );
+    let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
+    assert!(env_example.contains("PORT=3000"));
+    assert!(env_example.contains("CORS_ALLOWED_ORIGINS"));
+
+    let output = Command::new("cargo")
+        .arg("check")
+        .arg("--manifest-path")
+        .arg(project_dir.join("Cargo.toml"))
+        .output()
+        .expect("Failed to run cargo check");
+    assert!(
+        output.status.success(),
+        "manifest project failed to compile:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Test: regen restores a deleted file and refuses to overwrite a modified one
+#[test]
+fn test_regen_restores_deleted_file() {
+    use axum_app_create::error::CliError;
+    use axum_app_create::updater::regen::regen_file;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("regen-app");
+    let config = ProjectConfig {
+        project_name: "regen-app".to_string(),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let health_path = project_dir.join("src/handlers/health.rs");
+    let original = std::fs::read_to_string(&health_path).unwrap();
+    std::fs::remove_file(&health_path).unwrap();
+
+    regen_file(&project_dir, "src/handlers/health.rs", false, false, false).unwrap();
+    assert_eq!(std::fs::read_to_string(&health_path).unwrap(), original);
+
+    // Modified files are conflicts unless forced
+    std::fs::write(&health_path, "// edited\n").unwrap();
+    let result = regen_file(&project_dir, "src/handlers/health.rs", false, false, false);
+    assert!(matches!(result, Err(CliError::Generation(_))));
+    regen_file(&project_dir, "src/handlers/health.rs", true, false, false).unwrap();
+    assert_eq!(std::fs::read_to_string(&health_path).unwrap(), original);
+
+    // Paths outside the template set are rejected
+    let result = regen_file(&project_dir, "src/unknown.rs", false, false, false);
+    assert!(matches!(result, Err(CliError::ValidationError(_))));
+}
+
+/// Test: the metadata records the generating tool version and regen reports it
+#[test]
+fn test_metadata_records_generator_version() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("versioned-app");
+    let config = ProjectConfig {
+        project_name: "versioned-app".to_string(),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let json = std::fs::read_to_string(project_dir.join(".axum-app-create.json")).unwrap();
+    let metadata: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(metadata["version"], env!("CARGO_PKG_VERSION"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args(["regen", "--manifest-only", "--project-dir"])
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let expected = format!(
+        "generated by v{0}, updating with v{0}",
+        env!("CARGO_PKG_VERSION")
+    );
+    assert!(stdout.contains(&expected), "{}", stdout);
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("older"));
+}
+
+/// Test: regen --verbose prints a unified diff of the files it changes
+#[test]
+fn test_regen_verbose_prints_diff() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("verbose-app");
+    let config = ProjectConfig {
+        project_name: "verbose-app".to_string(),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    std::fs::write(project_dir.join("src/handlers/health.rs"), "// edited\n").unwrap();
+
+    let regen = |verbose: bool| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_axum-app-create"));
+        command
+            .args([
+                "regen",
+                "src/handlers/health.rs",
+                "--force",
+                "--project-dir",
+            ])
+            .arg(&project_dir);
+        if verbose {
+            command.arg("--verbose");
+        }
+        let output = command.output().expect("Failed to run axum-app-create");
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let stdout = regen(true);
+    assert!(
+        stdout.contains("--- a/src/handlers/health.rs"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("@@"), "{}", stdout);
+    assert!(stdout.contains("-// edited"), "{}", stdout);
+
+    // Unchanged files have no diff, and without --verbose none is printed
+    assert!(!regen(true).contains("@@"));
+    std::fs::write(project_dir.join("src/handlers/health.rs"), "// edited\n").unwrap();
+    assert!(!regen(false).contains("@@"));
+}
+
+/// Test: --backup copies a force-overwritten file to <path>.bak first
+#[test]
+fn test_regen_backup() {
+    use axum_app_create::updater::regen::{RegenReport, regen_file};
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("backup-app");
+    let config = ProjectConfig {
+        project_name: "backup-app".to_string(),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let health_path = project_dir.join("src/handlers/health.rs");
+    let original = std::fs::read_to_string(&health_path).unwrap();
+    std::fs::write(&health_path, "// edited\n").unwrap();
+
+    let file = regen_file(&project_dir, "src/handlers/health.rs", true, false, true).unwrap();
+    let report = RegenReport::new(vec![file]);
+    assert_eq!(report.files_backed_up, ["src/handlers/health.rs.bak"]);
+    assert_eq!(
+        std::fs::read_to_string(project_dir.join("src/handlers/health.rs.bak")).unwrap(),
+        "// edited\n"
+    );
+    assert_eq!(std::fs::read_to_string(&health_path).unwrap(), original);
+
+    // Nothing is backed up when the content doesn't change
+    let file = regen_file(&project_dir, "src/handlers/health.rs", true, false, true).unwrap();
+    assert_eq!(file.backup, None);
+}
+
+/// Test: --merge three-way merges a modified main.rs, marking conflicting edits
+#[test]
+fn test_regen_merge_modified_file() {
+    use axum_app_create::error::CliError;
+    use axum_app_create::updater::metadata::METADATA_FILE;
+    use axum_app_create::updater::regen::{FileClassification, regen_file};
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("merge-app");
+    let config = ProjectConfig {
+        project_name: "merge-app".to_string(),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    // Add a route where enabling SSE will add one too
+    let main_path = project_dir.join("src/main.rs");
+    let original = std::fs::read_to_string(&main_path).unwrap();
+    let edited = original.replace(
+        ".merge(health::router())\n",
+        ".merge(health::router())\n        .merge(users::router())\n",
+    );
+    assert_ne!(edited, original);
+    std::fs::write(&main_path, &edited).unwrap();
+
+    let metadata_path = project_dir.join(METADATA_FILE);
+    let mut metadata: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&metadata_path).unwrap()).unwrap();
+    metadata["config"]["features"]["sse"] = true.into();
+    std::fs::write(&metadata_path, metadata.to_string()).unwrap();
+
+    let classification = regen_file(&project_dir, "src/main.rs", false, true, false)
+        .unwrap()
+        .classification;
+    assert_eq!(
+        classification,
+        FileClassification::Merged { conflicts: true }
+    );
+    let merged = std::fs::read_to_string(&main_path).unwrap();
+    assert!(merged.contains("<<<<<<< ours"), "{}", merged);
+    assert!(merged.contains("======="), "{}", merged);
+    assert!(merged.contains(">>>>>>> theirs"), "{}", merged);
+    assert!(merged.contains(".merge(users::router())"), "{}", merged);
+    assert!(merged.contains(".merge(events::router())"), "{}", merged);
+    // Non-overlapping changes merge cleanly
+    assert!(merged.contains("mod events;"), "{}", merged);
+
+    // Without a snapshot of the original content, a modified file stays a conflict
+    let mut metadata: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&metadata_path).unwrap()).unwrap();
+    metadata["file_snapshots"] = serde_json::json!({});
+    std::fs::write(&metadata_path, metadata.to_string()).unwrap();
+    let result = regen_file(&project_dir, "src/main.rs", false, true, false);
+    assert!(matches!(result, Err(CliError::Generation(_))));
+}
+
+/// Test: --manifest-only refreshes Cargo.toml from changed features, leaving sources alone
+#[test]
+fn test_regen_manifest_only() {
+    use axum_app_create::error::CliError;
+    use axum_app_create::updater::metadata::METADATA_FILE;
+    use axum_app_create::updater::regen::regen_manifests;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (name, mode, manifests, api_manifest, main_path) in [
+        (
+            "manifest-single",
+            ProjectMode::Single,
+            &["Cargo.toml"][..],
+            "Cargo.toml",
+            "src/main.rs",
+        ),
+        (
+            "manifest-workspace",
+            ProjectMode::Workspace,
+            &[
+                "Cargo.toml",
+                "api/Cargo.toml",
+                "common/Cargo.toml",
+                "domain/Cargo.toml",
+                "infrastructure/Cargo.toml",
+            ][..],
+            "api/Cargo.toml",
+            "api/src/main.rs",
+        ),
+    ] {
+        let project_dir = temp_dir.path().join(name);
+        let config = ProjectConfig {
+            project_name: name.to_string(),
+            mode,
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+        let main_before = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+
+        // Enable auth in the stored configuration
+        let metadata_path = project_dir.join(METADATA_FILE);
+        let mut metadata: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&metadata_path).unwrap()).unwrap();
+        metadata["config"]["features"]["authentication"] = true.into();
+        std::fs::write(&metadata_path, metadata.to_string()).unwrap();
+
+        let mut regenerated: Vec<_> = regen_manifests(&project_dir, false, false, false)
+            .unwrap()
+            .into_iter()
+            .map(|file| file.path)
+            .collect();
+        regenerated.sort();
+        assert_eq!(regenerated, manifests);
+
+        let cargo_toml = std::fs::read_to_string(project_dir.join(api_manifest)).unwrap();
+        assert!(
+            cargo_toml.contains("jsonwebtoken"),
+            "{}: {}",
+            name,
+            cargo_toml
+        );
+        assert_eq!(
+            std::fs::read_to_string(project_dir.join(main_path)).unwrap(),
+            main_before
+        );
+    }
+
+    // A hand-edited manifest is a conflict unless forced
+    let project_dir = temp_dir.path().join("manifest-single");
+    std::fs::write(project_dir.join("Cargo.toml"), "# edited\n").unwrap();
+    let result = regen_manifests(&project_dir, false, false, false);
+    assert!(matches!(result, Err(CliError::Generation(_))));
+    regen_manifests(&project_dir, true, false, false).unwrap();
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("jsonwebtoken"));
+}
+
+/// Test: generated servers log panics through tracing and compile in both modes
+#[test]
+fn test_panic_hook_compiles() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, main_path) in [
+        (ProjectMode::Single, "src/main.rs"),
+        (ProjectMode::Workspace, "api/src/main.rs"),
+    ] {
+        let project_dir = temp_dir.path().join(format!("panic-{}", mode));
+
+        let config = ProjectConfig {
+            project_name: format!("panic-{}", mode),
+            mode,
+            features: FeatureSet {
+                logging: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains("install_panic_hook();"));
+        assert!(main_rs.contains("std::panic::set_hook"));
+        assert!(main_rs.contains("Backtrace::capture()"));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        if !output.status.success() {
+            eprintln!(
+                "cargo check stderr:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        assert!(
+            output.status.success(),
+            "{} project with panic hook failed to compile",
+            mode
+        );
+    }
+
+    // Without logging there is no subscriber, so the default stderr hook is kept
+    let project_dir = temp_dir.path().join("panic-no-logging");
+    let config = ProjectConfig {
+        project_name: "panic-no-logging".to_string(),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    let main_rs = std::fs::read_to_string(project_dir.join("src/main.rs")).unwrap();
+    assert!(!main_rs.contains("set_hook"));
+}
+
+/// Test: dry-run renders every template but leaves the target untouched
+#[test]
+fn test_dry_run_writes_nothing() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("dry-run-app");
+    let config = ProjectConfig {
+        project_name: "dry-run-app".to_string(),
+        ..Default::default()
+    };
+
+    let report = generate_project(&project_dir, &config, false, false, true).unwrap();
+
+    assert!(report.files_created.contains(&"Cargo.toml".to_string()));
+    assert!(report.files_created.contains(&"src/main.rs".to_string()));
+    // No database, so the migration template renders empty
+    assert!(
+        report
+            .skipped_empty
+            .contains(&"migrations/001_initial.sql".to_string())
+    );
+    assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+
+    // With --force an existing directory is reported, not deleted
+    std::fs::create_dir_all(&project_dir).unwrap();
+    std::fs::write(project_dir.join("keep.txt"), "keep").unwrap();
+    let forced = generate_project(&project_dir, &config, false, true, true).unwrap();
+    assert_eq!(forced, report);
+    assert_eq!(std::fs::read_dir(&project_dir).unwrap().count(), 1);
+
+    // Without --force an existing directory is still an error
+    assert!(generate_project(&project_dir, &config, false, false, true).is_err());
+}
+
+/// Test: --overwrite-policy merge keeps existing files and adds the missing ones
+#[test]
+fn test_overwrite_policy_merge() {
+    use axum_app_create::config::OverwritePolicy;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("merge-app");
+    std::fs::create_dir_all(project_dir.join("src")).unwrap();
+    std::fs::write(project_dir.join("src/main.rs"), "// my own main\n").unwrap();
+
+    let mut config = ProjectConfig {
+        project_name: "merge-app".to_string(),
+        no_update: true,
+        overwrite_policy: Some(OverwritePolicy::Merge),
+        ..Default::default()
+    };
+    let report = generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(project_dir.join("src/main.rs")).unwrap(),
+        "// my own main\n"
+    );
+    assert_eq!(report.skipped_existing, vec!["src/main.rs".to_string()]);
+    assert!(!report.files_created.contains(&"src/main.rs".to_string()));
+    assert!(report.files_created.contains(&"Cargo.toml".to_string()));
+    assert!(project_dir.join("Cargo.toml").exists());
+    assert!(project_dir.join("src/lib.rs").exists());
+
+    // `skip` leaves an existing directory untouched
+    std::fs::remove_file(project_dir.join("src/lib.rs")).unwrap();
+    config.overwrite_policy = Some(OverwritePolicy::Skip);
+    let report = generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert!(report.files_created.is_empty());
+    assert!(report.skipped_existing.contains(&"src/lib.rs".to_string()));
+    assert!(!project_dir.join("src/lib.rs").exists());
+
+    // `replace` behaves like --force
+    config.overwrite_policy = Some(OverwritePolicy::Replace);
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert!(project_dir.join("src/lib.rs").exists());
+    assert_ne!(
+        std::fs::read_to_string(project_dir.join("src/main.rs")).unwrap(),
+        "// my own main\n"
+    );
+}
+
+/// Test: --template-dir overrides built-in templates
+#[test]
+fn test_custom_template_dir_overrides_builtin() {
+    let temp_dir = TempDir::new().unwrap();
+    let template_dir = temp_dir.path().join("templates");
+    std::fs::create_dir_all(&template_dir).unwrap();
+    std::fs::write(
+        template_dir.join("README.md.hbs"),
+        "# {{project_name}} (custom)\n",
+    )
+    .unwrap();
+
+    let project_dir = temp_dir.path().join("custom-tpl-app");
+    let config = ProjectConfig {
+        project_name: "custom-tpl-app".to_string(),
+        template_dir: Some(template_dir),
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let readme = std::fs::read_to_string(project_dir.join("README.md")).unwrap();
+    assert_eq!(readme, "# custom-tpl-app (custom)\n");
+    assert!(project_dir.join("Cargo.toml").exists());
+}
+
+/// Test: partials in a custom template dir are inlined and not generated as files
+#[test]
+fn test_custom_template_partial_inlined() {
+    let temp_dir = TempDir::new().unwrap();
+    let template_dir = temp_dir.path().join("templates");
+    std::fs::create_dir_all(template_dir.join("partials")).unwrap();
+    std::fs::create_dir_all(template_dir.join("src")).unwrap();
+    std::fs::write(
+        template_dir.join("partials/license_header.hbs"),
+        "// {{project_name}} - MIT License\n",
+    )
+    .unwrap();
+    std::fs::write(
+        template_dir.join("src/main.rs.hbs"),
+        "{{> license_header}}\nfn main() {}\n",
+    )
+    .unwrap();
+
+    let project_dir = temp_dir.path().join("partial-app");
+    let config = ProjectConfig {
+        project_name: "partial-app".to_string(),
+        template_dir: Some(template_dir),
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let main_rs = std::fs::read_to_string(project_dir.join("src/main.rs")).unwrap();
+    assert_eq!(main_rs, "// partial-app - MIT License\nfn main() {}\n");
+    assert!(!project_dir.join("partials").exists());
+}
+
+/// Test: colliding custom templates fail before the project is created
+#[test]
+fn test_custom_template_dir_collision_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let template_dir = temp_dir.path().join("templates");
+    std::fs::create_dir_all(&template_dir).unwrap();
+    std::fs::write(template_dir.join("Cargo.toml.hbs"), "a").unwrap();
+    std::fs::write(template_dir.join("Cargo.toml"), "b").unwrap();
+
+    let project_dir = temp_dir.path().join("collision-app");
+    let config = ProjectConfig {
+        project_name: "collision-app".to_string(),
+        template_dir: Some(template_dir),
+        ..Default::default()
+    };
+
+    let err = generate_project(&project_dir, &config, false, false, false).unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("Conflicting custom templates"), "{}", msg);
+    assert!(msg.contains("Cargo.toml.hbs"));
+    assert!(!project_dir.exists());
+}
+
+/// Test: --seed-rng makes the generated .env JWT secret reproducible
+#[test]
+fn test_seed_rng_reproducible_jwt_secret() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    let generate_env = |dir_name: &str, seed: u64| {
+        let project_dir = temp_dir.path().join(dir_name);
+        let config = ProjectConfig {
+            project_name: "seeded-app".to_string(),
+            features: FeatureSet {
+                authentication: true,
+                ..Default::default()
+            },
+            seed_rng: Some(seed),
+            with_env: true,
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+        std::fs::read_to_string(project_dir.join(".env")).unwrap()
+    };
+
+    let first = generate_env("seeded-a", 7);
+    let second = generate_env("seeded-b", 7);
+    let other = generate_env("seeded-c", 8);
+
+    let jwt_line = |env: &str| {
+        env.lines()
+            .find(|line| line.starts_with("JWT_SECRET="))
+            .expect(".env should contain JWT_SECRET")
+            .to_string()
+    };
+
+    assert_eq!(jwt_line(&first), jwt_line(&second));
+    assert_ne!(jwt_line(&first), jwt_line(&other));
+}
+
+/// Integration test - --locale selects README language
+#[test]
+fn test_locale_selects_readme_language() {
+    use axum_app_create::config::Locale;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    let generate_readme = |dir_name: &str, mode: ProjectMode, locale: Locale| {
+        let project_dir = temp_dir.path().join(dir_name);
+        let config = ProjectConfig {
+            project_name: dir_name.to_string(),
+            mode,
+            locale,
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+        std::fs::read_to_string(project_dir.join("README.md")).unwrap()
+    };
+
+    for mode in [ProjectMode::Single, ProjectMode::Workspace] {
+        let en = generate_readme(&format!("en-{}", mode), mode, Locale::En);
+        assert!(en.contains("Quick Start"));
+        assert!(!en.contains("快速开始"));
+
+        let zh = generate_readme(&format!("zh-{}", mode), mode, Locale::Zh);
+        assert!(zh.contains("快速开始"));
+        assert!(!zh.contains("Quick Start"));
+
+        let both = generate_readme(&format!("both-{}", mode), mode, Locale::Both);
+        assert!(both.contains("Quick Start"));
+        assert!(both.contains("快速开始"));
+    }
+}
+
+/// Test: --locale zh also localizes CLI errors, progress and the success message
+#[test]
+fn test_locale_zh_cli_output() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args(["bad-db-app", "--database", "mongo", "--locale", "zh"])
+        .args(["--non-interactive"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("无效的数据库选项"), "{}", stderr);
+    assert!(!stderr.contains("Invalid database option"), "{}", stderr);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args(["zh-app", "--locale", "zh", "--non-interactive"])
+        .args(["--no-git", "--no-update"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("✓ 已创建 Cargo.toml"), "{}", stdout);
+    assert!(stdout.contains("项目 'zh-app' 创建成功"), "{}", stdout);
+    for english in ["Created", "created successfully", "Quick Start"] {
+        assert!(!stdout.contains(english), "{}:\n{}", english, stdout);
+    }
+}
+
+/// Integration test - --entities generates one migration and model per entity
+#[test]
+fn test_entities_generate_migrations_and_models() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, migrations_dir, model_dir) in [
+        (ProjectMode::Single, "migrations", "src/models"),
+        (
+            ProjectMode::Workspace,
+            "infrastructure/migrations",
+            "domain/src/entities",
+        ),
+    ] {
+        let project_dir = temp_dir.path().join(format!("entities-{}", mode));
+        let config = ProjectConfig {
+            project_name: format!("entities-{}", mode),
+            mode,
+            features: FeatureSet {
+                database: DatabaseOption::PostgreSQL,
+                ..Default::default()
+            },
+            entities: vec!["Post".to_string(), "Category".to_string()],
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let migrations = project_dir.join(migrations_dir);
+        let posts = std::fs::read_to_string(migrations.join("001_create_posts.sql")).unwrap();
+        assert!(posts.contains("CREATE TABLE IF NOT EXISTS posts"));
+        let categories =
+            std::fs::read_to_string(migrations.join("002_create_categories.sql")).unwrap();
+        assert!(categories.contains("CREATE TABLE IF NOT EXISTS categories"));
+        assert!(
+            !migrations.join("001_initial.sql").exists(),
+            "001_initial.sql should be replaced by entity migrations"
+        );
+
+        let post = std::fs::read_to_string(project_dir.join(model_dir).join("post.rs")).unwrap();
+        assert!(post.contains("pub struct Post"));
+        assert!(project_dir.join(model_dir).join("category.rs").exists());
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        if !output.status.success() {
+            eprintln!(
+                "cargo check stderr:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        assert!(
+            output.status.success(),
+            "{} project with entities failed to compile",
+            mode
+        );
+    }
+}
+
+/// Integration test - --comments en keeps generated source and config comments English-only
+#[test]
+fn test_comment_locale_english_only() {
+    use axum_app_create::config::Locale;
+
+    let has_cjk = |text: &str| text.chars().any(|c| ('\u{4e00}'..='\u{9fff}').contains(&c));
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, main_path) in [
+        (ProjectMode::Single, "src/main.rs"),
+        (ProjectMode::Workspace, "api/src/main.rs"),
+    ] {
+        let project_dir = temp_dir.path().join(format!("comments-{}", mode));
+        let config = ProjectConfig {
+            project_name: format!("comments-{}", mode),
+            mode,
+            comment_locale: Locale::En,
+            ..Default::default()
+        };
+
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains("// Load configuration"));
+        assert!(
+            !has_cjk(&main_rs),
+            "{} should have English-only comments",
+            main_path
+        );
+
+        let dockerfile = std::fs::read_to_string(project_dir.join("Dockerfile")).unwrap();
+        assert!(dockerfile.contains("# Stage 1: Build\n"));
+        assert!(!has_cjk(&dockerfile));
+
+        // README language still follows --locale
+        let readme = std::fs::read_to_string(project_dir.join("README.md")).unwrap();
+        assert!(readme.contains("快速开始"));
+    }
+
+    let project_dir = temp_dir.path().join("comments-both");
+    let config = ProjectConfig {
+        project_name: "comments-both".to_string(),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    let dockerfile = std::fs::read_to_string(project_dir.join("Dockerfile")).unwrap();
+    assert!(dockerfile.contains("# Stage 1: Build / 构建阶段"));
+}
+
+/// T062: Integration test - generate project with biz-error feature
+#[test]
+fn test_biz_error_feature() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("bizerror-test-app");
+
+    let config = ProjectConfig {
+        project_name: "bizerror-test-app".to_string(),
+        features: FeatureSet {
+            biz_error: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    // Verify biz_errors.yaml exists
+    assert!(project_dir.join("biz_errors.yaml").exists());
+
+    // Verify it contains error definitions in both English and Chinese
+    let biz_errors = std::fs::read_to_string(project_dir.join("biz_errors.yaml")).unwrap();
+    assert!(biz_errors.contains("en:"));
+    assert!(biz_errors.contains("zh:"));
+}
+
+/// Test: biz-error projects ship a test that checks biz_errors.yaml against the generated codes
+#[test]
+fn test_biz_error_consistency_test_passes() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("bizerror-check-app");
+
+    let config = ProjectConfig {
+        project_name: "bizerror-check-app".to_string(),
+        features: FeatureSet {
+            biz_error: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let test_file = project_dir.join("tests/biz_errors.rs");
+    assert!(
+        test_file.exists(),
+        "tests/biz_errors.rs should be generated"
+    );
+    let content = std::fs::read_to_string(&test_file).unwrap();
+    assert!(content.contains("bizerror_check_app::error_codes::ALL_ERROR_CODES"));
+
+    // Run only the consistency test (it must compile and pass)
+    let output = Command::new("cargo")
+        .arg("test")
+        .arg("--manifest-path")
+        .arg(project_dir.join("Cargo.toml"))
+        .arg("--test")
+        .arg("biz_errors")
+        .output()
+        .expect("Failed to run cargo test");
+
+    if !output.status.success() {
+        eprintln!(
+            "cargo test stderr:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    assert!(
+        output.status.success(),
+        "Generated biz-error consistency test failed"
+    );
+}
+
+/// Test: the biz-error consistency test is not generated without biz-error
+#[test]
+fn test_biz_error_consistency_test_absent_without_biz_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("no-bizerror-app");
+
+    let config = ProjectConfig {
+        project_name: "no-bizerror-app".to_string(),
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    assert!(!project_dir.join("tests/biz_errors.rs").exists());
+}
+
+/// T063: Integration test - generate project with multiple features
+#[test]
+fn test_multiple_features() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("multi-test-app");
+
+    let config = ProjectConfig {
+        project_name: "multi-test-app".to_string(),
+        features: FeatureSet {
+            database: DatabaseOption::Both,
+            authentication: true,
+            logging: true,
+            biz_error: true,
+            openapi: false,
+            sse: false,
+            sessions: false,
+            multipart: false,
+            cache: false,
+            cors: false,
+            compression: false,
+            metrics: false,
+        },
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    // Verify all feature files exist
+    assert!(project_dir.join("src/db.rs").exists());
+    assert!(project_dir.join("src/handlers/auth.rs").exists());
+    assert!(project_dir.join("migrations/001_initial.sql").exists());
+    assert!(project_dir.join("biz_errors.yaml").exists());
+
+    // Verify Cargo.toml contains all dependencies
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("sqlx"));
+    assert!(cargo_toml.contains("jsonwebtoken"));
+    assert!(cargo_toml.contains("tracing"));
+
+    // Verify .env.example contains all config variables
+    let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
+    assert!(env_example.contains("DATABASE_URL"));
+    assert!(env_example.contains("JWT_SECRET"));
+    assert!(env_example.contains("LOG_LEVEL"));
+}
+
+/// Test: auth-only project (no database) compiles with `cargo check`
+/// This verifies the auth.rs.hbs template generates valid code without database imports
+#[test]
+fn test_auth_only_project_compiles() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("auth-only-app");
+
+    let config = ProjectConfig {
+        project_name: "auth-only-app".to_string(),
+        features: FeatureSet {
+            authentication: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    // Run cargo check to verify it compiles
+    let output = Command::new("cargo")
+        .arg("check")
+        .arg("--manifest-path")
+        .arg(project_dir.join("Cargo.toml"))
+        .output()
+        .expect("Failed to run cargo check");
+
+    if !output.status.success() {
+        eprintln!(
+            "cargo check stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    assert!(
+        output.status.success(),
+        "Auth-only generated project failed to compile"
+    );
+}
+
+/// Test: --cargo-features project compiles with no features and with all features
+#[test]
+fn test_cargo_features_project_compiles() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("cargo-features-app");
+
+    let config = ProjectConfig {
+        project_name: "cargo-features-app".to_string(),
+        features: FeatureSet {
+            database: DatabaseOption::Both,
+            authentication: true,
+            logging: true,
+            ..Default::default()
+        },
+        cargo_features: true,
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("[features]"));
+    assert!(cargo_toml.contains("default = [\"auth\", \"postgres\", \"sqlite\"]"));
+    assert!(cargo_toml.contains("auth = [\"dep:jsonwebtoken\", \"dep:bcrypt\"]"));
+
+    let main_rs = std::fs::read_to_string(project_dir.join("src/main.rs")).unwrap();
+    assert!(main_rs.contains("#[cfg(feature = \"auth\")]"));
+
+    for feature_args in [&["--no-default-features"][..], &["--all-features"][..]] {
+        let output = Command::new("cargo")
+            .arg("check")
+            .args(feature_args)
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        if !output.status.success() {
+            eprintln!(
+                "cargo check {:?} stderr:\n{}",
+                feature_args,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        assert!(
+            output.status.success(),
+            "Cargo-features project failed to compile with {:?}",
+            feature_args
+        );
+    }
+}
+
+/// Test: --testcontainers generates a DB integration test that compiles
+///
+/// Running the test needs Docker, so only `cargo check --tests` is executed.
+#[test]
+fn test_testcontainers_db_integration_compiles() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("testcontainers-app");
+
+    let config = ProjectConfig {
+        project_name: "testcontainers-app".to_string(),
+        features: FeatureSet {
+            database: DatabaseOption::PostgreSQL,
+            authentication: true,
+            logging: true,
+            ..Default::default()
+        },
+        testcontainers: true,
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    assert!(project_dir.join("tests/db_integration.rs").exists());
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("testcontainers-modules"));
+
+    let output = Command::new("cargo")
+        .arg("check")
+        .arg("--tests")
+        .arg("--manifest-path")
+        .arg(project_dir.join("Cargo.toml"))
+        .output()
+        .expect("Failed to run cargo check");
+
+    if !output.status.success() {
+        eprintln!(
+            "cargo check stderr:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    assert!(
+        output.status.success(),
+        "Generated testcontainers test should compile"
+    );
+}
+
+/// Test: --testcontainers is rejected unless the project is single-mode PostgreSQL
+#[test]
+fn test_testcontainers_rejected_without_postgresql() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+    use axum_app_create::error::CliError;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (name, mode, database) in [
+        ("plain-db-app", ProjectMode::Single, DatabaseOption::None),
+        ("sqlite-db-app", ProjectMode::Single, DatabaseOption::SQLite),
+        ("both-db-app", ProjectMode::Single, DatabaseOption::Both),
+        (
+            "ws-db-app",
+            ProjectMode::Workspace,
+            DatabaseOption::PostgreSQL,
+        ),
+    ] {
+        let project_dir = temp_dir.path().join(name);
+        let config = ProjectConfig {
+            project_name: name.to_string(),
+            mode,
+            features: FeatureSet {
+                database,
+                ..Default::default()
+            },
+            testcontainers: true,
+            ..Default::default()
+        };
+
+        let result = generate_project(&project_dir, &config, false, false, false);
+        let Err(CliError::ValidationError(message)) = result else {
+            panic!("{}: expected a validation error, got {:?}", name, result);
+        };
+        assert!(
+            message.contains("--testcontainers is not supported"),
+            "{}",
+            name
+        );
+        assert!(!project_dir.exists(), "{}", name);
+    }
+}
+
+/// Test: --force flag overwrites existing directory
+#[test]
+fn test_force_overwrite() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("force-test-app");
+
+    let config = ProjectConfig {
+        project_name: "force-test-app".to_string(),
+        ..Default::default()
+    };
+
+    // Generate first time
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert!(project_dir.exists());
+
+    // Generate again with force=true should succeed
+    let result = generate_project(&project_dir, &config, false, true, false);
+    assert!(result.is_ok(), "Force overwrite failed: {:?}", result.err());
+    assert!(project_dir.join("Cargo.toml").exists());
+}
+
+/// Test: existing directory without force in non-interactive mode should fail
+#[test]
+fn test_existing_dir_no_force_fails() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("noforce-test-app");
+
+    let config = ProjectConfig {
+        project_name: "noforce-test-app".to_string(),
+        ..Default::default()
+    };
+
+    // Generate first time
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    // Generate again without force should fail
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(
+        result.is_err(),
+        "Should fail when directory exists without --force"
+    );
+}
+
+/// Test: --yes in interactive mode refuses to overwrite an existing directory
+#[test]
+fn test_assume_yes_keeps_existing_dir() {
+    use axum_app_create::error::CliError;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("yes-test-app");
+    std::fs::create_dir_all(&project_dir).unwrap();
+    std::fs::write(project_dir.join("notes.txt"), "keep me\n").unwrap();
+
+    let config = ProjectConfig {
+        project_name: "yes-test-app".to_string(),
+        assume_yes: true,
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, true, false, false);
+    let Err(CliError::Generation(message)) = result else {
+        panic!("expected a generation error, got {:?}", result);
+    };
+    assert!(message.contains("--force"));
+    assert_eq!(
+        std::fs::read_to_string(project_dir.join("notes.txt")).unwrap(),
+        "keep me\n"
+    );
+    assert!(!project_dir.join("Cargo.toml").exists());
+}
+
+/// Test: project with ALL features enabled compiles with `cargo check`
+/// This is the critical integration test that catches template type mismatches
+#[test]
+fn test_all_features_project_compiles() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("all-features-app");
+
+    let config = ProjectConfig {
+        project_name: "all-features-app".to_string(),
+        features: FeatureSet {
+            database: DatabaseOption::Both,
+            authentication: true,
+            logging: true,
+            biz_error: true,
+            openapi: true,
+            sse: false,
+            sessions: false,
+            multipart: false,
+            cache: false,
+            cors: false,
+            compression: false,
+            metrics: false,
+        },
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    // Verify biz-error dependency and build.rs in Cargo.toml
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(
+        cargo_toml.contains("biz-error"),
+        "Cargo.toml should contain biz-error dependency"
+    );
+    assert!(
+        cargo_toml.contains("[build-dependencies]"),
+        "Cargo.toml should have [build-dependencies] section"
+    );
+
+    // Verify build.rs exists
+    assert!(
+        project_dir.join("build.rs").exists(),
+        "build.rs should be generated for biz-error codegen"
+    );
+
+    // Verify OpenAPI docs are wired up
+    assert!(
+        cargo_toml.contains("utoipa"),
+        "Cargo.toml should contain utoipa dependency"
+    );
+    let main_rs = std::fs::read_to_string(project_dir.join("src/main.rs")).unwrap();
+    assert!(
+        main_rs.contains("SwaggerUi"),
+        "main.rs should serve Swagger UI"
+    );
+
+    // Run cargo check to verify it compiles
+    let output = Command::new("cargo")
+        .arg("check")
+        .arg("--manifest-path")
+        .arg(project_dir.join("Cargo.toml"))
+        .output()
+        .expect("Failed to run cargo check");
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        eprintln!("cargo check stderr:\n{}", stderr);
+    }
+
+    assert!(
+        output.status.success(),
+        "All-features generated project failed to compile"
+    );
+}
+
+/// Test: project with database + auth compiles (the specific combo that was broken)
+#[test]
+fn test_database_auth_project_compiles() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("db-auth-app");
+
+    let config = ProjectConfig {
+        project_name: "db-auth-app".to_string(),
+        features: FeatureSet {
+            database: DatabaseOption::PostgreSQL,
+            authentication: true,
+            logging: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    // Run cargo check
+    let output = Command::new("cargo")
+        .arg("check")
+        .arg("--manifest-path")
+        .arg(project_dir.join("Cargo.toml"))
+        .output()
+        .expect("Failed to run cargo check");
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        eprintln!("cargo check stderr:\n{}", stderr);
+    }
+
+    assert!(
+        output.status.success(),
+        "Database+Auth generated project failed to compile"
+    );
+}
+
+// ============================================================
+// v0.2.0 Integration Tests
+// ============================================================
+
+/// Test workspace mode generates correct structure
+#[test]
+fn test_workspace_mode_basic_structure() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("ws-app");
+
+    let config = ProjectConfig {
+        project_name: "ws-app".to_string(),
+        mode: ProjectMode::Workspace,
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(
+        result.is_ok(),
+        "Workspace generation failed: {:?}",
+        result.err()
+    );
+
+    // Verify root Cargo.toml has [workspace]
+    let cargo_content = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(
+        cargo_content.contains("[workspace]"),
+        "Root Cargo.toml missing [workspace]"
+    );
+    assert!(
+        cargo_content.contains("members"),
+        "Root Cargo.toml missing members"
+    );
+
+    // Verify all 4 sub-crate directories exist
+    for crate_name in &["api", "domain", "infrastructure", "common"] {
+        assert!(
+            project_dir.join(crate_name).exists(),
+            "Missing crate dir: {}",
+            crate_name
+        );
+        assert!(
+            project_dir
+                .join(format!("{}/Cargo.toml", crate_name))
+                .exists(),
+            "Missing {}/Cargo.toml",
+            crate_name
+        );
+        assert!(
+            project_dir
+                .join(format!("{}/src/lib.rs", crate_name))
+                .exists(),
+            "Missing {}/src/lib.rs",
+            crate_name
+        );
+    }
+
+    // Verify api crate has main.rs (binary crate)
+    assert!(
+        project_dir.join("api/src/main.rs").exists(),
+        "Missing api/src/main.rs"
+    );
+}
+
+/// Test workspace mode + database feature
+#[test]
+fn test_workspace_mode_with_database() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("ws-db-app");
+
+    let config = ProjectConfig {
+        project_name: "ws-db-app".to_string(),
+        mode: ProjectMode::Workspace,
+        features: axum_app_create::config::FeatureSet {
+            database: axum_app_create::config::DatabaseOption::PostgreSQL,
+            ..Default::default()
+        },
+        database: Some(axum_app_create::config::DatabaseConfig::default()),
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    assert!(
+        project_dir.join("infrastructure/src/db.rs").exists(),
+        "Missing infrastructure/src/db.rs"
+    );
+}
+
+/// Test workspace mode + auth feature
+#[test]
+fn test_workspace_mode_with_auth() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("ws-auth-app");
+
+    let config = ProjectConfig {
+        project_name: "ws-auth-app".to_string(),
+        mode: ProjectMode::Workspace,
+        features: axum_app_create::config::FeatureSet {
+            authentication: true,
+            ..Default::default()
+        },
+        authentication: Some(axum_app_create::config::AuthConfig::default()),
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    assert!(
+        project_dir.join("api/src/handlers/auth.rs").exists(),
+        "Missing api/src/handlers/auth.rs"
+    );
+    assert!(
+        project_dir.join("api/src/middleware/mod.rs").exists(),
+        "Missing api/src/middleware/mod.rs"
+    );
+}
+
+/// Test workspace mode + biz-error feature
+#[test]
+fn test_workspace_mode_with_biz_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("ws-biz-app");
+
+    let config = ProjectConfig {
+        project_name: "ws-biz-app".to_string(),
+        mode: ProjectMode::Workspace,
+        features: axum_app_create::config::FeatureSet {
+            biz_error: true,
+            ..Default::default()
+        },
+        biz_error: Some(axum_app_create::config::BizErrorConfig::default()),
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    assert!(
+        project_dir.join("common/src/error.rs").exists(),
+        "Missing common/src/error.rs"
+    );
+}
+
+/// Test preset minimal generates no optional features
+#[test]
+fn test_preset_minimal() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("minimal-app");
+
+    let features = Preset::Minimal.to_feature_set();
+    let config = ProjectConfig {
+        project_name: "minimal-app".to_string(),
+        features,
+        preset: Some(Preset::Minimal),
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    // No database or auth files should exist (they render to empty)
+    let db_content = std::fs::read_to_string(project_dir.join("src/db.rs")).unwrap_or_default();
+    assert!(db_content.trim().is_empty() || !project_dir.join("src/db.rs").exists());
+}
+
+/// Test preset api generates PostgreSQL + auth + biz-error
+#[test]
+fn test_preset_api() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("api-app");
+
+    let features = Preset::Api.to_feature_set();
+    let config = ProjectConfig {
+        project_name: "api-app".to_string(),
+        features,
+        preset: Some(Preset::Api),
+        database: Some(axum_app_create::config::DatabaseConfig::default()),
+        authentication: Some(axum_app_create::config::AuthConfig::default()),
+        biz_error: Some(axum_app_create::config::BizErrorConfig::default()),
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    // Auth file should have content
+    let auth_content = std::fs::read_to_string(project_dir.join("src/handlers/auth.rs")).unwrap();
+    assert!(
+        !auth_content.trim().is_empty(),
+        "Auth handler should have content"
+    );
+}
+
+/// Test preset fullstack generates both DBs + all features
+#[test]
+fn test_preset_fullstack() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("fullstack-app");
+
+    let features = Preset::Fullstack.to_feature_set();
+    let config = ProjectConfig {
+        project_name: "fullstack-app".to_string(),
+        features,
+        preset: Some(Preset::Fullstack),
+        database: Some(axum_app_create::config::DatabaseConfig::default()),
+        authentication: Some(axum_app_create::config::AuthConfig::default()),
+        biz_error: Some(axum_app_create::config::BizErrorConfig::default()),
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    let cargo_content = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(
+        cargo_content.contains("sqlx"),
+        "Fullstack should include sqlx"
+    );
+}
+
+/// Test CI template generated when ci=true
+#[test]
+fn test_ci_enabled() {
+    use axum_app_create::template::context::TemplateContext;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("ci-app");
+
+    let config = ProjectConfig {
+        project_name: "ci-app".to_string(),
+        ci: true,
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    assert!(
+        project_dir.join(".github/workflows/ci.yml").exists(),
+        "CI workflow file should exist"
+    );
+
+    // Governance files come with the workflow, owned by the author by default
+    let security = std::fs::read_to_string(project_dir.join("SECURITY.md")).unwrap();
+    assert!(security.contains("## Reporting a Vulnerability"));
+    let codeowners = std::fs::read_to_string(project_dir.join(".github/CODEOWNERS")).unwrap();
+    let owner = TemplateContext::from_config(&config).code_owner;
+    assert!(codeowners.contains(&format!("\n* {}\n", owner)));
+
+    // Single mode should NOT have --workspace flag
+    let ci_content = std::fs::read_to_string(project_dir.join(".github/workflows/ci.yml")).unwrap();
+    assert!(
+        !ci_content.contains("--workspace"),
+        "Single mode CI should not have --workspace"
+    );
+}
+
+/// Test the GitLab pipeline replaces the GitHub workflow when selected
+#[test]
+fn test_ci_provider_gitlab() {
+    use axum_app_create::config::CiProvider;
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("gitlab-app");
+
+    let config = ProjectConfig {
+        project_name: "gitlab-app".to_string(),
+        mode: ProjectMode::Workspace,
+        ci: true,
+        ci_provider: CiProvider::GitLab,
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    assert!(!project_dir.join(".github/workflows/ci.yml").exists());
+    assert!(!project_dir.join(".github/CODEOWNERS").exists());
+    assert!(project_dir.join("SECURITY.md").exists());
+
+    let ci_content = std::fs::read_to_string(project_dir.join(".gitlab-ci.yml")).unwrap();
+    for job in ["check:", "test:", "fmt:", "clippy:"] {
+        assert!(ci_content.contains(job), "missing job {}", job);
+    }
+    assert!(ci_content.contains("cargo test --workspace"));
+    assert!(ci_content.contains("cargo clippy --workspace -- -D warnings"));
+}
+
+/// Test CI template NOT generated when ci=false
+#[test]
+fn test_ci_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("no-ci-app");
+
+    let config = ProjectConfig {
+        project_name: "no-ci-app".to_string(),
+        ci: false,
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    assert!(
+        !project_dir.join(".github/workflows/ci.yml").exists(),
+        "CI workflow should NOT exist"
+    );
+    assert!(!project_dir.join("SECURITY.md").exists());
+    assert!(!project_dir.join(".github/CODEOWNERS").exists());
+}
+
+/// Test workspace mode + CI has --workspace flag
+#[test]
+fn test_workspace_ci_has_workspace_flag() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("ws-ci-app");
+
+    let config = ProjectConfig {
+        project_name: "ws-ci-app".to_string(),
+        mode: ProjectMode::Workspace,
+        ci: true,
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    let ci_content = std::fs::read_to_string(project_dir.join(".github/workflows/ci.yml")).unwrap();
+    assert!(
+        ci_content.contains("--workspace"),
+        "Workspace CI should have --workspace flag"
+    );
+}
+
+/// Test --sql-lint generates .sqlfluff with the selected database's dialect
+#[test]
+fn test_sql_lint_dialect_matches_database() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (database, dialect) in [
+        (DatabaseOption::PostgreSQL, "postgres"),
+        (DatabaseOption::SQLite, "sqlite"),
+    ] {
+        let project_dir = temp_dir.path().join(format!("sql-lint-{}", dialect));
+
+        let config = ProjectConfig {
+            project_name: format!("sql-lint-{}", dialect),
+            features: FeatureSet {
+                database,
+                ..Default::default()
+            },
+            sql_lint: true,
+            ci: true,
+            ..Default::default()
+        };
+
+        let result = generate_project(&project_dir, &config, false, false, false);
+        assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+        let sqlfluff = std::fs::read_to_string(project_dir.join(".sqlfluff")).unwrap();
+        assert!(
+            sqlfluff.contains(&format!("dialect = {}", dialect)),
+            ".sqlfluff should use the {} dialect",
+            dialect
+        );
+
+        let ci_content =
+            std::fs::read_to_string(project_dir.join(".github/workflows/ci.yml")).unwrap();
+        assert!(
+            ci_content.contains("sqlfluff lint migrations"),
+            "CI should lint migrations"
+        );
+    }
+}
+
+/// Test --sql-lint is ignored when no database is selected
+#[test]
+fn test_sql_lint_absent_without_database() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("sql-lint-none");
+
+    let config = ProjectConfig {
+        project_name: "sql-lint-none".to_string(),
+        sql_lint: true,
+        ci: true,
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    assert!(!project_dir.join(".sqlfluff").exists());
+    let ci_content = std::fs::read_to_string(project_dir.join(".github/workflows/ci.yml")).unwrap();
+    assert!(!ci_content.contains("sqlfluff"));
+}
+
+/// Test workspace mode basic project compiles with cargo check
+#[test]
+fn test_workspace_basic_compiles() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("ws-compile-test");
+
+    let config = ProjectConfig {
+        project_name: "ws-compile-test".to_string(),
+        mode: ProjectMode::Workspace,
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    let output = Command::new("cargo")
+        .arg("check")
+        .arg("--workspace")
+        .arg("--manifest-path")
+        .arg(project_dir.join("Cargo.toml"))
+        .output()
+        .expect("Failed to run cargo check");
+
+    if !output.status.success() {
+        eprintln!(
+            "cargo check stdout: {}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+        eprintln!(
+            "cargo check stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    assert!(
+        output.status.success(),
+        "Workspace project failed to compile"
+    );
+}
+
+/// Test workspace mode full-featured project compiles
+#[test]
+fn test_workspace_full_features_compiles() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("ws-full-test");
+
+    let features = Preset::Fullstack.to_feature_set();
+    let config = ProjectConfig {
+        project_name: "ws-full-test".to_string(),
+        mode: ProjectMode::Workspace,
+        features,
+        preset: Some(Preset::Fullstack),
+        database: Some(axum_app_create::config::DatabaseConfig::default()),
+        authentication: Some(axum_app_create::config::AuthConfig::default()),
+        biz_error: Some(axum_app_create::config::BizErrorConfig::default()),
+        ci: true,
+        ..Default::default()
+    };
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+
+    let output = Command::new("cargo")
+        .arg("check")
+        .arg("--workspace")
+        .arg("--manifest-path")
+        .arg(project_dir.join("Cargo.toml"))
+        .output()
+        .expect("Failed to run cargo check");
+
+    if !output.status.success() {
+        eprintln!(
+            "cargo check stdout: {}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+        eprintln!(
+            "cargo check stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    assert!(
+        output.status.success(),
+        "Workspace full-featured project failed to compile"
+    );
+}
+
+/// Test workspace_crates context is correctly populated for workspace mode
+#[test]
+fn test_workspace_crates_context() {
+    use axum_app_create::template::context::TemplateContext;
+
+    let config = ProjectConfig {
+        project_name: "ctx-test".to_string(),
+        mode: ProjectMode::Workspace,
+        ..Default::default()
+    };
+
+    let ctx = TemplateContext::from_config(&config);
+    assert!(ctx.is_workspace);
+    let crates = ctx
+        .workspace_crates
+        .as_ref()
+        .expect("workspace_crates should be Some");
+    assert_eq!(crates.len(), 4);
+
+    // Verify crate names
+    let names: Vec<&str> = crates.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["api", "domain", "infrastructure", "common"]);
+
+    // Verify api is bin, others are lib
+    assert_eq!(crates[0].kind, "bin");
+    assert_eq!(crates[1].kind, "lib");
+    assert_eq!(crates[2].kind, "lib");
+    assert_eq!(crates[3].kind, "lib");
+
+    // Verify package names
+    assert_eq!(crates[0].package_name, "ctx-test-api");
+    assert_eq!(crates[1].package_name, "ctx-test-domain");
+
+    // Verify api depends on domain, infrastructure, common
+    assert!(crates[0].workspace_deps.contains(&"domain".to_string()));
+    assert!(
+        crates[0]
+            .workspace_deps
+            .contains(&"infrastructure".to_string())
+    );
+    assert!(crates[0].workspace_deps.contains(&"common".to_string()));
+
+    // Verify domain has no workspace deps
+    assert!(crates[1].workspace_deps.is_empty());
+
+    // Verify infrastructure depends on domain
+    assert_eq!(crates[2].workspace_deps, vec!["domain"]);
+}
+
+/// Test the simple workspace layout has just a lib crate and a bin crate on top of it
+#[test]
+fn test_workspace_crates_context_simple_layout() {
+    use axum_app_create::config::WorkspaceLayout;
+    use axum_app_create::template::context::TemplateContext;
+
+    let config = ProjectConfig {
+        project_name: "ctx-test".to_string(),
+        mode: ProjectMode::Workspace,
+        workspace_layout: WorkspaceLayout::Simple,
+        ..Default::default()
+    };
+
+    let ctx = TemplateContext::from_config(&config);
+    assert_eq!(ctx.workspace_layout, "simple");
+    let crates = ctx
+        .workspace_crates
+        .as_ref()
+        .expect("workspace_crates should be Some");
+    assert_eq!(crates.len(), 2);
+
+    let names: Vec<&str> = crates.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["api", "core"]);
+
+    assert_eq!(crates[0].kind, "bin");
+    assert_eq!(crates[0].package_name, "ctx-test-api");
+    assert_eq!(crates[0].workspace_deps, vec!["core"]);
+
+    assert_eq!(crates[1].kind, "lib");
+    assert_eq!(crates[1].package_name, "ctx-test-core");
+    assert!(crates[1].workspace_deps.is_empty());
+}
+
+/// Test a project name valid on its own but too long for its workspace member names
+#[test]
+fn test_workspace_member_name_too_long() {
+    use axum_app_create::error::CliError;
+
+    let temp_dir = TempDir::new().unwrap();
+    let name = "a".repeat(90);
+    let project_dir = temp_dir.path().join(&name);
+    let config = ProjectConfig {
+        project_name: name.clone(),
+        mode: ProjectMode::Workspace,
+        ..Default::default()
+    };
+
+    // The same name is fine for a single package
+    assert!(axum_app_create::utils::validator::validate_project_name(&name).is_ok());
+
+    let result = generate_project(&project_dir, &config, false, false, false);
+    let Err(CliError::ValidationError(message)) = result else {
+        panic!("expected a validation error, got {:?}", result);
+    };
+    assert!(message.contains("Invalid workspace member package name"));
+    assert!(message.contains(&format!("{}-infrastructure", name)));
+    assert!(message.contains("shorten or change the project name"));
+    assert!(!project_dir.exists());
+}
+
+/// Test workspace_crates is None for single mode
+#[test]
+fn test_single_mode_no_workspace_crates() {
+    use axum_app_create::template::context::TemplateContext;
+
+    let config = ProjectConfig {
+        project_name: "single-test".to_string(),
+        mode: ProjectMode::Single,
+        ..Default::default()
+    };
+
+    let ctx = TemplateContext::from_config(&config);
+    assert!(!ctx.is_workspace);
+    assert!(ctx.workspace_crates.is_none());
+}
+
+/// Running without a terminal on stdin falls back to non-interactive defaults
+#[test]
+fn test_piped_stdin_runs_non_interactive() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .arg("piped-app")
+        .current_dir(temp_dir.path())
+        .env_remove("CI")
+        .stdin(std::process::Stdio::null())
+        .output()
+        .expect("Failed to run axum-app-create");
+
+    assert!(
+        output.status.success(),
+        "generation with piped stdin failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(temp_dir.path().join("piped-app/Cargo.toml").exists());
+}
+
+/// `doctor` reports the toolchain versions and passes when rustc and cargo work
+#[test]
+fn test_doctor_reports_rust_version() {
+    let rustc = Command::new("rustc").arg("--version").output().unwrap();
+    let rust_version = String::from_utf8(rustc.stdout).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .arg("doctor")
+        .output()
+        .expect("Failed to run axum-app-create");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains(&format!("✓ rustc: {}", rust_version.trim())),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("✓ cargo: cargo "), "{}", stdout);
+    assert!(stdout.contains("git: "), "{}", stdout);
+    assert!(stdout.contains("user config: "), "{}", stdout);
+}
+
+/// --name sets the package name independently of a nested target directory
+#[test]
+fn test_name_separate_from_target_dir() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args([
+            "services/billing",
+            "--name",
+            "billing-service",
+            "--non-interactive",
+            "--no-update",
+            "--no-git",
+        ])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(
+        output.status.success(),
+        "generation into a nested dir failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let project_dir = temp_dir.path().join("services/billing");
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains(r#"name = "billing-service""#));
+    assert!(!temp_dir.path().join("billing-service").exists());
+
+    // Without --name the last path component is the package name
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args([
+            "services/ledger",
+            "--non-interactive",
+            "--no-update",
+            "--no-git",
+        ])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(output.status.success());
+    let cargo_toml =
+        std::fs::read_to_string(temp_dir.path().join("services/ledger/Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains(r#"name = "ledger""#));
+
+    // An invalid --name is rejected even though the path is fine
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args(["services/bad", "--name", "123bad", "--non-interactive"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(!output.status.success());
+    assert!(!temp_dir.path().join("services/bad").exists());
+}
+
+/// --quiet prints only the project path, without progress lines or the banner
+#[test]
+fn test_quiet_prints_only_project_path() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let run = |quiet: bool| {
+        let name = if quiet { "quiet-app" } else { "loud-app" };
+        let mut command = Command::new(env!("CARGO_BIN_EXE_axum-app-create"));
+        command
+            .args([name, "--non-interactive", "--no-update"])
+            .current_dir(temp_dir.path());
+        if quiet {
+            command.arg("--quiet");
+        }
+        let output = command.output().expect("Failed to run axum-app-create");
+        assert!(
+            output.status.success(),
+            "generation failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let loud = run(false);
+    assert!(loud.contains("Generating files"));
+    assert!(loud.contains("Created Cargo.toml"));
+    assert!(loud.contains("created successfully"));
+
+    let quiet = run(true);
+    assert_eq!(quiet.trim(), "quiet-app");
+    assert!(!quiet.contains("Generating files"));
+    assert!(!quiet.contains("axum-app-create CLI Tool"));
+    assert!(!quiet.contains("created successfully"));
+    assert!(temp_dir.path().join("quiet-app/Cargo.toml").exists());
+}
+
+/// Test: declared dependency lower bounds of a minimal project actually build
+///
+/// Resolves every direct dependency to the oldest version its requirement
+/// allows (`-Z direct-minimal-versions`), so it needs a nightly toolchain and
+/// network access. Run with `cargo test -- --ignored`.
+#[test]
+#[ignore = "requires a nightly toolchain and network access"]
+fn test_minimal_versions_build() {
+    let nightly = Command::new("cargo")
+        .args(["+nightly", "--version"])
+        .output();
+    if !nightly.is_ok_and(|output| output.status.success()) {
+        eprintln!("skipping: nightly toolchain not installed");
+        return;
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("min-versions-app");
+    let config = ProjectConfig {
+        project_name: "min-versions-app".to_string(),
+        features: Preset::Minimal.to_feature_set(),
+        preset: Some(Preset::Minimal),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let manifest = project_dir.join("Cargo.toml");
+    let update = Command::new("cargo")
+        .args(["+nightly", "update", "-Z", "direct-minimal-versions"])
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .output()
+        .expect("Failed to run cargo update");
+    assert!(
+        update.status.success(),
+        "Minimal versions failed to resolve:\n{}",
+        String::from_utf8_lossy(&update.stderr)
+    );
+
+    let check = Command::new("cargo")
+        .args(["+nightly", "check", "--all-targets", "--locked"])
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .output()
+        .expect("Failed to run cargo check");
+    assert!(
+        check.status.success(),
+        "Generated project failed to build with minimal versions:\n{}",
+        String::from_utf8_lossy(&check.stderr)
+    );
+}
+
+/// Test: docker-compose.yml is generated only for database-backed projects
+#[test]
+fn test_docker_compose_requires_database() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for mode in [ProjectMode::Single, ProjectMode::Workspace] {
+        for database in [
+            DatabaseOption::None,
+            DatabaseOption::PostgreSQL,
+            DatabaseOption::SQLite,
+        ] {
+            let name = format!("compose-{}-{}", mode, database).to_lowercase();
+            let project_dir = temp_dir.path().join(&name);
+            let config = ProjectConfig {
+                project_name: name.clone(),
+                mode,
+                features: FeatureSet {
+                    database,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            generate_project(&project_dir, &config, false, false, false).unwrap();
+
+            let compose = project_dir.join("docker-compose.yml");
+            if database == DatabaseOption::None {
+                assert!(!compose.exists(), "{}: unexpected docker-compose.yml", name);
+                continue;
+            }
+
+            let content = std::fs::read_to_string(&compose).unwrap();
+            assert!(content.contains("env_file: .env"));
+            assert!(content.contains("${PORT:-8080}"));
+            assert_eq!(
+                content.contains("image: postgres:16"),
+                database == DatabaseOption::PostgreSQL,
+                "{}: postgres service",
+                name
+            );
+            assert_eq!(
+                content.contains("sqlite-data:/data"),
+                database == DatabaseOption::SQLite,
+                "{}: sqlite volume",
+                name
+            );
+        }
+    }
+}
+
+/// Test: `completions bash` prints a script covering the CLI flags
+#[test]
+fn test_bash_completions() {
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args(["completions", "bash"])
+        .output()
+        .expect("Failed to run axum-app-create");
+
+    assert!(output.status.success());
+    let script = String::from_utf8(output.stdout).unwrap();
+    assert!(script.contains("axum-app-create"));
+    assert!(script.contains("--preset"));
+    assert!(script.contains("--template-dir"));
+    // Nothing but the script goes to stdout
+    assert!(!script.contains("CLI Tool"));
+}
+
+/// Test: validate exits non-zero when a custom template is broken
+#[test]
+fn test_validate_template_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("README.md.hbs"), "# {{project_name}}").unwrap();
+
+    let validate = || {
+        Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+            .arg("validate")
+            .arg("--template-dir")
+            .arg(temp_dir.path())
+            .output()
+            .expect("Failed to run axum-app-create")
+    };
+
+    let output = validate();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("✓ README.md"));
+
+    std::fs::write(temp_dir.path().join("broken.rs.hbs"), "{{#if}}").unwrap();
+    let output = validate();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("❌ broken.rs"));
+    assert!(stderr.contains("1 of 2 templates failed"));
+}
+
+/// Test: --bare generates only the crate
+#[test]
+fn test_bare_generates_only_crate() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("bare-app");
+
+    let config = ProjectConfig {
+        project_name: "bare-app".to_string(),
+        ci: true,
+        bare: true,
+        with_env: true,
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    assert!(project_dir.join("Cargo.toml").exists());
+    assert!(project_dir.join("src/main.rs").exists());
+    for omitted in [
+        "README.md",
+        ".gitignore",
+        ".editorconfig",
+        ".github",
+        "Dockerfile",
+        ".env",
+        ".git",
+    ] {
+        assert!(
+            !project_dir.join(omitted).exists(),
+            "--bare should omit {}",
+            omitted
+        );
+    }
+}
+
+/// Test: --no-update skips `cargo update`, so no Cargo.lock is written
+#[test]
+fn test_no_update_skips_cargo_update() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let project_dir = temp_dir.path().join("no-update-app");
+    let config = ProjectConfig {
+        project_name: "no-update-app".to_string(),
+        no_update: true,
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert!(project_dir.join("Cargo.toml").exists());
+    assert!(!project_dir.join("Cargo.lock").exists());
+
+    let project_dir = temp_dir.path().join("update-app");
+    let config = ProjectConfig {
+        project_name: "update-app".to_string(),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert!(project_dir.join("Cargo.lock").exists());
+}
+
+/// Test: --db-url-parts assembles DATABASE_URL from DB_* vars and compiles
+#[test]
+fn test_db_url_parts_compiles() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, database, cargo_features, config_path) in [
+        (
+            ProjectMode::Single,
+            DatabaseOption::PostgreSQL,
+            false,
+            "src/config.rs",
+        ),
+        (
+            ProjectMode::Single,
+            DatabaseOption::Both,
+            true,
+            "src/config.rs",
+        ),
+        (
+            ProjectMode::Workspace,
+            DatabaseOption::PostgreSQL,
+            false,
+            "api/src/config.rs",
+        ),
+    ] {
+        let name = format!("db-parts-{}-{}", mode, database)
+            .to_lowercase()
+            .replace(" + ", "-");
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            cargo_features,
+            db_url_parts: true,
+            features: FeatureSet {
+                database,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let config_rs = std::fs::read_to_string(project_dir.join(config_path)).unwrap();
+        for var in ["DB_HOST", "DB_PORT", "DB_USER", "DB_PASSWORD", "DB_NAME"] {
+            assert!(
+                config_rs.contains(var),
+                "{}: builder should read {}",
+                name,
+                var
+            );
+        }
+        assert!(config_rs.contains("database_url: database_url(),"));
+
+        let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
+        assert!(env_example.contains("# DB_HOST=localhost"));
+
+        let feature_sets: &[&[&str]] = if cargo_features {
+            &[&["--no-default-features"], &["--all-features"]]
+        } else {
+            &[&[]]
+        };
+        for feature_args in feature_sets {
+            let output = Command::new("cargo")
+                .arg("check")
+                .arg("--workspace")
+                .args(*feature_args)
+                .arg("--manifest-path")
+                .arg(project_dir.join("Cargo.toml"))
+                .output()
+                .expect("Failed to run cargo check");
+
+            assert!(
+                output.status.success(),
+                "{} {:?} failed to compile:\n{}",
+                name,
+                feature_args,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+
+    // Without the flag nothing changes
+    let project_dir = temp_dir.path().join("db-parts-off");
+    let config = ProjectConfig {
+        project_name: "db-parts-off".to_string(),
+        features: FeatureSet {
+            database: DatabaseOption::PostgreSQL,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    let config_rs = std::fs::read_to_string(project_dir.join("src/config.rs")).unwrap();
+    assert!(!config_rs.contains("DB_HOST"));
+}
+
+/// Test: --no-git skips the repository but otherwise generates the same files
+#[test]
+fn test_no_git_skips_repository() {
+    use axum_app_create::updater::metadata::METADATA_FILE;
+    use std::collections::BTreeMap;
+    use std::path::Path;
+
+    /// Every file under `dir` except git and metadata, keyed by relative path
+    fn collect(dir: &Path, root: &Path, files: &mut BTreeMap<String, Vec<u8>>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let relative = path
+                .strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .to_string();
+            if relative == ".git" || relative == METADATA_FILE {
+                continue;
+            }
+            if path.is_dir() {
+                collect(&path, root, files);
+            } else {
+                files.insert(relative, std::fs::read(&path).unwrap());
+            }
+        }
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    let mut generated = Vec::new();
+    for no_git in [false, true] {
+        let project_dir = temp_dir
+            .path()
+            .join(format!("git-{}", no_git))
+            .join("git-app");
+        let config = ProjectConfig {
+            project_name: "git-app".to_string(),
+            seed_rng: Some(7),
+            no_update: true,
+            no_git,
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        assert_eq!(project_dir.join(".git").exists(), !no_git);
+        assert!(project_dir.join(".gitignore").exists());
+        assert!(project_dir.join(METADATA_FILE).exists());
+
+        let mut files = BTreeMap::new();
+        collect(&project_dir, &project_dir, &mut files);
+        generated.push(files);
+    }
+
+    assert_eq!(generated[0], generated[1]);
+}
+
+/// Test: --procfile runs the release binary and migrates only when there are migrations
+#[test]
+fn test_procfile() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
+    let temp_dir = TempDir::new().unwrap();
+
+    let generate = |name: &str, mode: ProjectMode, database: DatabaseOption, entities: &[&str]| {
+        let project_dir = temp_dir.path().join(name);
+        let config = ProjectConfig {
+            project_name: name.to_string(),
+            mode,
+            procfile: true,
+            entities: entities.iter().map(|e| e.to_string()).collect(),
+            features: FeatureSet {
+                database,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+        std::fs::read_to_string(project_dir.join("Procfile")).unwrap()
+    };
+
+    let procfile = generate("proc-none", ProjectMode::Single, DatabaseOption::None, &[]);
+    assert!(procfile.contains("web: HOST=0.0.0.0 ./target/release/proc-none\n"));
+    assert!(!procfile.contains("release:"));
+
+    let procfile = generate(
+        "proc-pg",
+        ProjectMode::Single,
+        DatabaseOption::PostgreSQL,
+        &[],
+    );
+    assert!(procfile.contains("web: HOST=0.0.0.0 ./target/release/proc-pg\n"));
+    assert!(procfile.contains("release: sqlx migrate run\n"));
+
+    // Workspace projects only have migrations for entities
+    let procfile = generate(
+        "proc-ws",
+        ProjectMode::Workspace,
+        DatabaseOption::PostgreSQL,
+        &[],
+    );
+    assert!(procfile.contains("./target/release/proc-ws"));
+    assert!(!procfile.contains("release:"));
+
+    let procfile = generate(
+        "proc-ws-entities",
+        ProjectMode::Workspace,
+        DatabaseOption::PostgreSQL,
+        &["Post"],
+    );
+    assert!(procfile.contains("release: sqlx migrate run --source infrastructure/migrations\n"));
+
+    // No Procfile without the flag
+    let project_dir = temp_dir.path().join("proc-off");
+    let config = ProjectConfig {
+        project_name: "proc-off".to_string(),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert!(!project_dir.join("Procfile").exists());
+}
+
+/// Test: a detected origin remote sets the repository in Cargo.toml and the README
+#[test]
+fn test_repository_from_git_remote() {
+    use axum_app_create::generator::git::detect_git_remote;
+
+    let temp_dir = TempDir::new().unwrap();
+    let repo = git2::Repository::init(temp_dir.path()).unwrap();
+    repo.remote("origin", "git@github.com:acme/shop.git")
+        .unwrap();
+    let repository = detect_git_remote(temp_dir.path());
+    assert_eq!(repository.as_deref(), Some("https://github.com/acme/shop"));
+
+    for (mode, member_manifest) in [
+        (ProjectMode::Single, None),
+        (ProjectMode::Workspace, Some("api/Cargo.toml")),
+    ] {
+        let name = format!("repository-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            repository: repository.clone(),
+            no_git: true,
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+        assert!(
+            cargo_toml.contains("repository = \"https://github.com/acme/shop\"\n"),
+            "{}",
+            cargo_toml
+        );
+        if let Some(member_manifest) = member_manifest {
+            let member = std::fs::read_to_string(project_dir.join(member_manifest)).unwrap();
+            assert!(member.contains("repository.workspace = true\n"));
+        }
+        let readme = std::fs::read_to_string(project_dir.join("README.md")).unwrap();
+        assert!(readme.contains(&format!("git clone https://github.com/acme/shop {}", name)));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        assert!(
+            output.status.success(),
+            "{} project with a repository failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    // Without a remote the field is omitted
+    let project_dir = temp_dir.path().join("repository-none");
+    let config = ProjectConfig {
+        project_name: "repository-none".to_string(),
+        no_git: true,
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(!cargo_toml.contains("repository = "));
+}
+
+/// Integration test - --from-git-remote reads the origin of the target directory, not the cwd
+#[test]
+fn test_from_git_remote_uses_target_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let target_repo = temp_dir.path().join("shop");
+    let cwd_repo = temp_dir.path().join("other");
+    for (dir, remote) in [
+        (&target_repo, "git@github.com:acme/shop.git"),
+        (&cwd_repo, "https://github.com/acme/other"),
+    ] {
+        std::fs::create_dir(dir).unwrap();
+        git2::Repository::init(dir)
+            .unwrap()
+            .remote("origin", remote)
+            .unwrap();
+    }
+
+    // `services/` does not exist yet, so detection starts from `shop/`
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args(["../shop/services/orders", "--from-git-remote"])
+        .args(["--non-interactive", "--no-git", "--no-update"])
+        .current_dir(&cwd_repo)
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let cargo_toml =
+        std::fs::read_to_string(target_repo.join("services/orders/Cargo.toml")).unwrap();
+    assert!(
+        cargo_toml.contains("repository = \"https://github.com/acme/shop\"\n"),
+        "{}",
+        cargo_toml
+    );
+    assert!(!cargo_toml.contains("acme/other"), "{}", cargo_toml);
+}
+
+/// Test: .editorconfig is generated at the root only, in both modes
+#[test]
+fn test_editorconfig() {
+    let temp_dir = TempDir::new().unwrap();
+
+    for (name, mode) in [
+        ("editorconfig-single", ProjectMode::Single),
+        ("editorconfig-ws", ProjectMode::Workspace),
+    ] {
+        let project_dir = temp_dir.path().join(name);
+        let config = ProjectConfig {
+            project_name: name.to_string(),
+            mode,
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let editorconfig = std::fs::read_to_string(project_dir.join(".editorconfig")).unwrap();
+        assert!(editorconfig.contains("[*.rs]\nindent_style = space\nindent_size = 4\n"));
+        assert!(editorconfig.contains("insert_final_newline = true"));
+        assert!(editorconfig.contains("trim_trailing_whitespace = true"));
+    }
+
+    for member in ["api", "domain", "infrastructure", "common"] {
+        assert!(
+            !temp_dir
+                .path()
+                .join("editorconfig-ws")
+                .join(member)
+                .join(".editorconfig")
+                .exists()
+        );
+    }
+}
+
+/// Test: --codeowners lists each workspace crate, or a single catch-all
+#[test]
+fn test_codeowners() {
+    use axum_app_create::template::context::TemplateContext;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    let generate = |name: &str, mode: ProjectMode, codeowners: bool| {
+        let project_dir = temp_dir.path().join(name);
+        let config = ProjectConfig {
+            project_name: name.to_string(),
+            mode,
+            codeowners,
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+        std::fs::read_to_string(project_dir.join(".github/CODEOWNERS")).ok()
+    };
+
+    let owner = TemplateContext::from_config(&ProjectConfig::default()).code_owner;
+
+    let codeowners = generate("owners-ws", ProjectMode::Workspace, true).unwrap();
+    for path in ["/api/", "/domain/", "/infrastructure/", "/common/"] {
+        assert!(
+            codeowners.contains(&format!("\n{} {}\n", path, owner)),
+            "{}",
+            codeowners
+        );
+    }
+    assert!(!codeowners.contains("\n* "));
+
+    let codeowners = generate("owners-single", ProjectMode::Single, true).unwrap();
+    assert!(codeowners.contains(&format!("\n* {}\n", owner)));
+    assert!(!codeowners.contains("/api/"));
+
+    // No CODEOWNERS without the flag
+    assert!(generate("owners-off", ProjectMode::Workspace, false).is_none());
+}
+
+/// Test: --sse generates a streaming /events handler that compiles in both modes
+#[test]
+fn test_sse_endpoint_compiles() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, handler_path, main_path) in [
+        (ProjectMode::Single, "src/handlers/events.rs", "src/main.rs"),
+        (
+            ProjectMode::Workspace,
+            "api/src/handlers/events.rs",
+            "api/src/main.rs",
+        ),
+    ] {
+        let name = format!("sse-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            features: FeatureSet {
+                sse: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let events_rs = std::fs::read_to_string(project_dir.join(handler_path)).unwrap();
+        assert!(events_rs.contains("use axum::response::sse::{Event, KeepAlive, Sse};"));
+        assert!(events_rs.contains(".route(\"/events\", get(events))"));
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains("events::router()"));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        assert!(
+            output.status.success(),
+            "{} project with SSE failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    // No handler without the flag
+    let project_dir = temp_dir.path().join("sse-off");
+    let config = ProjectConfig {
+        project_name: "sse-off".to_string(),
+        ..Default::default()
+    };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert!(!project_dir.join("src/handlers/events.rs").exists());
+}
+
+/// Test: --sessions adds a tower-sessions layer and compiles in both modes
+#[test]
+fn test_sessions_layer_compiles() {
+    use axum_app_create::config::FeatureSet;
+
+    let temp_dir = TempDir::new().unwrap();
+
+    for (mode, main_path, handler_path) in [
+        (
+            ProjectMode::Single,
+            "src/main.rs",
+            "src/handlers/session.rs",
+        ),
+        (
+            ProjectMode::Workspace,
+            "api/src/main.rs",
+            "api/src/handlers/session.rs",
+        ),
+    ] {
+        let name = format!("sessions-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            features: FeatureSet {
+                sessions: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let main_rs = std::fs::read_to_string(project_dir.join(main_path)).unwrap();
+        assert!(main_rs.contains(".layer(SessionManagerLayer::new(MemoryStore::default()))"));
+        let handler = std::fs::read_to_string(project_dir.join(handler_path)).unwrap();
+        assert!(handler.contains(".insert(USER_KEY, &req.username)"));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        assert!(
+            output.status.success(),
+            "{} project with sessions failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+/// Test: --port changes the rendered bind defaults and the success message
+#[test]
+fn test_port_override() {
+    let temp_dir = TempDir::new().unwrap();
+
+    for (name, mode, config_path) in [
+        ("port-single", "single", "src/config.rs"),
+        ("port-workspace", "workspace", "api/src/config.rs"),
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+            .current_dir(temp_dir.path())
+            .args([name, "--mode", mode, "--port", "3000", "--host", "0.0.0.0"])
+            .args(["--non-interactive", "--no-update", "--no-git"])
+            .output()
+            .expect("Failed to run axum-app-create");
+        assert!(
+            output.status.success(),
+            "{}: {}",
+            name,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert!(
+            String::from_utf8_lossy(&output.stdout).contains("curl http://127.0.0.1:3000/health")
+        );
 
-    // No database or auth files should exist (they render to empty)
-    let db_content = std::fs::read_to_string(project_dir.join("src/db.rs")).unwrap_or_default();
-    assert!(db_content.trim().is_empty() || !project_dir.join("src/db.rs").exists());
+        let project_dir = temp_dir.path().join(name);
+        let config_rs = std::fs::read_to_string(project_dir.join(config_path)).unwrap();
+        assert!(config_rs.contains(r#""3000".to_string()"#));
+        assert!(config_rs.contains(r#""0.0.0.0".to_string()"#));
+        let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
+        assert!(env_example.contains("HOST=0.0.0.0\nPORT=3000\n"));
+        let dockerfile = std::fs::read_to_string(project_dir.join("Dockerfile")).unwrap();
+        assert!(dockerfile.contains("EXPOSE 3000"));
+    }
 }
 
-/// Test preset api generates PostgreSQL + auth + biz-error
+/// Test: author names with quotes or apostrophes produce a valid Cargo manifest
 #[test]
-fn test_preset_api() {
+fn test_author_with_apostrophe() {
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("api-app");
 
-    let features = Preset::Api.to_feature_set();
-    let config = ProjectConfig {
-        project_name: "api-app".to_string(),
-        features,
-        preset: Some(Preset::Api),
-        database: Some(axum_app_create::config::DatabaseConfig::default()),
-        authentication: Some(axum_app_create::config::AuthConfig::default()),
-        biz_error: Some(axum_app_create::config::BizErrorConfig::default()),
-        ..Default::default()
-    };
+    for (name, mode) in [
+        ("obrien-single", "single"),
+        ("obrien-workspace", "workspace"),
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+            .current_dir(temp_dir.path())
+            .args([name, "--mode", mode, "--author", r#"Dara "DO" O'Brien"#])
+            .args(["--non-interactive", "--no-update", "--no-git"])
+            .output()
+            .expect("Failed to run axum-app-create");
+        assert!(
+            output.status.success(),
+            "{}: {}",
+            name,
+            String::from_utf8_lossy(&output.stderr)
+        );
 
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+        let project_dir = temp_dir.path().join(name);
+        let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+        assert!(
+            cargo_toml.contains(r#"authors = ["Dara \"DO\" O'Brien"#),
+            "{}",
+            cargo_toml
+        );
 
-    // Auth file should have content
-    let auth_content = std::fs::read_to_string(project_dir.join("src/handlers/auth.rs")).unwrap();
-    assert!(
-        !auth_content.trim().is_empty(),
-        "Auth handler should have content"
-    );
+        let output = Command::new("cargo")
+            .args(["metadata", "--no-deps", "--format-version", "1"])
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo metadata");
+        assert!(
+            output.status.success(),
+            "{}: {}",
+            name,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let metadata = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            metadata.contains(r#""authors":["Dara \"DO\" O'Brien"#),
+            "{}",
+            metadata
+        );
+    }
 }
 
-/// Test preset fullstack generates both DBs + all features
+/// Test: a --host that is neither an IP address nor a hostname is rejected
 #[test]
-fn test_preset_fullstack() {
+fn test_invalid_host_rejected() {
+    use axum_app_create::config::ServerConfig;
+    use axum_app_create::error::CliError;
+
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("fullstack-app");
 
-    let features = Preset::Fullstack.to_feature_set();
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .current_dir(temp_dir.path())
+        .args(["bad-host", "--host", "a\"b"])
+        .args(["--non-interactive", "--no-update", "--no-git"])
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid host"));
+    assert!(!temp_dir.path().join("bad-host").exists());
+
+    // Hosts from a manifest are validated too
+    let project_dir = temp_dir.path().join("bad-host-config");
     let config = ProjectConfig {
-        project_name: "fullstack-app".to_string(),
-        features,
-        preset: Some(Preset::Fullstack),
-        database: Some(axum_app_create::config::DatabaseConfig::default()),
-        authentication: Some(axum_app_create::config::AuthConfig::default()),
-        biz_error: Some(axum_app_create::config::BizErrorConfig::default()),
+        project_name: "bad-host-config".to_string(),
+        server: ServerConfig {
+            host: "0.0.0.0\\".to_string(),
+            ..Default::default()
+        },
         ..Default::default()
     };
-
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
-
-    let cargo_content = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
-    assert!(
-        cargo_content.contains("sqlx"),
-        "Fullstack should include sqlx"
-    );
+    let result = generate_project(&project_dir, &config, false, false, false);
+    assert!(matches!(result, Err(CliError::ValidationError(_))));
+    assert!(!project_dir.exists());
 }
 
-/// Test CI template generated when ci=true
+/// Test: --multipart generates an upload handler using `Multipart` that compiles in both modes
 #[test]
-fn test_ci_enabled() {
+fn test_multipart_upload_compiles() {
+    use axum_app_create::config::FeatureSet;
+
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("ci-app");
 
+    for (mode, cargo_path, handler_path) in [
+        (ProjectMode::Single, "Cargo.toml", "src/handlers/upload.rs"),
+        (
+            ProjectMode::Workspace,
+            "api/Cargo.toml",
+            "api/src/handlers/upload.rs",
+        ),
+    ] {
+        let name = format!("multipart-{}", mode);
+        let project_dir = temp_dir.path().join(&name);
+        let config = ProjectConfig {
+            project_name: name.clone(),
+            mode,
+            features: FeatureSet {
+                multipart: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+
+        let cargo_toml = std::fs::read_to_string(project_dir.join(cargo_path)).unwrap();
+        assert!(cargo_toml.contains(r#"axum = { version = "0.8", features = ["multipart"] }"#));
+        let handler = std::fs::read_to_string(project_dir.join(handler_path)).unwrap();
+        assert!(handler.contains("mut multipart: Multipart"));
+        assert!(handler.contains("DefaultBodyLimit::max(MAX_UPLOAD_BYTES)"));
+        let env_example = std::fs::read_to_string(project_dir.join(".env.example")).unwrap();
+        assert!(env_example.contains("UPLOAD_DIR=uploads"));
+
+        let output = Command::new("cargo")
+            .arg("check")
+            .arg("--workspace")
+            .arg("--manifest-path")
+            .arg(project_dir.join("Cargo.toml"))
+            .output()
+            .expect("Failed to run cargo check");
+
+        assert!(
+            output.status.success(),
+            "{} project with multipart failed to compile:\n{}",
+            mode,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    // Without the feature, no upload handler is generated
+    let project_dir = temp_dir.path().join("no-multipart");
     let config = ProjectConfig {
-        project_name: "ci-app".to_string(),
-        ci: true,
+        project_name: "no-multipart".to_string(),
         ..Default::default()
     };
-
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
-
-    assert!(
-        project_dir.join(".github/workflows/ci.yml").exists(),
-        "CI workflow file should exist"
-    );
-
-    // Single mode should NOT have --workspace flag
-    let ci_content = std::fs::read_to_string(project_dir.join(".github/workflows/ci.yml")).unwrap();
-    assert!(
-        !ci_content.contains("--workspace"),
-        "Single mode CI should not have --workspace"
-    );
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    assert!(!project_dir.join("src/handlers/upload.rs").exists());
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("axum = \"0.8\"\n"));
 }
 
-/// Test CI template NOT generated when ci=false
+/// Test: --edition is written to Cargo.toml and rejected when too old for the features
 #[test]
-fn test_ci_disabled() {
-    let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("no-ci-app");
+fn test_edition() {
+    use axum_app_create::config::{Edition, FeatureSet};
+    use axum_app_create::error::CliError;
 
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("edition-app");
     let config = ProjectConfig {
-        project_name: "no-ci-app".to_string(),
-        ci: false,
+        project_name: "edition-app".to_string(),
+        edition: Edition::E2021,
         ..Default::default()
     };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("edition = \"2021\""));
 
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
-
+    let project_dir = temp_dir.path().join("old-edition-app");
+    let config = ProjectConfig {
+        project_name: "old-edition-app".to_string(),
+        edition: Edition::E2015,
+        features: FeatureSet {
+            authentication: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let result = generate_project(&project_dir, &config, false, false, false);
     assert!(
-        !project_dir.join(".github/workflows/ci.yml").exists(),
-        "CI workflow should NOT exist"
+        matches!(result, Err(CliError::ValidationError(msg)) if msg.contains("minimum edition 2018"))
     );
+    assert!(!project_dir.exists());
 }
 
-/// Test workspace mode + CI has --workspace flag
+/// Test: a failed generation removes the directory it created, but not one reused with --force
 #[test]
-fn test_workspace_ci_has_workspace_flag() {
+fn test_failed_generation_cleans_up() {
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("ws-ci-app");
 
+    // Render failure: strict mode rejects the undefined variable
+    let render_dir = temp_dir.path().join("render-templates");
+    std::fs::create_dir_all(&render_dir).unwrap();
+    std::fs::write(render_dir.join("README.md.hbs"), "# {{no_such_variable}}\n").unwrap();
+
+    // Write failure after files were created: `README.md` is written as a file first
+    let write_dir = temp_dir.path().join("write-templates");
+    std::fs::create_dir_all(write_dir.join("README.md")).unwrap();
+    std::fs::write(write_dir.join("README.md/nested.txt"), "nested\n").unwrap();
+
+    for (name, template_dir) in [("render-fail", &render_dir), ("write-fail", &write_dir)] {
+        let project_dir = temp_dir.path().join(name);
+        let config = ProjectConfig {
+            project_name: name.to_string(),
+            template_dir: Some(template_dir.clone()),
+            ..Default::default()
+        };
+        let result = generate_project(&project_dir, &config, false, false, false);
+        assert!(result.is_err(), "{} unexpectedly succeeded", name);
+        assert!(!project_dir.exists(), "{} left a partial directory", name);
+    }
+
+    // --force reusing an existing directory never removes it
+    let project_dir = temp_dir.path().join("forced");
+    std::fs::create_dir_all(&project_dir).unwrap();
     let config = ProjectConfig {
-        project_name: "ws-ci-app".to_string(),
-        mode: ProjectMode::Workspace,
-        ci: true,
+        project_name: "forced".to_string(),
+        template_dir: Some(write_dir),
         ..Default::default()
     };
-
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
-
-    let ci_content = std::fs::read_to_string(project_dir.join(".github/workflows/ci.yml")).unwrap();
-    assert!(
-        ci_content.contains("--workspace"),
-        "Workspace CI should have --workspace flag"
-    );
+    assert!(generate_project(&project_dir, &config, false, true, false).is_err());
+    assert!(project_dir.exists());
 }
 
-/// Test workspace mode basic project compiles with cargo check
+/// Test: docker-entrypoint.sh waits for the database host, only with PostgreSQL and a Dockerfile
 #[test]
-fn test_workspace_basic_compiles() {
+fn test_docker_entrypoint() {
+    use axum_app_create::config::{DatabaseOption, FeatureSet};
+
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("ws-compile-test");
 
-    let config = ProjectConfig {
-        project_name: "ws-compile-test".to_string(),
-        mode: ProjectMode::Workspace,
-        ..Default::default()
+    let generate = |name: &str, mode: ProjectMode, database: DatabaseOption, bare: bool| {
+        let project_dir = temp_dir.path().join(name);
+        let config = ProjectConfig {
+            project_name: name.to_string(),
+            mode,
+            bare,
+            features: FeatureSet {
+                database,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        generate_project(&project_dir, &config, false, false, false).unwrap();
+        project_dir
     };
 
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+    for mode in [ProjectMode::Single, ProjectMode::Workspace] {
+        let name = format!("entrypoint-{}", mode);
+        let project_dir = generate(&name, mode, DatabaseOption::PostgreSQL, false);
 
-    let output = Command::new("cargo")
-        .arg("check")
-        .arg("--workspace")
-        .arg("--manifest-path")
-        .arg(project_dir.join("Cargo.toml"))
-        .output()
-        .expect("Failed to run cargo check");
+        let script = std::fs::read_to_string(project_dir.join("docker-entrypoint.sh")).unwrap();
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains(r#"nc -z "$DB_HOST" "$DB_PORT""#));
+        assert!(script.contains(&format!("exec /app/{} \"$@\"", name)));
 
-    if !output.status.success() {
-        eprintln!(
-            "cargo check stdout: {}",
-            String::from_utf8_lossy(&output.stdout)
-        );
-        eprintln!(
-            "cargo check stderr: {}",
-            String::from_utf8_lossy(&output.stderr)
-        );
+        let dockerfile = std::fs::read_to_string(project_dir.join("Dockerfile")).unwrap();
+        assert!(dockerfile.contains("COPY --chmod=755 docker-entrypoint.sh"));
+        assert!(dockerfile.contains(r#"ENTRYPOINT ["/app/docker-entrypoint.sh"]"#));
+        assert!(!dockerfile.contains("FROM scratch"));
     }
 
-    assert!(
-        output.status.success(),
-        "Workspace project failed to compile"
+    // No database to wait for: the binary stays the entrypoint
+    let project_dir = generate(
+        "entrypoint-none",
+        ProjectMode::Single,
+        DatabaseOption::None,
+        false,
+    );
+    assert!(!project_dir.join("docker-entrypoint.sh").exists());
+    let dockerfile = std::fs::read_to_string(project_dir.join("Dockerfile")).unwrap();
+    assert!(dockerfile.contains(r#"ENTRYPOINT ["/app/entrypoint-none"]"#));
+
+    // SQLite has no host to wait for
+    let project_dir = generate(
+        "entrypoint-sqlite",
+        ProjectMode::Single,
+        DatabaseOption::SQLite,
+        false,
     );
+    assert!(!project_dir.join("docker-entrypoint.sh").exists());
+
+    // --bare omits the Dockerfile, and the entrypoint with it
+    let project_dir = generate(
+        "entrypoint-bare",
+        ProjectMode::Single,
+        DatabaseOption::PostgreSQL,
+        true,
+    );
+    assert!(!project_dir.join("Dockerfile").exists());
+    assert!(!project_dir.join("docker-entrypoint.sh").exists());
 }
 
-/// Test workspace mode full-featured project compiles
+/// Test: new-handler adds a handler module, registers it and mounts its router
 #[test]
-fn test_workspace_full_features_compiles() {
+fn test_new_handler() {
     let temp_dir = TempDir::new().unwrap();
-    let project_dir = temp_dir.path().join("ws-full-test");
-
-    let features = Preset::Fullstack.to_feature_set();
+    let project_dir = temp_dir.path().join("handler-app");
     let config = ProjectConfig {
-        project_name: "ws-full-test".to_string(),
-        mode: ProjectMode::Workspace,
-        features,
-        preset: Some(Preset::Fullstack),
-        database: Some(axum_app_create::config::DatabaseConfig::default()),
-        authentication: Some(axum_app_create::config::AuthConfig::default()),
-        biz_error: Some(axum_app_create::config::BizErrorConfig::default()),
-        ci: true,
+        project_name: "handler-app".to_string(),
         ..Default::default()
     };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
+
+    let new_handler = || {
+        Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+            .args(["new-handler", "orders", "--project-dir"])
+            .arg(&project_dir)
+            .output()
+            .expect("Failed to run axum-app-create")
+    };
+    let output = new_handler();
+    assert!(
+        output.status.success(),
+        "new-handler failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
 
-    let result = generate_project(&project_dir, &config, false, false);
-    assert!(result.is_ok(), "Generation failed: {:?}", result.err());
+    assert!(project_dir.join("src/handlers/orders.rs").exists());
+    let handlers = std::fs::read_to_string(project_dir.join("src/handlers/mod.rs")).unwrap();
+    assert!(handlers.contains("pub mod orders;\n"));
+    let main_rs = std::fs::read_to_string(project_dir.join("src/main.rs")).unwrap();
+    assert!(main_rs.contains(".merge(orders::router())"));
+
+    // Running it again refuses to overwrite the handler
+    assert!(!new_handler().status.success());
 
     let output = Command::new("cargo")
         .arg("check")
-        .arg("--workspace")
         .arg("--manifest-path")
         .arg(project_dir.join("Cargo.toml"))
         .output()
         .expect("Failed to run cargo check");
-
-    if !output.status.success() {
-        eprintln!(
-            "cargo check stdout: {}",
-            String::from_utf8_lossy(&output.stdout)
-        );
-        eprintln!(
-            "cargo check stderr: {}",
-            String::from_utf8_lossy(&output.stderr)
-        );
-    }
-
     assert!(
         output.status.success(),
-        "Workspace full-featured project failed to compile"
+        "Project with a new handler failed to compile:\n{}",
+        String::from_utf8_lossy(&output.stderr)
     );
 }
 
-/// Test workspace_crates context is correctly populated for workspace mode
+/// Test: --merge keeps dependencies added to Cargo.toml while adding generated ones
 #[test]
-fn test_workspace_crates_context() {
-    use axum_app_create::template::context::TemplateContext;
+fn test_regen_merge_manifest_keeps_added_dependency() {
+    use axum_app_create::updater::metadata::METADATA_FILE;
+    use axum_app_create::updater::regen::{FileClassification, regen_manifests};
 
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("manifest-merge-app");
     let config = ProjectConfig {
-        project_name: "ctx-test".to_string(),
-        mode: ProjectMode::Workspace,
+        project_name: "manifest-merge-app".to_string(),
         ..Default::default()
     };
+    generate_project(&project_dir, &config, false, false, false).unwrap();
 
-    let ctx = TemplateContext::from_config(&config);
-    assert!(ctx.is_workspace);
-    let crates = ctx
-        .workspace_crates
-        .as_ref()
-        .expect("workspace_crates should be Some");
-    assert_eq!(crates.len(), 4);
-
-    // Verify crate names
-    let names: Vec<&str> = crates.iter().map(|c| c.name.as_str()).collect();
-    assert_eq!(names, vec!["api", "domain", "infrastructure", "common"]);
+    let manifest_path = project_dir.join("Cargo.toml");
+    let original = std::fs::read_to_string(&manifest_path).unwrap();
+    let edited = original.replace(
+        "[dependencies]\n",
+        "[dependencies]\n# HTTP client for upstream calls\nreqwest = \"0.12\"\n",
+    );
+    assert_ne!(edited, original);
+    std::fs::write(&manifest_path, &edited).unwrap();
+
+    // Enable auth in the stored configuration
+    let metadata_path = project_dir.join(METADATA_FILE);
+    let mut metadata: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&metadata_path).unwrap()).unwrap();
+    metadata["config"]["features"]["authentication"] = true.into();
+    std::fs::write(&metadata_path, metadata.to_string()).unwrap();
+
+    let regenerated = regen_manifests(&project_dir, false, true, false).unwrap();
+    assert_eq!(
+        regenerated[0].classification,
+        FileClassification::Merged { conflicts: false }
+    );
 
-    // Verify api is bin, others are lib
-    assert_eq!(crates[0].kind, "bin");
-    assert_eq!(crates[1].kind, "lib");
-    assert_eq!(crates[2].kind, "lib");
-    assert_eq!(crates[3].kind, "lib");
+    let merged = std::fs::read_to_string(&manifest_path).unwrap();
+    assert!(
+        merged.contains("# HTTP client for upstream calls\nreqwest = \"0.12\"\n"),
+        "{}",
+        merged
+    );
+    assert!(merged.contains("jsonwebtoken"), "{}", merged);
+    assert!(!merged.contains("<<<<<<<"), "{}", merged);
+}
 
-    // Verify package names
-    assert_eq!(crates[0].package_name, "ctx-test-api");
-    assert_eq!(crates[1].package_name, "ctx-test-domain");
+/// Test: --license writes the license text and sets the Cargo.toml license field
+#[test]
+fn test_license() {
+    let temp_dir = TempDir::new().unwrap();
 
-    // Verify api depends on domain, infrastructure, common
-    assert!(crates[0].workspace_deps.contains(&"domain".to_string()));
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args(["license-app", "--license", "MIT", "--author", "Jane Doe"])
+        .args(["--non-interactive", "--no-git", "--no-update", "--quiet"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to run axum-app-create");
     assert!(
-        crates[0]
-            .workspace_deps
-            .contains(&"infrastructure".to_string())
+        output.status.success(),
+        "generation failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
     );
-    assert!(crates[0].workspace_deps.contains(&"common".to_string()));
 
-    // Verify domain has no workspace deps
-    assert!(crates[1].workspace_deps.is_empty());
+    let project_dir = temp_dir.path().join("license-app");
+    let license = std::fs::read_to_string(project_dir.join("LICENSE")).unwrap();
+    assert!(license.contains("MIT License"));
+    assert!(license.contains("Jane Doe"));
+    let cargo_toml = std::fs::read_to_string(project_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("license = \"MIT\""), "{}", cargo_toml);
 
-    // Verify infrastructure depends on domain
-    assert_eq!(crates[2].workspace_deps, vec!["domain"]);
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args(["unknown-license-app", "--license", "WTFPL"])
+        .args(["--non-interactive", "--no-git", "--no-update", "--quiet"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(!output.status.success());
+    assert!(!temp_dir.path().join("unknown-license-app").exists());
 }
 
-/// Test workspace_crates is None for single mode
+/// Test: the initial migration creates the users table only with auth, in the database's dialect
 #[test]
-fn test_single_mode_no_workspace_crates() {
-    use axum_app_create::template::context::TemplateContext;
-
-    let config = ProjectConfig {
-        project_name: "single-test".to_string(),
-        mode: ProjectMode::Single,
-        ..Default::default()
+fn test_initial_migration_dialects() {
+    use axum_app_create::config::{AuthConfig, DatabaseConfig, DatabaseOption, FeatureSet};
+    use axum_app_create::generator::project::render_file;
+
+    let render = |database: DatabaseOption, authentication: bool| {
+        let config = ProjectConfig {
+            project_name: "migration-app".to_string(),
+            features: FeatureSet {
+                database,
+                authentication,
+                ..Default::default()
+            },
+            database: Some(DatabaseConfig {
+                option: database,
+                ..Default::default()
+            }),
+            authentication: authentication.then(AuthConfig::default),
+            ..Default::default()
+        };
+        render_file(&config, "migrations/001_initial.sql", None)
+            .unwrap()
+            .unwrap()
     };
 
-    let ctx = TemplateContext::from_config(&config);
-    assert!(!ctx.is_workspace);
-    assert!(ctx.workspace_crates.is_none());
+    let postgres = render(DatabaseOption::PostgreSQL, true);
+    assert!(postgres.contains("CREATE TABLE IF NOT EXISTS users"));
+    assert!(postgres.contains("id SERIAL PRIMARY KEY"));
+    assert!(!postgres.contains("AUTOINCREMENT"));
+
+    let sqlite = render(DatabaseOption::SQLite, true);
+    assert!(sqlite.contains("CREATE TABLE IF NOT EXISTS users"));
+    assert!(sqlite.contains("id INTEGER PRIMARY KEY AUTOINCREMENT"));
+    assert!(!sqlite.contains("SERIAL"));
+
+    // With both databases the migrations follow PostgreSQL, like entity migrations
+    let both = render(DatabaseOption::Both, true);
+    assert_eq!(both.matches("CREATE TABLE").count(), 1, "{}", both);
+    assert!(both.contains("SERIAL"));
+
+    for database in [DatabaseOption::PostgreSQL, DatabaseOption::SQLite] {
+        assert!(!render(database, false).contains("CREATE TABLE IF NOT EXISTS users"));
+    }
 }
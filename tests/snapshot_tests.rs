@@ -0,0 +1,220 @@
+// Snapshot tests for generated output
+//
+// Each preset/mode combination is generated through the CLI and compared, file
+// by file, with its golden copy in tests/snapshots/. After an intended template
+// change, rewrite the snapshots with:
+//
+//     UPDATE_SNAPSHOTS=1 cargo test --test snapshot_tests
+
+use chrono::Datelike;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Files whose content changes on every run and are left out of snapshots
+const SKIPPED_FILES: &[&str] = &[".axum-app-create.json"];
+
+/// Marker starting each file's section in a snapshot
+const SECTION_MARKER: &str = "=== file: ";
+
+fn snapshot_path(mode: &str, preset: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{}_{}.snap", mode, preset))
+}
+
+/// Generate a project with pinned author, git identity and RNG seed
+///
+/// `--no-update` skips resolving a Cargo.lock, which would follow crates.io.
+///
+/// Returns every file as `path → normalized content`, keyed by `/`-separated
+/// paths relative to the project root.
+fn generate(mode: &str, preset: &str) -> BTreeMap<String, String> {
+    let temp_dir = TempDir::new().unwrap();
+    let gitconfig = temp_dir.path().join("gitconfig");
+    std::fs::write(
+        &gitconfig,
+        "[user]\n\tname = Snapshot Author\n\temail = snapshot@example.com\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_axum-app-create"))
+        .args(["snapshot-app", "--mode", mode, "--preset", preset])
+        .args(["--author", "Snapshot Author", "--seed-rng", "42"])
+        .args(["--non-interactive", "--no-git", "--no-update", "--quiet"])
+        .current_dir(temp_dir.path())
+        .env("GIT_CONFIG_GLOBAL", &gitconfig)
+        .env("GIT_CONFIG_NOSYSTEM", "1")
+        .env_remove("AXUM_APP_CREATE_TEMPLATE_DIR")
+        .output()
+        .expect("Failed to run axum-app-create");
+    assert!(
+        output.status.success(),
+        "generation failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let project_dir = temp_dir.path().join("snapshot-app");
+    let mut files = BTreeMap::new();
+    collect_files(&project_dir, &project_dir, &mut files);
+    files
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut BTreeMap<String, String>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_files(root, &path, files);
+            continue;
+        }
+
+        let relative = path
+            .strip_prefix(root)
+            .unwrap()
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if SKIPPED_FILES.contains(&relative.as_str()) {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path).unwrap();
+        files.insert(relative, normalize(&content));
+    }
+}
+
+/// Replace values that depend on when the test runs
+fn normalize(content: &str) -> String {
+    let year = chrono::Utc::now().year();
+    content.replace(&format!("**Year**: {}", year), "**Year**: <YEAR>")
+}
+
+fn to_snapshot(files: &BTreeMap<String, String>) -> String {
+    files
+        .iter()
+        .map(|(path, content)| format!("{}{}\n{}", SECTION_MARKER, path, content))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_snapshot(snapshot: &str) -> BTreeMap<String, String> {
+    let mut files = BTreeMap::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+
+    for line in snapshot.split_inclusive('\n') {
+        if let Some(path) = line.strip_prefix(SECTION_MARKER) {
+            files.extend(current.take().map(|(path, lines)| (path, lines.concat())));
+            current = Some((path.trim_end().to_string(), Vec::new()));
+        } else if let Some((_, lines)) = &mut current {
+            lines.push(line);
+        }
+    }
+    files.extend(current.map(|(path, lines)| (path, lines.concat())));
+
+    // Sections are joined with a blank line; drop it from the preceding file
+    let last = files.keys().next_back().cloned();
+    for (path, content) in files.iter_mut() {
+        if Some(path) != last.as_ref() {
+            content.pop();
+        }
+    }
+    files
+}
+
+/// Compare a generated project with its snapshot, or rewrite it with UPDATE_SNAPSHOTS=1
+fn assert_snapshot(mode: &str, preset: &str) {
+    let files = generate(mode, preset);
+    let path = snapshot_path(mode, preset);
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, to_snapshot(&files)).unwrap();
+        return;
+    }
+
+    let snapshot = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot {}; run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    let expected = parse_snapshot(&snapshot);
+
+    let mut drift = Vec::new();
+    for (file, content) in &files {
+        match expected.get(file) {
+            None => drift.push(format!("  + {} (not in snapshot)", file)),
+            Some(old) if old != content => {
+                let line = old
+                    .lines()
+                    .zip(content.lines())
+                    .position(|(a, b)| a != b)
+                    .unwrap_or_else(|| old.lines().count().min(content.lines().count()));
+                drift.push(format!(
+                    "  ~ {} (first difference at line {})",
+                    file,
+                    line + 1
+                ));
+            }
+            Some(_) => {}
+        }
+    }
+    for file in expected.keys().filter(|file| !files.contains_key(*file)) {
+        drift.push(format!("  - {} (no longer generated)", file));
+    }
+
+    assert!(
+        drift.is_empty(),
+        "{} differs from the generated {} {} project:\n{}\n\
+         If the change is intended, run UPDATE_SNAPSHOTS=1 cargo test --test snapshot_tests",
+        path.display(),
+        mode,
+        preset,
+        drift.join("\n")
+    );
+}
+
+#[test]
+fn test_snapshot_round_trip() {
+    let files = BTreeMap::from([
+        ("Cargo.toml".to_string(), "[package]\n\n".to_string()),
+        (
+            "README.md".to_string(),
+            "# app\n=== not a marker\n".to_string(),
+        ),
+        ("empty".to_string(), String::new()),
+        ("src/main.rs".to_string(), "fn main() {}".to_string()),
+    ]);
+    assert_eq!(parse_snapshot(&to_snapshot(&files)), files);
+}
+
+#[test]
+fn test_snapshot_single_minimal() {
+    assert_snapshot("single", "minimal");
+}
+
+#[test]
+fn test_snapshot_single_api() {
+    assert_snapshot("single", "api");
+}
+
+#[test]
+fn test_snapshot_single_fullstack() {
+    assert_snapshot("single", "fullstack");
+}
+
+#[test]
+fn test_snapshot_workspace_minimal() {
+    assert_snapshot("workspace", "minimal");
+}
+
+#[test]
+fn test_snapshot_workspace_api() {
+    assert_snapshot("workspace", "api");
+}
+
+#[test]
+fn test_snapshot_workspace_fullstack() {
+    assert_snapshot("workspace", "fullstack");
+}